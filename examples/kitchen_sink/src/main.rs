@@ -1,58 +1,13 @@
 use serde::Deserialize;
 use wary::Wary;
 
-#[allow(non_camel_case_types)]
-mod rule {
-	pub type password = super::PasswordRule;
-}
-
-/// Rule for validating passwords.
-///
-/// In order to be valid, the password must be EITHER 12+ characters long OR
-/// 8+ characters long and contain at least one uppercase letter, one lowercase
-/// letter, one digit, and one special character.
-struct PasswordRule;
-
-impl PasswordRule {
-	const fn new() -> Self {
-		Self
-	}
-}
-
-impl<I> wary::Rule<I> for PasswordRule
-where
-	I: AsRef<str>,
-{
-	type Context = ();
-
-	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<(), wary::Error> {
-		let string = item.as_ref();
-
-		if string.len() >= 12
-			|| string.len() >= 8
-				&& string.chars().any(char::is_uppercase)
-				&& string.chars().any(char::is_lowercase)
-				&& string.chars().any(char::is_numeric)
-				&& string.chars().any(|ch| !ch.is_alphanumeric())
-		{
-			Ok(())
-		} else {
-			Err(wary::Error::with_message(
-				"password_too_weak",
-				"Password must be 12+ characters long or 8+ characters long and contain at least one of \
-				 each: uppercase, lowercase, number, special.",
-			))
-		}
-	}
-}
-
 #[derive(Debug, Wary, Deserialize)]
 struct User {
 	#[validate(length(3..=16))]
 	#[transform(lowercase)]
 	#[serde(rename = "userName")] // uses this field in the report
 	username: String,
-	#[validate(custom(password))]
+	#[validate(password(min_length = 8, min_entropy = 50, deny = [username, email]))]
 	password: String,
 	#[validate(email)]
 	email: String,
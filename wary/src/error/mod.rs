@@ -2,10 +2,10 @@ mod path;
 
 use core::fmt;
 
-pub use path::Path;
+pub use path::{Elem, Path};
 
 #[cfg(feature = "alloc")]
-use crate::alloc::{borrow::Cow, vec::Vec};
+use crate::alloc::{borrow::Cow, boxed::Box, vec::Vec};
 use crate::options::rule;
 
 #[derive(Debug, thiserror::Error, PartialEq)]
@@ -31,6 +31,31 @@ pub enum Error {
 	Suffix(#[from] rule::suffix::Error),
 	#[error(transparent)]
 	Equals(#[from] rule::equals::Error),
+	#[error(transparent)]
+	Document(#[from] rule::document::Error),
+	#[error(transparent)]
+	DateTime(#[from] rule::datetime::Error),
+	#[error(transparent)]
+	Duration(#[from] rule::duration::Error),
+	#[cfg(any(feature = "jiff", feature = "chrono", feature = "time"))]
+	#[error(transparent)]
+	Calendar(#[from] rule::calendar::Error),
+	#[error(transparent)]
+	Encoding(#[from] rule::encoding::Error),
+	#[error(transparent)]
+	Media(#[from] rule::media::Error),
+	#[error(transparent)]
+	Number(#[from] rule::number::Error),
+	#[error(transparent)]
+	OneOf(#[from] rule::one_of::Error),
+	#[error(transparent)]
+	Unique(#[from] rule::unique::Error),
+	#[error(transparent)]
+	Variant(#[from] rule::variant::Error),
+	#[error(transparent)]
+	Sorted(#[from] rule::sorted::Error),
+	#[error(transparent)]
+	Password(#[from] rule::password::Error),
 	#[cfg(feature = "email")]
 	#[error(transparent)]
 	Email(#[from] rule::email::Error),
@@ -52,12 +77,20 @@ pub enum Error {
 	#[cfg(feature = "uuid")]
 	#[error(transparent)]
 	Uuid(#[from] rule::uuid::Error),
+	#[cfg(feature = "iso")]
+	#[error(transparent)]
+	Iso(#[from] rule::iso::Error),
 	#[cfg(feature = "credit_card")]
 	#[error(transparent)]
 	CreditCard(#[from] rule::credit_card::Error),
-	#[cfg(any(feature = "jiff", feature = "chrono"))]
+	#[cfg(any(feature = "jiff", feature = "chrono", feature = "time"))]
 	#[error(transparent)]
 	Time(#[from] rule::time::Error),
+	#[cfg(feature = "jiff")]
+	#[error(transparent)]
+	TimeZone(#[from] rule::timezone::Error),
+	#[error(transparent)]
+	UtcOffset(#[from] rule::utc_offset::Error),
 	#[error("{code}")]
 	Custom {
 		code: &'static str,
@@ -66,6 +99,15 @@ pub enum Error {
 		#[cfg(not(feature = "alloc"))]
 		message: Option<&'static str>,
 	},
+	/// An error with its code or message replaced, such as with
+	/// `#[validate(length(max = 8, code = "...", message = "..."))]`.
+	#[cfg(feature = "alloc")]
+	#[error("{code}")]
+	Override {
+		code: &'static str,
+		message: Option<Cow<'static, str>>,
+		detail: Box<Error>,
+	},
 }
 
 #[allow(dead_code)]
@@ -99,6 +141,86 @@ impl Error {
 		}
 	}
 
+	/// Replaces the code of the error.
+	///
+	/// The original error is kept as the detail of the new one. Without the
+	/// `alloc` feature, the detail is dropped and a custom error is returned.
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub fn override_code(self, code: &'static str) -> Self {
+		match self {
+			Self::Override {
+				message, detail, ..
+			} => Self::Override {
+				code,
+				message,
+				detail,
+			},
+			detail => Self::Override {
+				code,
+				message: None,
+				detail: Box::new(detail),
+			},
+		}
+	}
+
+	/// Replaces the code of the error.
+	///
+	/// The original error is kept as the detail of the new one. Without the
+	/// `alloc` feature, the detail is dropped and a custom error is returned.
+	#[cfg(not(feature = "alloc"))]
+	#[must_use]
+	pub fn override_code(self, code: &'static str) -> Self {
+		Self::Custom {
+			code,
+			message: self.message(),
+		}
+	}
+
+	/// Replaces the message of the error.
+	///
+	/// The original error is kept as the detail of the new one. Without the
+	/// `alloc` feature, the detail is dropped and a custom error is returned.
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub fn override_message(self, message: impl Into<Cow<'static, str>>) -> Self {
+		match self {
+			Self::Override { code, detail, .. } => Self::Override {
+				code,
+				message: Some(message.into()),
+				detail,
+			},
+			detail => Self::Override {
+				code: detail.code(),
+				message: Some(message.into()),
+				detail: Box::new(detail),
+			},
+		}
+	}
+
+	/// Replaces the message of the error.
+	///
+	/// The original error is kept as the detail of the new one. Without the
+	/// `alloc` feature, the detail is dropped and a custom error is returned.
+	#[cfg(not(feature = "alloc"))]
+	#[must_use]
+	pub fn override_message(self, message: &'static str) -> Self {
+		Self::Custom {
+			code: self.code(),
+			message: Some(message),
+		}
+	}
+
+	/// Returns the error without any overridden code or message.
+	#[must_use]
+	pub fn detail(&self) -> &Self {
+		match self {
+			#[cfg(feature = "alloc")]
+			Self::Override { detail, .. } => detail.detail(),
+			_ => self,
+		}
+	}
+
 	#[must_use]
 	pub(crate) fn code(&self) -> &'static str {
 		match self {
@@ -111,6 +233,19 @@ impl Error {
 			Self::Prefix(error) => error.code(),
 			Self::Suffix(error) => error.code(),
 			Self::Equals(error) => error.code(),
+			Self::Document(error) => error.code(),
+			Self::DateTime(error) => error.code(),
+			Self::Duration(error) => error.code(),
+			#[cfg(any(feature = "jiff", feature = "chrono", feature = "time"))]
+			Self::Calendar(error) => error.code(),
+			Self::Encoding(error) => error.code(),
+			Self::Media(error) => error.code(),
+			Self::Number(error) => error.code(),
+			Self::OneOf(error) => error.code(),
+			Self::Unique(error) => error.code(),
+			Self::Variant(error) => error.code(),
+			Self::Sorted(error) => error.code(),
+			Self::Password(error) => error.code(),
 			#[cfg(feature = "email")]
 			Self::Email(error) => error.code(),
 			#[cfg(feature = "url")]
@@ -124,11 +259,18 @@ impl Error {
 			Self::Required(error) => error.code(),
 			#[cfg(feature = "uuid")]
 			Self::Uuid(error) => error.code(),
+			#[cfg(feature = "iso")]
+			Self::Iso(error) => error.code(),
 			#[cfg(feature = "credit_card")]
 			Self::CreditCard(error) => error.code(),
-			#[cfg(any(feature = "jiff", feature = "chrono"))]
+			#[cfg(any(feature = "jiff", feature = "chrono", feature = "time"))]
 			Self::Time(error) => error.code(),
+			#[cfg(feature = "jiff")]
+			Self::TimeZone(error) => error.code(),
+			Self::UtcOffset(error) => error.code(),
 			Self::Custom { code, .. } => code,
+			#[cfg(feature = "alloc")]
+			Self::Override { code, .. } => code,
 		}
 	}
 
@@ -144,6 +286,19 @@ impl Error {
 			Self::Prefix(error) => error.message(),
 			Self::Suffix(error) => error.message(),
 			Self::Equals(error) => error.message(),
+			Self::Document(error) => error.message(),
+			Self::DateTime(error) => error.message().into(),
+			Self::Duration(error) => error.message(),
+			#[cfg(any(feature = "jiff", feature = "chrono", feature = "time"))]
+			Self::Calendar(error) => error.message(),
+			Self::Encoding(error) => error.message(),
+			Self::Media(error) => error.message(),
+			Self::Number(error) => error.message(),
+			Self::OneOf(error) => error.message(),
+			Self::Unique(error) => error.message(),
+			Self::Variant(error) => error.message(),
+			Self::Sorted(error) => error.message(),
+			Self::Password(error) => error.message(),
 			#[cfg(feature = "email")]
 			Self::Email(error) => error.message().into(),
 			#[cfg(feature = "url")]
@@ -157,46 +312,74 @@ impl Error {
 			Self::Required(error) => error.message().into(),
 			#[cfg(feature = "uuid")]
 			Self::Uuid(error) => error.message().into(),
+			#[cfg(feature = "iso")]
+			Self::Iso(error) => error.message(),
 			#[cfg(feature = "credit_card")]
 			Self::CreditCard(error) => error.message().into(),
-			#[cfg(any(feature = "jiff", feature = "chrono"))]
-			Self::Time(error) => error.message().into(),
+			#[cfg(any(feature = "jiff", feature = "chrono", feature = "time"))]
+			Self::Time(error) => error.message(),
+			#[cfg(feature = "jiff")]
+			Self::TimeZone(error) => error.message().into(),
+			Self::UtcOffset(error) => error.message(),
 			#[cfg(feature = "alloc")]
 			Self::Custom { message, .. } => return message.as_deref().map(Cow::Borrowed),
 			#[cfg(not(feature = "alloc"))]
 			Self::Custom { message, .. } => return *message,
+			Self::Override {
+				message: Some(message),
+				..
+			} => Cow::Borrowed(message.as_ref()),
+			Self::Override { detail, .. } => return detail.message(),
 		})
 	}
 
 	#[cfg(not(feature = "alloc"))]
-	pub(crate) fn message(&self) -> Option<&str> {
+	pub(crate) fn message(&self) -> Option<&'static str> {
 		Some(match self {
-			Self::Alphanumeric(error) => error.message().into(),
-			Self::Ascii(error) => error.message().into(),
-			Self::Addr(error) => error.message().into(),
+			Self::Alphanumeric(error) => error.message(),
+			Self::Ascii(error) => error.message(),
+			Self::Addr(error) => error.message(),
 			Self::Lowercase(error) => error.message(),
 			Self::Uppercase(error) => error.message(),
 			Self::Contains(error) => error.message(),
 			Self::Prefix(error) => error.message(),
 			Self::Suffix(error) => error.message(),
 			Self::Equals(error) => error.message(),
+			Self::Document(error) => error.message(),
+			Self::DateTime(error) => error.message(),
+			Self::Duration(error) => error.message(),
+			#[cfg(any(feature = "jiff", feature = "chrono", feature = "time"))]
+			Self::Calendar(error) => error.message(),
+			Self::Encoding(error) => error.message(),
+			Self::Media(error) => error.message(),
+			Self::Number(error) => error.message(),
+			Self::OneOf(error) => error.message(),
+			Self::Unique(error) => error.message(),
+			Self::Variant(error) => error.message(),
+			Self::Sorted(error) => error.message(),
+			Self::Password(error) => error.message(),
 			#[cfg(feature = "email")]
-			Self::Email(error) => error.message().into(),
+			Self::Email(error) => error.message(),
 			#[cfg(feature = "url")]
-			Self::Url(error) => error.message().into(),
+			Self::Url(error) => error.message(),
 			Self::Length(error) => error.message(),
-			Self::Range(error) => error.message().into(),
+			Self::Range(error) => error.message(),
 			#[cfg(feature = "semver")]
-			Self::Semver(error) => error.message().into(),
+			Self::Semver(error) => error.message(),
 			#[cfg(feature = "regex")]
 			Self::Regex(error) => error.message(),
-			Self::Required(error) => error.message().into(),
+			Self::Required(error) => error.message(),
 			#[cfg(feature = "uuid")]
-			Self::Uuid(error) => error.message().into(),
+			Self::Uuid(error) => error.message(),
+			#[cfg(feature = "iso")]
+			Self::Iso(error) => error.message(),
 			#[cfg(feature = "credit_card")]
-			Self::CreditCard(error) => error.message().into(),
-			#[cfg(any(feature = "jiff", feature = "chrono"))]
-			Self::Time(error) => error.message().into(),
+			Self::CreditCard(error) => error.message(),
+			#[cfg(any(feature = "jiff", feature = "chrono", feature = "time"))]
+			Self::Time(error) => error.message(),
+			#[cfg(feature = "jiff")]
+			Self::TimeZone(error) => error.message(),
+			Self::UtcOffset(error) => error.message(),
 			#[cfg(feature = "alloc")]
 			Self::Custom { message, .. } => return message.as_deref().map(Cow::Borrowed),
 			#[cfg(not(feature = "alloc"))]
@@ -246,6 +429,17 @@ impl Report {
 	pub fn extend(&mut self, other: Self) {
 		self.errors.extend(other.errors);
 	}
+
+	/// Applies `f` to every error after the first `start`.
+	///
+	/// Used by the derive macro to override the code or message of the
+	/// errors reported by a single rule.
+	#[doc(hidden)]
+	pub fn map_from(&mut self, start: usize, f: impl Fn(Error) -> Error) {
+		for (_, error) in self.errors.iter_mut().skip(start) {
+			*error = f(core::mem::replace(error, Error::new("")));
+		}
+	}
 }
 
 #[cfg(not(feature = "alloc"))]
@@ -285,6 +479,17 @@ impl Report {
 			self.len += 1;
 		}
 	}
+
+	/// Applies `f` to every error after the first `start`.
+	///
+	/// Used by the derive macro to override the code or message of the
+	/// errors reported by a single rule.
+	#[doc(hidden)]
+	pub fn map_from(&mut self, start: usize, f: impl Fn(Error) -> Error) {
+		for (_, error) in self.errors.iter_mut().skip(start).flatten() {
+			*error = f(core::mem::replace(error, Error::new("")));
+		}
+	}
 }
 
 #[cfg(feature = "serde")]
@@ -317,7 +522,7 @@ mod ser {
 					path,
 					code: error.code(),
 					message: error.message(),
-					detail: error,
+					detail: error.detail(),
 				};
 
 				seq.serialize_element(&detail)?;
@@ -343,7 +548,7 @@ mod ser {
 						path,
 						code: error.code(),
 						message: error.message(),
-						detail: error,
+						detail: error.detail(),
 					};
 
 					seq.serialize_element(&detail)?;
@@ -354,3 +559,28 @@ mod ser {
 		}
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_error_override() {
+		let detail = Error::from(rule::required::Error::CannotBeEmpty);
+		let error = Error::from(rule::required::Error::CannotBeEmpty).override_code("missing");
+
+		assert_eq!(error.code(), "missing");
+		assert_eq!(error.message(), detail.message());
+		assert_eq!(error.detail(), &detail);
+
+		let error = error.override_message("Please fill in this field");
+
+		assert_eq!(error.code(), "missing");
+		assert_eq!(
+			error.message().as_deref(),
+			Some("Please fill in this field")
+		);
+		assert_eq!(error.detail(), &detail);
+	}
+}
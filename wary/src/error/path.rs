@@ -3,7 +3,12 @@
 use core::fmt;
 
 #[cfg(feature = "alloc")]
-use crate::alloc::{sync::Arc, vec::Vec};
+use crate::alloc::{
+	borrow::Cow,
+	string::{String, ToString},
+	sync::Arc,
+	vec::Vec,
+};
 
 #[cfg(feature = "alloc")]
 type ArcNode = Arc<Node>;
@@ -56,6 +61,12 @@ impl Path {
 		}
 	}
 
+	/// Appends the [`Display`](fmt::Display) output of a map key.
+	#[must_use]
+	pub fn append_key(&self, key: &impl fmt::Display) -> Self {
+		self.append(key.to_string())
+	}
+
 	#[must_use]
 	pub fn append<E: Into<Elem>>(&self, elem: E) -> Self {
 		let Self::NonEmpty { tail, head } = self else {
@@ -88,6 +99,12 @@ impl Path {
 		Self::NonEmpty { tail: (), head: () }
 	}
 
+	/// Appends the [`Display`](fmt::Display) output of a map key.
+	#[must_use]
+	pub fn append_key(&self, _key: &impl fmt::Display) -> Self {
+		Self::NonEmpty { tail: (), head: () }
+	}
+
 	#[must_use]
 	pub fn append<E: Into<Elem>>(&self, _elem: E) -> Self {
 		Self::NonEmpty { tail: (), head: () }
@@ -114,7 +131,7 @@ impl Iterator for Iter<'_> {
 
 		self.next = node.prev.as_deref();
 
-		Some(node.elem)
+		Some(node.elem.clone())
 	}
 
 	#[cfg(not(feature = "alloc"))]
@@ -174,12 +191,23 @@ impl serde::Serialize for Path {
 	}
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(untagged))]
 pub enum Elem {
+	/// A field name, or a map key.
+	#[cfg(feature = "alloc")]
+	Key(Cow<'static, str>),
+	/// A field name.
+	#[cfg(not(feature = "alloc"))]
 	Key(&'static str),
 	Index(usize),
+	/// Appended after a map key for errors in the key itself (from `keys`),
+	/// which tells them apart from errors in its value (from `values`).
+	///
+	/// Displayed as `$key` (e.g. `labels.App.$key`) and serialized as `null`,
+	/// so it never collides with a real key.
+	MapKey,
 }
 
 impl fmt::Display for Elem {
@@ -187,16 +215,39 @@ impl fmt::Display for Elem {
 		match self {
 			Self::Key(key) => write!(f, "{key}"),
 			Self::Index(index) => write!(f, "{index}"),
+			Self::MapKey => write!(f, "$key"),
 		}
 	}
 }
 
+#[cfg(feature = "alloc")]
+impl From<&'static str> for Elem {
+	fn from(key: &'static str) -> Self {
+		Self::Key(Cow::Borrowed(key))
+	}
+}
+
+#[cfg(not(feature = "alloc"))]
 impl From<&'static str> for Elem {
 	fn from(key: &'static str) -> Self {
 		Self::Key(key)
 	}
 }
 
+#[cfg(feature = "alloc")]
+impl From<String> for Elem {
+	fn from(key: String) -> Self {
+		Self::Key(Cow::Owned(key))
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl From<Cow<'static, str>> for Elem {
+	fn from(key: Cow<'static, str>) -> Self {
+		Self::Key(key)
+	}
+}
+
 impl From<usize> for Elem {
 	fn from(index: usize) -> Self {
 		Self::Index(index)
@@ -229,10 +280,131 @@ mod test {
 		let vec = path.collect();
 
 		assert_eq!(vec, vec![
-			Elem::Key("a"),
+			Elem::from("a"),
 			Elem::Index(1),
-			Elem::Key("b"),
-			Elem::Key("c"),
+			Elem::from("b"),
+			Elem::from("c"),
+		]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_path_rename() {
+		#[derive(Wary)]
+		#[wary(crate = "crate", rename_all = "camelCase")]
+		struct User {
+			#[validate(length(min = 1))]
+			display_name: &'static str,
+			#[validate(rename = "mail", length(min = 1))]
+			email_address: &'static str,
+			#[validate(dive)]
+			home_address: Address,
+		}
+
+		#[derive(Wary)]
+		#[wary(crate = "crate", rename_all = "SCREAMING-KEBAB-CASE")]
+		struct Address {
+			#[validate(length(min = 1))]
+			postal_code: &'static str,
+		}
+
+		let user = User {
+			display_name: "",
+			email_address: "",
+			home_address: Address { postal_code: "" },
+		};
+
+		let report = user.validate(&()).unwrap_err();
+
+		assert_eq!(paths(&report), vec![
+			vec![Elem::from("displayName")],
+			vec![Elem::from("mail")],
+			vec![Elem::from("homeAddress"), Elem::from("POSTAL-CODE")],
+		]);
+	}
+
+	#[cfg(all(feature = "alloc", feature = "serde"))]
+	#[test]
+	fn test_path_rename_serde() {
+		#[derive(Wary)]
+		#[wary(crate = "crate", rename_all = "camelCase")]
+		struct User {
+			#[validate(length(min = 1))]
+			display_name: &'static str,
+			#[serde(rename = "email")]
+			#[validate(length(min = 1))]
+			email_address: &'static str,
+			#[serde(rename(serialize = "Phone"))]
+			#[validate(length(min = 1))]
+			phone_number: &'static str,
+			#[serde(rename = "avatar")]
+			#[validate(rename = "picture", length(min = 1))]
+			avatar_url: &'static str,
+		}
+
+		let user = User {
+			display_name: "",
+			email_address: "",
+			phone_number: "",
+			avatar_url: "",
+		};
+
+		let report = user.validate(&()).unwrap_err();
+
+		assert_eq!(paths(&report), vec![
+			vec![Elem::from("displayName")],
+			vec![Elem::from("email")],
+			vec![Elem::from("phoneNumber")],
+			vec![Elem::from("picture")],
+		]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_path_map_keys() {
+		use std::collections::BTreeMap;
+
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		struct Labels {
+			#[validate(keys(lowercase), values(length(min = 1)))]
+			labels: BTreeMap<&'static str, &'static str>,
+		}
+
+		let labels = Labels {
+			labels: BTreeMap::from([("App", ""), ("a.b", "")]),
+		};
+
+		let report = labels.validate(&()).unwrap_err();
+
+		// errors in a key end with `Elem::MapKey`, and dotted keys stay a single
+		// element
+		assert_eq!(paths(&report), vec![
+			vec![Elem::from("labels"), Elem::from("App"), Elem::MapKey],
+			vec![Elem::from("labels"), Elem::from("App")],
+			vec![Elem::from("labels"), Elem::from("a.b"), Elem::MapKey],
+			vec![Elem::from("labels"), Elem::from("a.b")],
 		]);
+
+		// a real `$key` key is not mistaken for the marker
+		let labels = Labels {
+			labels: BTreeMap::from([("$key", "")]),
+		};
+
+		let report = labels.validate(&()).unwrap_err();
+
+		assert_eq!(paths(&report), vec![
+			vec![Elem::from("labels"), Elem::from("$key"), Elem::MapKey],
+			vec![Elem::from("labels"), Elem::from("$key")],
+		]);
+	}
+
+	#[cfg(feature = "alloc")]
+	fn paths(report: &crate::Report) -> Vec<Vec<Elem>> {
+		report
+			.errors
+			.iter()
+			.map(|(path, _)| path.clone().collect())
+			.collect()
 	}
 }
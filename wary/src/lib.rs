@@ -58,6 +58,38 @@ pub mod internal {
 
 	#[cfg(feature = "regex")]
 	pub use init_regex;
+
+	/// Calls the function of `#[validate(skip_if = ...)]`, constraining it so
+	/// closures like `|ctx, item| item.is_empty()` can be written without type
+	/// annotations.
+	#[inline]
+	pub fn skip_if<C: ?Sized, T: ?Sized>(ctx: &C, item: &T, f: impl FnOnce(&C, &T) -> bool) -> bool {
+		f(ctx, item)
+	}
+
+	/// Constrains the key function of `#[validate(unique(by = ...))]` to
+	/// borrow from the items of `collection`, so closures like
+	/// `|item| &item.id` can be written without type annotations.
+	#[inline]
+	pub fn borrowed_key<C, K, F>(_collection: &C, by: F) -> F
+	where
+		C: crate::AsSlice + ?Sized,
+		K: ?Sized,
+		F: Fn(&C::Item) -> &K,
+	{
+		by
+	}
+
+	/// Like [`borrowed_key`], for the owned keys of
+	/// `#[validate(sorted(by = ...))]`, such as `|item| item.price`.
+	#[inline]
+	pub fn owned_key<C, K, F>(_collection: &C, by: F) -> F
+	where
+		C: crate::AsSlice + ?Sized,
+		F: Fn(&C::Item) -> K,
+	{
+		by
+	}
 }
 
 pub mod toolbox {
@@ -78,7 +110,7 @@ pub mod toolbox {
 			vec,
 			vec::Vec,
 		};
-		pub use crate::{options::Unset, AsMut, AsRef, AsSlice, Error, Report};
+		pub use crate::{options::Unset, AsIter, AsMap, AsMut, AsRef, AsSlice, Error, Report, StrList};
 		#[allow(missing_docs)]
 		pub type Result<T> = core::result::Result<T, Error>;
 	}
@@ -188,6 +220,16 @@ pub trait Rule<I: ?Sized> {
 	///
 	/// Returns an error if the item does not pass validation.
 	fn validate(&self, ctx: &Self::Context, item: &I) -> Result<(), Error>;
+
+	/// Validates the item and appends all errors to the attached [`Report`].
+	///
+	/// Rules that can find more than one error (such as one per element of a
+	/// collection) override this to report each of them at its own path.
+	fn validate_into(&self, ctx: &Self::Context, item: &I, parent: &Path, report: &mut Report) {
+		if let Err(error) = self.validate(ctx, item) {
+			report.push(parent.clone(), error);
+		}
+	}
 }
 
 pub trait AsyncRule<I: ?Sized> {
@@ -419,6 +461,36 @@ impl AsSlice for String {
 	}
 }
 
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> AsSlice for smallvec::SmallVec<A> {
+	type Item = A::Item;
+
+	#[inline]
+	fn as_slice(&self) -> &[Self::Item] {
+		self
+	}
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const N: usize> AsSlice for arrayvec::ArrayVec<T, N> {
+	type Item = T;
+
+	#[inline]
+	fn as_slice(&self) -> &[Self::Item] {
+		self
+	}
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> AsSlice for heapless::Vec<T, N> {
+	type Item = T;
+
+	#[inline]
+	fn as_slice(&self) -> &[Self::Item] {
+		self
+	}
+}
+
 /// Trait for cheap reference-to-slice conversion with mutability.
 ///
 /// Similar to [`AsSlice`], but mutable.
@@ -465,3 +537,246 @@ impl<const N: usize, T> AsMutSlice for [T; N] {
 		self
 	}
 }
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> AsMutSlice for smallvec::SmallVec<A> {
+	#[inline]
+	fn as_mut_slice(&mut self) -> &mut [Self::Item] {
+		self
+	}
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const N: usize> AsMutSlice for arrayvec::ArrayVec<T, N> {
+	#[inline]
+	fn as_mut_slice(&mut self) -> &mut [Self::Item] {
+		self
+	}
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> AsMutSlice for heapless::Vec<T, N> {
+	#[inline]
+	fn as_mut_slice(&mut self) -> &mut [Self::Item] {
+		self
+	}
+}
+
+/// Trait for iterating over the items of a collection.
+///
+/// This trait is implemented for every [`AsSlice`] type, as well as
+/// collections that are not stored contiguously like
+/// [`VecDeque`](alloc::collections::VecDeque),
+/// [`LinkedList`](alloc::collections::LinkedList),
+/// [`BTreeSet`](alloc::collections::BTreeSet) and
+/// [`HashSet`](std::collections::HashSet).
+pub trait AsIter {
+	/// An item of the collection.
+	type Item;
+
+	/// Returns an iterator over the items of the collection.
+	fn items(&self) -> impl Iterator<Item = &Self::Item>;
+}
+
+impl<T> AsIter for T
+where
+	T: AsSlice + ?Sized,
+{
+	type Item = T::Item;
+
+	#[inline]
+	fn items(&self) -> impl Iterator<Item = &Self::Item> {
+		self.as_slice().iter()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsIter for alloc::collections::VecDeque<T> {
+	type Item = T;
+
+	#[inline]
+	fn items(&self) -> impl Iterator<Item = &Self::Item> {
+		self.iter()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsIter for alloc::collections::LinkedList<T> {
+	type Item = T;
+
+	#[inline]
+	fn items(&self) -> impl Iterator<Item = &Self::Item> {
+		self.iter()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsIter for alloc::collections::BTreeSet<T> {
+	type Item = T;
+
+	#[inline]
+	fn items(&self) -> impl Iterator<Item = &Self::Item> {
+		self.iter()
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T, S> AsIter for std::collections::HashSet<T, S> {
+	type Item = T;
+
+	#[inline]
+	fn items(&self) -> impl Iterator<Item = &Self::Item> {
+		self.iter()
+	}
+}
+
+/// Trait for iterating over the entries of a map.
+///
+/// This trait is used for validating the keys and values of maps like
+/// [`BTreeMap`](alloc::collections::BTreeMap),
+/// [`HashMap`](std::collections::HashMap) and `IndexMap` (with the `indexmap`
+/// feature).
+///
+/// Errors from both `keys` and `values` are reported at the key of the entry,
+/// so the `Key` must implement [`Display`](core::fmt::Display) to be used with
+/// either rule.
+pub trait AsMap {
+	/// A key of the map.
+	type Key;
+	/// A value of the map.
+	type Value;
+
+	/// Returns an iterator over the entries of the map.
+	fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)>;
+}
+
+impl<T> AsMap for &T
+where
+	T: AsMap + ?Sized,
+{
+	type Key = T::Key;
+	type Value = T::Value;
+
+	#[inline]
+	fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+		(**self).entries()
+	}
+}
+
+impl<T> AsMap for &mut T
+where
+	T: AsMap + ?Sized,
+{
+	type Key = T::Key;
+	type Value = T::Value;
+
+	#[inline]
+	fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+		(**self).entries()
+	}
+}
+
+impl<T> AsMap for Option<T>
+where
+	T: AsMap,
+{
+	type Key = T::Key;
+	type Value = T::Value;
+
+	#[inline]
+	fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+		self.iter().flat_map(AsMap::entries)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<K, V> AsMap for alloc::collections::BTreeMap<K, V> {
+	type Key = K;
+	type Value = V;
+
+	#[inline]
+	fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+		self.iter()
+	}
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> AsMap for std::collections::HashMap<K, V, S> {
+	type Key = K;
+	type Value = V;
+
+	#[inline]
+	fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+		self.iter()
+	}
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V, S> AsMap for indexmap::IndexMap<K, V, S> {
+	type Key = K;
+	type Value = V;
+
+	#[inline]
+	fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+		self.iter()
+	}
+}
+
+/// Trait for the lists of strings that rules take as options, such as the
+/// allowed media types of [`mime`](options::rule::mime) or the values denied
+/// by [`password`](options::rule::password).
+///
+/// This trait is implemented for slices, arrays and vectors of strings, and
+/// for [`Unset`](options::Unset), which is no list at all.
+pub trait StrList {
+	/// Returns the strings of the list, or `None` if the list is not set.
+	fn strs(&self) -> Option<impl Iterator<Item = &str>>;
+}
+
+impl StrList for options::Unset {
+	#[inline]
+	fn strs(&self) -> Option<impl Iterator<Item = &str>> {
+		None::<core::iter::Empty<&str>>
+	}
+}
+
+impl<T> StrList for [T]
+where
+	T: core::convert::AsRef<str>,
+{
+	#[inline]
+	fn strs(&self) -> Option<impl Iterator<Item = &str>> {
+		Some(self.iter().map(core::convert::AsRef::as_ref))
+	}
+}
+
+impl<T, const N: usize> StrList for [T; N]
+where
+	T: core::convert::AsRef<str>,
+{
+	#[inline]
+	fn strs(&self) -> Option<impl Iterator<Item = &str>> {
+		self.as_slice().strs()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T> StrList for Vec<T>
+where
+	T: core::convert::AsRef<str>,
+{
+	#[inline]
+	fn strs(&self) -> Option<impl Iterator<Item = &str>> {
+		self.as_slice().strs()
+	}
+}
+
+impl<T> StrList for &T
+where
+	T: StrList + ?Sized,
+{
+	#[inline]
+	fn strs(&self) -> Option<impl Iterator<Item = &str>> {
+		(**self).strs()
+	}
+}
@@ -19,22 +19,53 @@ pub mod rule {
 	pub mod url;
 	pub use alphanumeric as alnum;
 	pub mod addr;
+	#[cfg(any(feature = "jiff", feature = "chrono", feature = "time"))]
+	pub mod calendar;
+	#[cfg(any(feature = "jiff", feature = "chrono", feature = "time"))]
+	pub use calendar::{age, time_of_day, weekday};
 	pub mod contains;
 	pub mod equals;
 	pub use equals as eq;
 	#[cfg(feature = "credit_card")]
 	pub mod credit_card;
+	pub mod datetime;
+	pub mod document;
+	pub mod duration;
+	pub use document::json;
+	#[cfg(feature = "toml")]
+	pub use document::toml;
+	#[cfg(feature = "yaml")]
+	pub use document::yaml;
+	pub mod encoding;
+	pub use encoding::{base32, base58, base64, hex};
+	#[cfg(feature = "iso")]
+	pub mod iso;
+	pub mod media;
+	pub use media::{magic, mime};
+	pub mod none_of;
+	pub mod number;
+	pub use number::{decimals, digits, finite, multiple_of};
+	pub mod one_of;
+	pub mod password;
+	#[cfg(feature = "iso")]
+	pub use iso::{country, currency, language, locale};
 	pub mod prefix;
 	#[cfg(feature = "regex")]
 	pub mod regex;
 	pub mod required;
 	#[cfg(feature = "semver")]
 	pub mod semver;
+	pub mod sorted;
 	pub mod suffix;
-	#[cfg(any(feature = "jiff", feature = "chrono"))]
+	#[cfg(any(feature = "jiff", feature = "chrono", feature = "time"))]
 	pub mod time;
+	#[cfg(feature = "jiff")]
+	pub mod timezone;
+	pub mod unique;
+	pub mod utc_offset;
 	#[cfg(feature = "uuid")]
 	pub mod uuid;
+	pub mod variant;
 
 	pub mod and;
 	pub mod custom;
@@ -42,12 +73,19 @@ pub mod rule {
 	pub mod dive;
 	pub mod func;
 	pub mod inner;
+	pub mod keys;
 	pub mod or;
+	pub mod skip;
+	pub mod values;
 }
 
 pub mod transformer {
 	pub use super::{lowercase, uppercase};
 
+	#[cfg(any(feature = "jiff", feature = "chrono", feature = "time"))]
+	pub mod time;
+	#[cfg(any(feature = "jiff", feature = "chrono", feature = "time"))]
+	pub use time::{round, to_utc, truncate};
 	#[cfg(feature = "alloc")]
 	pub mod trim;
 
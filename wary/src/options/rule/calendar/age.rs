@@ -0,0 +1,205 @@
+//! Rule for age validation.
+//!
+//! See [`AgeRule`] for more information.
+
+use super::{civil_from_days, CivilDate, Error};
+use crate::{
+	options::rule::time::{Clock, MaybeClock},
+	toolbox::rule::*,
+};
+
+#[doc(hidden)]
+pub type Rule<C> = AgeRule<C>;
+
+/// Returns the completed years between a birth date and a date.
+///
+/// Someone born on February 29th has their birthday on March 1st in years
+/// that are not leap years.
+fn completed_years(birth: (i32, u8, u8), today: (i32, u8, u8)) -> i64 {
+	let years = i64::from(today.0) - i64::from(birth.0);
+
+	if (today.1, today.2) < (birth.1, birth.2) {
+		years - 1
+	} else {
+		years
+	}
+}
+
+/// Rule for age validation from a birth date.
+///
+/// Works with any [`CivilDate`], such as the date types of `jiff`, `chrono`
+/// and `time`. The age is the number of completed years between the birth date
+/// and the current date in UTC, which comes from the system clock unless a
+/// [`Clock`] is given with `clock` (such as the context, with `clock = ctx`).
+///
+/// # Example
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use wary::{options::rule::time::Clock, Wary, Validate};
+///
+/// struct Context {
+///   now: Duration,
+/// }
+///
+/// impl Clock for Context {
+///   fn now(&self) -> Duration {
+///     self.now
+///   }
+/// }
+///
+/// #[derive(Wary)]
+/// #[wary(context = Context)]
+/// struct Person {
+///   #[validate(age(min = 18, max = 130, clock = ctx))]
+///   birth_date: jiff::civil::Date,
+/// }
+///
+/// // 2025-05-04
+/// let ctx = Context {
+///   now: Duration::from_secs(1_746_333_380),
+/// };
+///
+/// let person = Person {
+///   birth_date: jiff::civil::date(2007, 5, 4),
+/// };
+///
+/// assert!(person.validate(&ctx).is_ok());
+///
+/// let person = Person {
+///   birth_date: jiff::civil::date(2007, 5, 5),
+/// };
+///
+/// assert!(person.validate(&ctx).is_err());
+/// ```
+#[must_use]
+pub struct AgeRule<C> {
+	min: Option<u32>,
+	max: Option<u32>,
+	clock: C,
+}
+
+impl AgeRule<Unset> {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			min: None,
+			max: None,
+			clock: Unset,
+		}
+	}
+
+	/// Set the clock used to get the current date.
+	#[inline]
+	pub fn clock<C>(self, clock: C) -> AgeRule<C>
+	where
+		C: Clock,
+	{
+		AgeRule {
+			min: self.min,
+			max: self.max,
+			clock,
+		}
+	}
+}
+
+impl<C> AgeRule<C> {
+	/// Set the minimum age (inclusive).
+	#[inline]
+	pub fn min(mut self, min: u32) -> Self {
+		self.min = Some(min);
+		self
+	}
+
+	/// Set the maximum age (inclusive).
+	#[inline]
+	pub fn max(mut self, max: u32) -> Self {
+		self.max = Some(max);
+		self
+	}
+
+	/// Set the maximum age (exclusive).
+	#[inline]
+	pub fn exclusive_max(mut self, max: u32) -> Self {
+		self.max = Some(max.saturating_sub(1));
+		self
+	}
+}
+
+impl<I: ?Sized, C> crate::Rule<I> for AgeRule<C>
+where
+	I: CivilDate,
+	C: MaybeClock,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let now = self.clock.try_now().ok_or(Error::NoClock)?;
+		let today = civil_from_days(i64::try_from(now.as_secs() / 86_400).unwrap_or(i64::MAX));
+		let age = completed_years(item.civil_date(), today);
+
+		if age < 0 {
+			return Err(Error::Unborn.into());
+		}
+
+		if let Some(min) = self.min {
+			if age < i64::from(min) {
+				return Err(Error::TooYoung { min }.into());
+			}
+		}
+
+		if let Some(max) = self.max {
+			if age > i64::from(max) {
+				return Err(Error::TooOld { max }.into());
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::completed_years;
+
+	#[test]
+	fn test_completed_years() {
+		assert_eq!(completed_years((2000, 2, 29), (2018, 2, 28)), 17);
+		assert_eq!(completed_years((2000, 2, 29), (2018, 3, 1)), 18);
+		assert_eq!(completed_years((2000, 2, 29), (2020, 2, 29)), 20);
+		assert_eq!(completed_years((2000, 6, 15), (2000, 6, 14)), -1);
+	}
+
+	#[cfg(feature = "jiff")]
+	#[test]
+	fn test_age_rule() {
+		use core::time::Duration;
+
+		use super::AgeRule;
+		use crate::{
+			options::rule::{calendar::Error, time::FixedClock},
+			toolbox::test::*,
+		};
+
+		// 2025-05-04
+		let rule = AgeRule::new()
+			.min(18)
+			.max(130)
+			.clock(FixedClock(Duration::from_secs(1_746_333_380)));
+
+		assert!(rule.validate(&(), &jiff::civil::date(2007, 5, 4)).is_ok());
+		assert_eq!(
+			rule.validate(&(), &jiff::civil::date(2007, 5, 5)),
+			Err(Error::TooYoung { min: 18 }.into())
+		);
+		assert_eq!(
+			rule.validate(&(), &jiff::civil::date(1894, 5, 3)),
+			Err(Error::TooOld { max: 130 }.into())
+		);
+		assert_eq!(
+			rule.validate(&(), &jiff::civil::date(2026, 1, 1)),
+			Err(Error::Unborn.into())
+		);
+	}
+}
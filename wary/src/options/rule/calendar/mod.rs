@@ -0,0 +1,345 @@
+//! Rules for validating dates and times against the calendar.
+//!
+//! Each rule lives in its own module (re-exported at the top level of
+//! [`rule`](crate::options::rule)) and shares the [`Error`] type defined here.
+//!
+//! - [`age`] (completed years since a birth date)
+//! - [`time_of_day`] (a window of the day, such as business hours)
+//! - [`weekday`] (allowed days of the week)
+
+pub mod age;
+pub mod time_of_day;
+pub mod weekday;
+
+use core::fmt;
+
+pub use self::weekday::Weekday;
+pub(crate) use super::datetime::days_from_civil;
+use crate::toolbox::rule::*;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Error {
+	#[error("expected an age of at least {min}")]
+	TooYoung { min: u32 },
+	#[error("expected an age of at most {max}")]
+	TooOld { max: u32 },
+	#[error("birth date is in the future")]
+	Unborn,
+	#[error("the current time is not available")]
+	NoClock,
+	#[error("{weekday} is not allowed")]
+	WeekdayNotAllowed { weekday: Weekday },
+	#[error("expected a time between {} and {}", Hm(*start), Hm(*end))]
+	OutsideTimeOfDay { start: u64, end: u64 },
+	#[error("time of day bound is not valid")]
+	InvalidTimeOfDayBound,
+}
+
+impl Error {
+	#[must_use]
+	pub(crate) fn code(&self) -> &'static str {
+		match self {
+			Self::TooYoung { .. } => "age_too_young",
+			Self::TooOld { .. } => "age_too_old",
+			Self::Unborn => "unborn",
+			Self::NoClock => "no_clock",
+			Self::WeekdayNotAllowed { .. } => "weekday_not_allowed",
+			Self::OutsideTimeOfDay { .. } => "time_of_day_not_allowed",
+			Self::InvalidTimeOfDayBound => "invalid_time_of_day_bound",
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub(crate) fn message(&self) -> Cow<'static, str> {
+		match self {
+			Self::TooYoung { min } => format!("expected an age of at least {min}").into(),
+			Self::TooOld { max } => format!("expected an age of at most {max}").into(),
+			Self::Unborn => "birth date is in the future".into(),
+			Self::NoClock => "the current time is not available".into(),
+			Self::WeekdayNotAllowed { weekday } => format!("{weekday} is not allowed").into(),
+			Self::OutsideTimeOfDay { start, end } => {
+				format!("expected a time between {} and {}", Hm(*start), Hm(*end)).into()
+			}
+			Self::InvalidTimeOfDayBound => "time of day bound is not valid".into(),
+		}
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	pub(crate) fn message(&self) -> &'static str {
+		match self {
+			Self::TooYoung { .. } => "age is too young",
+			Self::TooOld { .. } => "age is too old",
+			Self::Unborn => "birth date is in the future",
+			Self::NoClock => "the current time is not available",
+			Self::WeekdayNotAllowed { .. } => "weekday is not allowed",
+			Self::OutsideTimeOfDay { .. } => "time of day is not allowed",
+			Self::InvalidTimeOfDayBound => "time of day bound is not valid",
+		}
+	}
+}
+
+/// Formats nanoseconds since midnight as `HH:MM` (or `HH:MM:SS`).
+struct Hm(u64);
+
+impl fmt::Display for Hm {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let seconds = self.0 / NANOS_PER_SECOND;
+
+		write!(f, "{:02}:{:02}", seconds / 3600, seconds / 60 % 60)?;
+
+		if seconds % 60 != 0 {
+			write!(f, ":{:02}", seconds % 60)?;
+		}
+
+		Ok(())
+	}
+}
+
+/// A value with a calendar date.
+///
+/// Values with a time zone use their local date, and timestamps use the date
+/// in UTC.
+pub trait CivilDate {
+	/// Returns the year, month (1 to 12) and day (1 to 31).
+	fn civil_date(&self) -> (i32, u8, u8);
+}
+
+impl<T: ?Sized> CivilDate for &T
+where
+	T: CivilDate,
+{
+	fn civil_date(&self) -> (i32, u8, u8) {
+		(**self).civil_date()
+	}
+}
+
+/// A value with a time of day.
+///
+/// Values with a time zone use their local time.
+pub trait TimeOfDay {
+	/// Returns the nanoseconds since midnight.
+	fn nanos_since_midnight(&self) -> u64;
+}
+
+impl<T: ?Sized> TimeOfDay for &T
+where
+	T: TimeOfDay,
+{
+	fn nanos_since_midnight(&self) -> u64 {
+		(**self).nanos_since_midnight()
+	}
+}
+
+fn nanos_since_midnight(hour: u8, minute: u8, second: u8, nanosecond: u32) -> u64 {
+	(u64::from(hour) * 3600 + u64::from(minute) * 60 + u64::from(second)) * NANOS_PER_SECOND
+		+ u64::from(nanosecond)
+}
+
+/// The year, month and day of a number of days since the Unix epoch, from
+/// Howard Hinnant's `civil_from_days`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(crate) fn civil_from_days(days: i64) -> (i32, u8, u8) {
+	let days = days + 719_468;
+	let era = days.div_euclid(146_097);
+	let day_of_era = days - era * 146_097;
+	let year_of_era =
+		(day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let month = (5 * day_of_year + 2) / 153;
+	let day = day_of_year - (153 * month + 2) / 5 + 1;
+	let month = if month < 10 { month + 3 } else { month - 9 };
+	let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+	(year as i32, month as u8, day as u8)
+}
+
+#[cfg(feature = "jiff")]
+mod jiff_ {
+	use super::*;
+
+	impl CivilDate for jiff::civil::Date {
+		fn civil_date(&self) -> (i32, u8, u8) {
+			(
+				i32::from(self.year()),
+				self.month().unsigned_abs(),
+				self.day().unsigned_abs(),
+			)
+		}
+	}
+
+	impl CivilDate for jiff::civil::DateTime {
+		fn civil_date(&self) -> (i32, u8, u8) {
+			self.date().civil_date()
+		}
+	}
+
+	impl CivilDate for jiff::Zoned {
+		fn civil_date(&self) -> (i32, u8, u8) {
+			self.date().civil_date()
+		}
+	}
+
+	impl CivilDate for jiff::Timestamp {
+		fn civil_date(&self) -> (i32, u8, u8) {
+			self.to_zoned(jiff::tz::TimeZone::UTC).civil_date()
+		}
+	}
+
+	impl TimeOfDay for jiff::civil::Time {
+		fn nanos_since_midnight(&self) -> u64 {
+			nanos_since_midnight(
+				self.hour().unsigned_abs(),
+				self.minute().unsigned_abs(),
+				self.second().unsigned_abs(),
+				self.subsec_nanosecond().unsigned_abs(),
+			)
+		}
+	}
+
+	impl TimeOfDay for jiff::civil::DateTime {
+		fn nanos_since_midnight(&self) -> u64 {
+			self.time().nanos_since_midnight()
+		}
+	}
+
+	impl TimeOfDay for jiff::Zoned {
+		fn nanos_since_midnight(&self) -> u64 {
+			self.time().nanos_since_midnight()
+		}
+	}
+
+	impl TimeOfDay for jiff::Timestamp {
+		fn nanos_since_midnight(&self) -> u64 {
+			self
+				.to_zoned(jiff::tz::TimeZone::UTC)
+				.nanos_since_midnight()
+		}
+	}
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_ {
+	use chrono::{Datelike, Timelike};
+
+	use super::*;
+
+	fn civil_date(date: &impl Datelike) -> (i32, u8, u8) {
+		(
+			date.year(),
+			u8::try_from(date.month()).unwrap_or_default(),
+			u8::try_from(date.day()).unwrap_or_default(),
+		)
+	}
+
+	/// Leap seconds are counted as the last nanosecond of the second before.
+	fn time_of_day(time: &impl Timelike) -> u64 {
+		u64::from(time.num_seconds_from_midnight()) * NANOS_PER_SECOND
+			+ u64::from(time.nanosecond().min(999_999_999))
+	}
+
+	impl CivilDate for chrono::NaiveDate {
+		fn civil_date(&self) -> (i32, u8, u8) {
+			civil_date(self)
+		}
+	}
+
+	impl CivilDate for chrono::NaiveDateTime {
+		fn civil_date(&self) -> (i32, u8, u8) {
+			civil_date(self)
+		}
+	}
+
+	impl<Tz: chrono::TimeZone> CivilDate for chrono::DateTime<Tz> {
+		fn civil_date(&self) -> (i32, u8, u8) {
+			civil_date(self)
+		}
+	}
+
+	impl TimeOfDay for chrono::NaiveTime {
+		fn nanos_since_midnight(&self) -> u64 {
+			time_of_day(self)
+		}
+	}
+
+	impl TimeOfDay for chrono::NaiveDateTime {
+		fn nanos_since_midnight(&self) -> u64 {
+			time_of_day(self)
+		}
+	}
+
+	impl<Tz: chrono::TimeZone> TimeOfDay for chrono::DateTime<Tz> {
+		fn nanos_since_midnight(&self) -> u64 {
+			time_of_day(self)
+		}
+	}
+}
+
+#[cfg(feature = "time")]
+mod time_ {
+	use super::*;
+
+	impl CivilDate for ::time::Date {
+		fn civil_date(&self) -> (i32, u8, u8) {
+			(self.year(), u8::from(self.month()), self.day())
+		}
+	}
+
+	impl CivilDate for ::time::PrimitiveDateTime {
+		fn civil_date(&self) -> (i32, u8, u8) {
+			self.date().civil_date()
+		}
+	}
+
+	impl CivilDate for ::time::OffsetDateTime {
+		fn civil_date(&self) -> (i32, u8, u8) {
+			self.date().civil_date()
+		}
+	}
+
+	impl TimeOfDay for ::time::Time {
+		fn nanos_since_midnight(&self) -> u64 {
+			nanos_since_midnight(self.hour(), self.minute(), self.second(), self.nanosecond())
+		}
+	}
+
+	impl TimeOfDay for ::time::PrimitiveDateTime {
+		fn nanos_since_midnight(&self) -> u64 {
+			self.time().nanos_since_midnight()
+		}
+	}
+
+	impl TimeOfDay for ::time::OffsetDateTime {
+		fn nanos_since_midnight(&self) -> u64 {
+			self.time().nanos_since_midnight()
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{civil_from_days, days_from_civil, Hm};
+
+	#[test]
+	fn test_civil_from_days() {
+		assert_eq!(civil_from_days(0), (1970, 1, 1));
+		assert_eq!(civil_from_days(-1), (1969, 12, 31));
+		assert_eq!(civil_from_days(-719_468), (0, 3, 1));
+
+		for (year, month, day) in [(2024, 2, 29), (2100, 3, 1), (1600, 12, 31)] {
+			assert_eq!(
+				civil_from_days(days_from_civil(year, month, day)),
+				(year, month, day)
+			);
+		}
+	}
+
+	#[test]
+	fn test_hm() {
+		assert_eq!(Hm(9 * 3600 * 1_000_000_000).to_string(), "09:00");
+		assert_eq!(Hm(86_399 * 1_000_000_000).to_string(), "23:59:59");
+	}
+}
@@ -0,0 +1,180 @@
+//! Rule for time of day validation.
+//!
+//! See [`TimeOfDayRule`] for more information.
+
+use super::{nanos_since_midnight, Error, TimeOfDay};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = TimeOfDayRule;
+
+/// A bound of a [`TimeOfDayRule`].
+///
+/// Strings are parsed as `HH:MM` or `HH:MM:SS`.
+pub trait Bound {
+	/// Returns the nanoseconds since midnight, or `None` if the bound is not a
+	/// valid time of day.
+	fn nanos_since_midnight(self) -> Option<u64>;
+}
+
+impl Bound for &str {
+	fn nanos_since_midnight(self) -> Option<u64> {
+		let two_digits = |s: &str| -> Option<u8> {
+			let bytes = s.as_bytes();
+
+			(bytes.len() == 2 && bytes.iter().all(u8::is_ascii_digit))
+				.then(|| (bytes[0] - b'0') * 10 + (bytes[1] - b'0'))
+		};
+
+		let mut parts = self.split(':');
+		let hour = two_digits(parts.next()?)?;
+		let minute = two_digits(parts.next()?)?;
+		let second = parts.next().map_or(Some(0), two_digits)?;
+
+		if parts.next().is_some() || hour > 23 || minute > 59 || second > 59 {
+			return None;
+		}
+
+		Some(nanos_since_midnight(hour, minute, second, 0))
+	}
+}
+
+impl<T> Bound for T
+where
+	T: TimeOfDay,
+{
+	fn nanos_since_midnight(self) -> Option<u64> {
+		Some(TimeOfDay::nanos_since_midnight(&self))
+	}
+}
+
+/// Rule for time of day validation.
+///
+/// Works with any [`TimeOfDay`], such as the time types of `jiff`, `chrono`
+/// and `time`. The window set with `between` includes its start but not its
+/// end, and wraps around midnight if the start is after the end (such as
+/// `("22:00", "06:00")`).
+///
+/// # Errors
+///
+/// Bounds that are not a valid time of day are rejected at compile time when
+/// they are literals, and otherwise fail every validation with
+/// [`Error::InvalidTimeOfDayBound`].
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Appointment {
+///   #[validate(time_of_day(between = ("09:00", "17:30")))]
+///   starts_at: jiff::civil::DateTime,
+/// }
+///
+/// let appointment = Appointment {
+///   starts_at: jiff::civil::datetime(2025, 5, 5, 9, 0, 0, 0),
+/// };
+///
+/// assert!(appointment.validate(&()).is_ok());
+///
+/// let appointment = Appointment {
+///   starts_at: jiff::civil::datetime(2025, 5, 5, 17, 30, 0, 0),
+/// };
+///
+/// assert!(appointment.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct TimeOfDayRule {
+	between: Option<(u64, u64)>,
+	invalid_bound: bool,
+}
+
+impl TimeOfDayRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			between: None,
+			invalid_bound: false,
+		}
+	}
+
+	/// Set the allowed window, from `start` (inclusive) to `end` (exclusive).
+	#[inline]
+	pub fn between(mut self, (start, end): (impl Bound, impl Bound)) -> Self {
+		self.between = start.nanos_since_midnight().zip(end.nanos_since_midnight());
+		self.invalid_bound = self.between.is_none();
+		self
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for TimeOfDayRule
+where
+	I: TimeOfDay,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		if self.invalid_bound {
+			return Err(Error::InvalidTimeOfDayBound.into());
+		}
+
+		let Some((start, end)) = self.between else {
+			return Ok(());
+		};
+
+		let time = item.nanos_since_midnight();
+		let allowed = if start <= end {
+			start <= time && time < end
+		} else {
+			start <= time || time < end
+		};
+
+		if allowed {
+			Ok(())
+		} else {
+			Err(Error::OutsideTimeOfDay { start, end }.into())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::Bound;
+
+	#[test]
+	fn test_bound() {
+		assert_eq!("00:00".nanos_since_midnight(), Some(0));
+		assert_eq!(
+			"09:30:15".nanos_since_midnight(),
+			Some((9 * 3600 + 30 * 60 + 15) * 1_000_000_000)
+		);
+
+		for bound in ["24:00", "9:00", "09:60", "09:00:00:00", "09", ""] {
+			assert_eq!(bound.nanos_since_midnight(), None, "{bound}");
+		}
+	}
+
+	#[cfg(feature = "chrono")]
+	#[test]
+	fn test_time_of_day_rule() {
+		use super::{Error, TimeOfDayRule};
+		use crate::toolbox::test::*;
+
+		let time = |hour, minute| chrono::NaiveTime::from_hms_opt(hour, minute, 0).unwrap();
+		let rule = TimeOfDayRule::new().between(("22:00", time(6, 0)));
+
+		assert!(rule.validate(&(), &time(23, 0)).is_ok());
+		assert!(rule.validate(&(), &time(5, 59)).is_ok());
+		assert!(rule.validate(&(), &time(6, 0)).is_err());
+		assert!(rule.validate(&(), &time(12, 0)).is_err());
+
+		let start = String::from("9:00");
+		let rule = TimeOfDayRule::new().between((start.as_str(), "17:00"));
+
+		assert_eq!(
+			rule.validate(&(), &time(12, 0)),
+			Err(Error::InvalidTimeOfDayBound.into())
+		);
+	}
+}
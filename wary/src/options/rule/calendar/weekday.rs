@@ -0,0 +1,176 @@
+//! Rule for day of the week validation.
+//!
+//! See [`WeekdayRule`] for more information.
+
+use core::fmt;
+
+use super::{days_from_civil, CivilDate, Error};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = WeekdayRule;
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Weekday {
+	Mon,
+	Tue,
+	Wed,
+	Thu,
+	Fri,
+	Sat,
+	Sun,
+}
+
+impl Weekday {
+	const ALL: [Self; 7] = [
+		Self::Mon,
+		Self::Tue,
+		Self::Wed,
+		Self::Thu,
+		Self::Fri,
+		Self::Sat,
+		Self::Sun,
+	];
+
+	/// Returns the day of the week of a calendar date.
+	#[must_use]
+	pub fn of(date: &(impl CivilDate + ?Sized)) -> Self {
+		let (year, month, day) = date.civil_date();
+		// 1970-01-01 was a Thursday
+		let days = (days_from_civil(year, month, day) + 3).rem_euclid(7);
+
+		Self::ALL[usize::try_from(days).unwrap_or_default()]
+	}
+
+	const fn bit(self) -> u8 {
+		1 << self as u8
+	}
+}
+
+impl fmt::Display for Weekday {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Mon => "Monday",
+			Self::Tue => "Tuesday",
+			Self::Wed => "Wednesday",
+			Self::Thu => "Thursday",
+			Self::Fri => "Friday",
+			Self::Sat => "Saturday",
+			Self::Sun => "Sunday",
+		})
+	}
+}
+
+/// Rule for day of the week validation.
+///
+/// Works with any [`CivilDate`], such as the date and time types of `jiff`,
+/// `chrono` and `time`. By default, every day is allowed. Use `not` to deny
+/// days, or `only` to allow just some. With the derive macro, days can be
+/// written without the [`Weekday`] prefix, in short (`Sat`) or full
+/// (`Saturday`) form.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Appointment {
+///   #[validate(weekday(not = [Sat, Sun]))]
+///   day: jiff::civil::Date,
+/// }
+///
+/// let appointment = Appointment {
+///   day: jiff::civil::date(2025, 5, 5),
+/// };
+///
+/// assert!(appointment.validate(&()).is_ok());
+///
+/// let appointment = Appointment {
+///   day: jiff::civil::date(2025, 5, 4),
+/// };
+///
+/// assert!(appointment.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct WeekdayRule {
+	allowed: u8,
+}
+
+impl WeekdayRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self { allowed: 0x7f }
+	}
+
+	/// Deny these days.
+	#[inline]
+	pub fn not(mut self, days: impl IntoIterator<Item = Weekday>) -> Self {
+		for day in days {
+			self.allowed &= !day.bit();
+		}
+
+		self
+	}
+
+	/// Only allow these days.
+	#[inline]
+	pub fn only(mut self, days: impl IntoIterator<Item = Weekday>) -> Self {
+		self.allowed = days.into_iter().fold(0, |allowed, day| allowed | day.bit());
+		self
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for WeekdayRule
+where
+	I: CivilDate,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let weekday = Weekday::of(item);
+
+		if self.allowed & weekday.bit() == 0 {
+			return Err(Error::WeekdayNotAllowed { weekday }.into());
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(all(test, feature = "jiff"))]
+mod test {
+	use super::{Weekday, WeekdayRule};
+	use crate::{options::rule::calendar::Error, toolbox::test::*};
+
+	#[test]
+	fn test_weekday() {
+		assert_eq!(Weekday::of(&jiff::civil::date(1970, 1, 1)), Weekday::Thu);
+		assert_eq!(Weekday::of(&jiff::civil::date(2024, 2, 29)), Weekday::Thu);
+		assert_eq!(Weekday::of(&jiff::civil::date(1969, 12, 28)), Weekday::Sun);
+		assert_eq!(Weekday::of(&jiff::civil::date(2025, 5, 5)), Weekday::Mon);
+	}
+
+	#[test]
+	fn test_weekday_rule() {
+		let rule = WeekdayRule::new().not([Weekday::Sat, Weekday::Sun]);
+
+		assert!(rule.validate(&(), &jiff::civil::date(2025, 5, 9)).is_ok());
+		assert_eq!(
+			rule.validate(&(), &jiff::civil::date(2025, 5, 10)),
+			Err(
+				Error::WeekdayNotAllowed {
+					weekday: Weekday::Sat
+				}
+				.into()
+			)
+		);
+
+		let rule = WeekdayRule::new().only([Weekday::Wed]);
+
+		assert!(rule.validate(&(), &jiff::civil::date(2025, 5, 7)).is_ok());
+		assert!(rule.validate(&(), &jiff::civil::date(2025, 5, 8)).is_err());
+	}
+}
@@ -0,0 +1,666 @@
+//! Rule for date and time string validation.
+//!
+//! See [`DateTimeRule`] for more information.
+
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule<'f> = DateTimeRule<'f>;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Error {
+	#[error("expected a valid date or time")]
+	Invalid,
+	#[error("unsupported date or time format")]
+	UnsupportedFormat,
+	#[error("date or time is too early")]
+	TooEarly,
+	#[error("date or time is too late")]
+	TooLate,
+	#[error("date or time bound is not a point in time")]
+	InvalidBound,
+}
+
+impl Error {
+	#[must_use]
+	pub(crate) fn code(&self) -> &'static str {
+		match self {
+			Self::Invalid => "invalid_datetime",
+			Self::UnsupportedFormat => "unsupported_datetime_format",
+			Self::TooEarly => "datetime_too_early",
+			Self::TooLate => "datetime_too_late",
+			Self::InvalidBound => "invalid_datetime_bound",
+		}
+	}
+
+	pub(crate) fn message(&self) -> &'static str {
+		match self {
+			Self::Invalid => "expected a valid date or time",
+			Self::UnsupportedFormat => "unsupported date or time format",
+			Self::TooEarly => "date or time is too early",
+			Self::TooLate => "date or time is too late",
+			Self::InvalidBound => "date or time bound is not a point in time",
+		}
+	}
+}
+
+const MONTHS: [&str; 12] = [
+	"january",
+	"february",
+	"march",
+	"april",
+	"may",
+	"june",
+	"july",
+	"august",
+	"september",
+	"october",
+	"november",
+	"december",
+];
+
+/// The format of a date or time string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format<'f> {
+	/// RFC 3339, such as `2025-05-04T04:36:20.5+02:00`.
+	Rfc3339,
+	/// ISO 8601 calendar date, such as `2025-05-04`.
+	Iso8601Date,
+	/// A `strftime`-like format, such as `%Y-%m-%d %H:%M`.
+	Custom(&'f str),
+}
+
+/// A parsed date and time. Fields that are not part of the format keep their
+/// defaults (midnight on 1970-01-01, UTC).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Parsed {
+	year: i32,
+	month: u8,
+	day: u8,
+	hour: u8,
+	minute: u8,
+	second: u8,
+	nanosecond: u32,
+	offset: i32,
+}
+
+impl Default for Parsed {
+	fn default() -> Self {
+		Self {
+			year: 1970,
+			month: 1,
+			day: 1,
+			hour: 0,
+			minute: 0,
+			second: 0,
+			nanosecond: 0,
+			offset: 0,
+		}
+	}
+}
+
+fn is_leap_year(year: i32) -> bool {
+	year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+	match month {
+		2 if is_leap_year(year) => 29,
+		2 => 28,
+		4 | 6 | 9 | 11 => 30,
+		_ => 31,
+	}
+}
+
+/// Days since the Unix epoch, from Howard Hinnant's `days_from_civil`.
+pub(crate) fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+	let year = i64::from(year) - i64::from(month <= 2);
+	let era = year.div_euclid(400);
+	let year_of_era = year - era * 400;
+	let month = i64::from(month);
+	let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+	era * 146_097 + day_of_era - 719_468
+}
+
+impl Parsed {
+	fn is_valid(&self) -> bool {
+		(1..=12).contains(&self.month)
+			&& (1..=days_in_month(self.year, self.month)).contains(&self.day)
+			&& self.hour < 24
+			&& self.minute < 60
+			// leap seconds
+			&& self.second <= 60
+			&& self.nanosecond < 1_000_000_000
+	}
+
+	fn days(&self) -> i64 {
+		days_from_civil(self.year, self.month, self.day)
+	}
+
+	fn unix_nanos(&self) -> i128 {
+		let seconds = self.days() * 86_400
+			+ i64::from(self.hour) * 3600
+			+ i64::from(self.minute) * 60
+			+ i64::from(self.second)
+			- i64::from(self.offset);
+
+		i128::from(seconds) * 1_000_000_000 + i128::from(self.nanosecond)
+	}
+}
+
+struct Cursor<'i> {
+	input: &'i [u8],
+}
+
+impl Cursor<'_> {
+	fn byte(&mut self, byte: u8) -> Option<()> {
+		let (first, rest) = self.input.split_first()?;
+
+		(*first == byte).then(|| self.input = rest)
+	}
+
+	fn any(&mut self, bytes: &[u8]) -> Option<u8> {
+		let (first, rest) = self.input.split_first()?;
+
+		bytes.contains(first).then(|| {
+			self.input = rest;
+			*first
+		})
+	}
+
+	fn digits(&mut self, len: usize) -> Option<u32> {
+		let digits = self.input.get(..len)?;
+
+		if !digits.iter().all(u8::is_ascii_digit) {
+			return None;
+		}
+
+		self.input = &self.input[len..];
+
+		Some(
+			digits
+				.iter()
+				.fold(0, |acc, digit| acc * 10 + u32::from(digit - b'0')),
+		)
+	}
+
+	fn two_digits(&mut self) -> Option<u8> {
+		self.digits(2).and_then(|n| u8::try_from(n).ok())
+	}
+
+	fn year(&mut self) -> Option<i32> {
+		self.digits(4).and_then(|n| i32::try_from(n).ok())
+	}
+
+	/// Parses 1 to 9 digits of a fraction of a second.
+	fn fraction(&mut self) -> Option<u32> {
+		let len = self.input.iter().take_while(|b| b.is_ascii_digit()).count();
+
+		if !(1..=9).contains(&len) {
+			return None;
+		}
+
+		let nanos = self.digits(len)?;
+
+		Some(nanos * 10_u32.pow(9 - u32::try_from(len).ok()?))
+	}
+
+	/// Parses `±HH:MM`, `±HHMM` (unless `colon`) or (if `zulu`) `Z`.
+	fn offset(&mut self, zulu: bool, colon: bool) -> Option<i32> {
+		if zulu && self.any(b"Zz").is_some() {
+			return Some(0);
+		}
+
+		let sign = match self.any(b"+-")? {
+			b'+' => 1,
+			_ => -1,
+		};
+
+		let hours = self.two_digits()?;
+
+		if self.byte(b':').is_none() && colon {
+			return None;
+		}
+
+		let minutes = self.two_digits()?;
+
+		if hours > 23 || minutes > 59 {
+			return None;
+		}
+
+		Some(sign * (i32::from(hours) * 3600 + i32::from(minutes) * 60))
+	}
+
+	fn month_name(&mut self, full: bool) -> Option<u8> {
+		for (month, name) in (1..).zip(MONTHS) {
+			let name = if full { name } else { &name[..3] };
+
+			if let Some(prefix) = self.input.get(..name.len()) {
+				if prefix.eq_ignore_ascii_case(name.as_bytes()) {
+					self.input = &self.input[name.len()..];
+					return Some(month);
+				}
+			}
+		}
+
+		None
+	}
+
+	fn finish(&self) -> Option<()> {
+		self.input.is_empty().then_some(())
+	}
+}
+
+fn parse_date(cursor: &mut Cursor, parsed: &mut Parsed) -> Option<()> {
+	parsed.year = cursor.year()?;
+	cursor.byte(b'-')?;
+	parsed.month = cursor.two_digits()?;
+	cursor.byte(b'-')?;
+	parsed.day = cursor.two_digits()?;
+
+	Some(())
+}
+
+fn parse_time(cursor: &mut Cursor, parsed: &mut Parsed) -> Option<()> {
+	parsed.hour = cursor.two_digits()?;
+	cursor.byte(b':')?;
+	parsed.minute = cursor.two_digits()?;
+	cursor.byte(b':')?;
+	parsed.second = cursor.two_digits()?;
+
+	Some(())
+}
+
+fn parse_rfc3339(input: &str) -> Option<Parsed> {
+	let mut cursor = Cursor {
+		input: input.as_bytes(),
+	};
+	let mut parsed = Parsed::default();
+
+	parse_date(&mut cursor, &mut parsed)?;
+	cursor.any(b"Tt ")?;
+	parse_time(&mut cursor, &mut parsed)?;
+
+	if cursor.byte(b'.').is_some() {
+		parsed.nanosecond = cursor.fraction()?;
+	}
+
+	parsed.offset = cursor.offset(true, true)?;
+	cursor.finish()?;
+
+	Some(parsed)
+}
+
+fn parse_iso8601_date(input: &str) -> Option<Parsed> {
+	let mut cursor = Cursor {
+		input: input.as_bytes(),
+	};
+	let mut parsed = Parsed::default();
+
+	parse_date(&mut cursor, &mut parsed)?;
+	cursor.finish()?;
+
+	Some(parsed)
+}
+
+fn parse_custom(input: &str, format: &str) -> core::result::Result<Parsed, Error> {
+	let mut cursor = Cursor {
+		input: input.as_bytes(),
+	};
+	let mut parsed = Parsed::default();
+	let mut format = format.as_bytes().iter();
+
+	while let Some(&byte) = format.next() {
+		if byte != b'%' {
+			cursor.byte(byte).ok_or(Error::Invalid)?;
+			continue;
+		}
+
+		let field = match format.next().ok_or(Error::UnsupportedFormat)? {
+			b'Y' => cursor.year().map(|year| parsed.year = year),
+			b'm' => cursor.two_digits().map(|month| parsed.month = month),
+			b'd' => cursor.two_digits().map(|day| parsed.day = day),
+			b'H' => cursor.two_digits().map(|hour| parsed.hour = hour),
+			b'M' => cursor.two_digits().map(|minute| parsed.minute = minute),
+			b'S' => cursor.two_digits().map(|second| parsed.second = second),
+			b'f' => cursor.fraction().map(|nanos| parsed.nanosecond = nanos),
+			b'z' => cursor
+				.offset(false, false)
+				.map(|offset| parsed.offset = offset),
+			b'b' => cursor.month_name(false).map(|month| parsed.month = month),
+			b'B' => cursor.month_name(true).map(|month| parsed.month = month),
+			b'F' => parse_date(&mut cursor, &mut parsed),
+			b'T' => parse_time(&mut cursor, &mut parsed),
+			b'%' => cursor.byte(b'%'),
+			_ => return Err(Error::UnsupportedFormat),
+		};
+
+		field.ok_or(Error::Invalid)?;
+	}
+
+	cursor.finish().ok_or(Error::Invalid)?;
+
+	Ok(parsed)
+}
+
+impl Format<'_> {
+	fn parse(&self, input: &str) -> core::result::Result<Parsed, Error> {
+		let parsed = match self {
+			Self::Rfc3339 => parse_rfc3339(input).ok_or(Error::Invalid)?,
+			Self::Iso8601Date => parse_iso8601_date(input).ok_or(Error::Invalid)?,
+			Self::Custom(format) => parse_custom(input, format)?,
+		};
+
+		if parsed.is_valid() {
+			Ok(parsed)
+		} else {
+			Err(Error::Invalid)
+		}
+	}
+}
+
+/// A bound of a [`DateTimeRule`].
+///
+/// Strings are parsed as RFC 3339, or as an ISO 8601 date (at midnight UTC).
+/// With the `jiff` or `chrono` features, their date and time values can also
+/// be used.
+pub trait Bound {
+	/// Returns the nanoseconds since the Unix epoch, or `None` if the bound is
+	/// not a point in time.
+	fn unix_nanos(self) -> Option<i128>;
+}
+
+impl Bound for &str {
+	fn unix_nanos(self) -> Option<i128> {
+		[Format::Rfc3339, Format::Iso8601Date]
+			.iter()
+			.find_map(|format| format.parse(self).ok())
+			.map(|parsed| parsed.unix_nanos())
+	}
+}
+
+#[cfg(any(feature = "jiff", feature = "chrono", feature = "time"))]
+impl<T> Bound for T
+where
+	T: super::time::Instant,
+{
+	fn unix_nanos(self) -> Option<i128> {
+		super::time::Instant::unix_nanos(&self)
+	}
+}
+
+/// Rule for date and time string validation.
+///
+/// Strings are parsed as RFC 3339 by default, or as ISO 8601 dates with
+/// `iso8601_date`, or with a `strftime`-like `format`. A format supports
+/// `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`, `%f` (fraction of a second), `%z`
+/// (`+hhmm` or `+hh:mm`), `%b` and `%B` (month names), `%F` (`%Y-%m-%d`),
+/// `%T` (`%H:%M:%S`) and `%%`. Fields that are left out default to midnight
+/// on 1970-01-01, UTC.
+///
+/// The parsed value can be checked against bounds, like with
+/// [`time`](super::time).
+///
+/// # Errors
+///
+/// Bounds that are not a point in time (such as a string that is not a valid
+/// RFC 3339 date and time or ISO 8601 date) are rejected at compile time when
+/// they are literals, and otherwise fail every validation with
+/// [`Error::InvalidBound`].
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Event {
+///   #[validate(datetime(rfc3339, after = "2020-01-01"))]
+///   starts_at: String,
+///   #[validate(datetime(iso8601_date))]
+///   day: String,
+///   #[validate(datetime(format = "%d %b %Y"))]
+///   display: String,
+/// }
+///
+/// let event = Event {
+///   starts_at: "2025-05-04T04:36:20Z".into(),
+///   day: "2025-05-04".into(),
+///   display: "04 May 2025".into(),
+/// };
+///
+/// assert!(event.validate(&()).is_ok());
+///
+/// let event = Event {
+///   starts_at: "2019-12-31T23:59:59+01:00".into(),
+///   day: "2025-02-29".into(),
+///   display: "May 4, 2025".into(),
+/// };
+///
+/// assert_eq!(event.validate(&()).unwrap_err().len(), 3);
+/// ```
+#[must_use]
+pub struct DateTimeRule<'f> {
+	format: Format<'f>,
+	min: Option<i128>,
+	max: Option<i128>,
+	exclusive_min: bool,
+	exclusive_max: bool,
+	invalid_bound: bool,
+}
+
+impl<'f> DateTimeRule<'f> {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			format: Format::Rfc3339,
+			min: None,
+			max: None,
+			exclusive_min: false,
+			exclusive_max: false,
+			invalid_bound: false,
+		}
+	}
+
+	fn bound(&mut self, bound: impl Bound) -> Option<i128> {
+		let nanos = bound.unix_nanos();
+
+		self.invalid_bound |= nanos.is_none();
+		nanos
+	}
+
+	/// Parse the input as RFC 3339 (the default).
+	#[inline]
+	pub const fn rfc3339(mut self) -> Self {
+		self.format = Format::Rfc3339;
+		self
+	}
+
+	/// Parse the input as an ISO 8601 calendar date.
+	#[inline]
+	pub const fn iso8601_date(mut self) -> Self {
+		self.format = Format::Iso8601Date;
+		self
+	}
+
+	/// Parse the input with a `strftime`-like format.
+	#[inline]
+	pub const fn format(mut self, format: &'f str) -> Self {
+		self.format = Format::Custom(format);
+		self
+	}
+
+	/// After a specific time.
+	#[inline]
+	pub fn after(self, after: impl Bound) -> Self {
+		self.min(after)
+	}
+
+	/// Set the minimum value (inclusive).
+	#[inline]
+	pub fn min(mut self, min: impl Bound) -> Self {
+		self.min = self.bound(min);
+		self.exclusive_min = false;
+		self
+	}
+
+	/// Set the minimum value (exclusive).
+	#[inline]
+	pub fn exclusive_min(mut self, min: impl Bound) -> Self {
+		self.min = self.bound(min);
+		self.exclusive_min = true;
+		self
+	}
+
+	/// Before a specific time.
+	#[inline]
+	pub fn before(self, before: impl Bound) -> Self {
+		self.max(before)
+	}
+
+	/// Set the maximum value (inclusive).
+	#[inline]
+	pub fn max(mut self, max: impl Bound) -> Self {
+		self.max = self.bound(max);
+		self.exclusive_max = false;
+		self
+	}
+
+	/// Set the maximum value (exclusive).
+	#[inline]
+	pub fn exclusive_max(mut self, max: impl Bound) -> Self {
+		self.max = self.bound(max);
+		self.exclusive_max = true;
+		self
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for DateTimeRule<'_>
+where
+	I: AsRef<str>,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		if self.invalid_bound {
+			return Err(Error::InvalidBound.into());
+		}
+
+		let nanos = self.format.parse(item.as_ref())?.unix_nanos();
+
+		if let Some(min) = self.min {
+			if nanos < min || self.exclusive_min && nanos == min {
+				return Err(Error::TooEarly.into());
+			}
+		}
+
+		if let Some(max) = self.max {
+			if nanos > max || self.exclusive_max && nanos == max {
+				return Err(Error::TooLate.into());
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{DateTimeRule, Error, Format, Parsed};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_parse() {
+		let parsed = Format::Rfc3339
+			.parse("2025-05-04t04:36:20.25-01:30")
+			.unwrap();
+
+		assert_eq!(parsed, Parsed {
+			year: 2025,
+			month: 5,
+			day: 4,
+			hour: 4,
+			minute: 36,
+			second: 20,
+			nanosecond: 250_000_000,
+			offset: -5400,
+		});
+		assert_eq!(
+			parsed.unix_nanos(),
+			(1_746_333_380 + 5400) * 1_000_000_000 + 250_000_000
+		);
+		assert_eq!(Parsed::default().unix_nanos(), 0);
+		assert_eq!(
+			Format::Iso8601Date
+				.parse("1969-12-31")
+				.unwrap()
+				.unix_nanos(),
+			-86_400 * 1_000_000_000
+		);
+
+		for input in [
+			"2025-05-04T04:36:20",
+			"2025-05-04T04:36:20.Z",
+			"2025-05-04T24:00:00Z",
+			"2025-13-01T00:00:00Z",
+			"2025-5-4T00:00:00Z",
+			"2025-05-04T04:36:20+0100",
+		] {
+			assert_eq!(Format::Rfc3339.parse(input), Err(Error::Invalid), "{input}");
+		}
+
+		assert!(Format::Iso8601Date.parse("2024-02-29").is_ok());
+		assert!(Format::Iso8601Date.parse("2100-02-29").is_err());
+		assert!(Format::Iso8601Date.parse("2025-05-04T00:00:00Z").is_err());
+	}
+
+	#[test]
+	fn test_parse_custom() {
+		let format = Format::Custom("%B %d, %Y %T%z");
+
+		assert!(format.parse("May 04, 2025 04:36:20+0200").is_ok());
+		assert!(format.parse("may 04, 2025 04:36:20-02:00").is_ok());
+		assert!(format.parse("Jun 04, 2025 04:36:20+0200").is_err());
+
+		assert!(Format::Custom("%d/%m/%Y").parse("31/12/2025").is_ok());
+		assert!(Format::Custom("%d/%m/%Y").parse("31/12/2025 ").is_err());
+		assert!(Format::Custom("%H:%M:%S.%f").parse("23:59:60.123").is_ok());
+		assert!(Format::Custom("100%%").parse("100%").is_ok());
+		assert_eq!(
+			Format::Custom("%Q").parse("1"),
+			Err(Error::UnsupportedFormat)
+		);
+	}
+
+	#[test]
+	fn test_datetime_rule() {
+		let rule = DateTimeRule::new()
+			.min("2025-01-01")
+			.exclusive_max("2026-01-01T00:00:00+01:00");
+
+		assert!(rule.validate(&(), "2025-01-01T00:00:00Z").is_ok());
+		assert!(rule.validate(&(), "2025-12-31T22:59:59Z").is_ok());
+		assert!(rule.validate(&(), "2025-12-31T23:00:00Z").is_err());
+		assert_eq!(
+			rule.validate(&(), "2024-12-31T23:59:59Z"),
+			Err(Error::TooEarly.into())
+		);
+
+		let rule = DateTimeRule::new().iso8601_date().before("2025-05-04");
+
+		assert!(rule.validate(&(), "2025-05-03").is_ok());
+		assert!(rule.validate(&(), "2025-05-04").is_ok());
+		assert_eq!(rule.validate(&(), "2025-05-05"), Err(Error::TooLate.into()));
+
+		let bound = String::from("2025-1-1");
+		let rule = DateTimeRule::new().after(bound.as_str());
+
+		assert_eq!(
+			rule.validate(&(), "2025-05-04T04:36:20Z"),
+			Err(Error::InvalidBound.into())
+		);
+	}
+}
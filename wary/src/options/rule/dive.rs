@@ -30,4 +30,115 @@ mod test {
 
 		assert!(name.validate(&()).is_err());
 	}
+
+	#[test]
+	fn test_dive_generic() {
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		struct Item {
+			#[validate(ascii)]
+			name: &'static str,
+		}
+
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		struct Page<T> {
+			#[validate(inner(dive))]
+			items: Vec<T>,
+			#[validate(dive)]
+			first: Option<T>,
+		}
+
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		enum Either<L, R> {
+			Left(#[validate(dive)] L),
+			Right(#[validate(dive)] R),
+		}
+
+		let page = Page {
+			items: vec![Item { name: "a" }, Item { name: "😃" }],
+			first: Some(Item { name: "😃" }),
+		};
+
+		assert_eq!(page.validate(&()).unwrap_err().len(), 2);
+		assert!(Either::<Item, Item>::Left(Item { name: "a" })
+			.validate(&())
+			.is_ok());
+		assert!(Either::<Item, Item>::Right(Item { name: "😃" })
+			.validate(&())
+			.is_err());
+	}
+
+	#[test]
+	fn test_dive_bound() {
+		use core::fmt::Debug;
+
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		struct Item {
+			#[validate(ascii)]
+			name: &'static str,
+		}
+
+		#[derive(Wary)]
+		#[wary(
+			crate = "crate",
+			bound = "T: Validate<Context = ()> + Transform<Context = ()> + Debug"
+		)]
+		struct Wrapper<T> {
+			#[validate(dive, func = |_: &(), item: &T| {
+				assert!(!format!("{item:?}").is_empty());
+				Ok(())
+			})]
+			item: T,
+		}
+
+		impl Debug for Item {
+			fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+				f.write_str(self.name)
+			}
+		}
+
+		assert!(Wrapper {
+			item: Item { name: "a" }
+		}
+		.validate(&())
+		.is_ok());
+	}
+
+	#[test]
+	fn test_dive_split_bound() {
+		use core::fmt::Debug;
+
+		#[derive(Wary, Debug)]
+		#[wary(crate = "crate")]
+		struct Item {
+			#[validate(ascii)]
+			#[transform(lowercase)]
+			name: String,
+		}
+
+		// only the `Validate` bounds are replaced, so the `Transform` impl keeps
+		// its inferred `T: Transform` bound
+		#[derive(Wary)]
+		#[wary(crate = "crate", bound(validate = "T: Validate<Context = ()> + Debug"))]
+		struct Wrapper<T> {
+			#[validate(dive, func = |_: &(), item: &T| {
+				assert!(!format!("{item:?}").is_empty());
+				Ok(())
+			})]
+			#[transform(dive)]
+			item: T,
+		}
+
+		let mut wrapper = Wrapper {
+			item: Item { name: "A".into() },
+		};
+
+		assert!(wrapper.validate(&()).is_ok());
+
+		wrapper.transform(&());
+		assert_eq!(wrapper.item.name, "a");
+	}
 }
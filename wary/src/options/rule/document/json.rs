@@ -0,0 +1,382 @@
+//! Rule for JSON validation.
+//!
+//! See [`JsonRule`] for more information.
+
+use super::{Error, Top};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = JsonRule;
+
+/// The deepest nesting that can be validated without allocating. This matches
+/// the default recursion limit of `serde_json`.
+const MAX_DEPTH: usize = 128;
+
+/// Rule for JSON validation (RFC 8259).
+///
+/// The input is checked without allocating or building a value. Documents
+/// nested deeper than 128 levels are always rejected.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Metadata {
+///   #[validate(json)]
+///   any: String,
+///   #[validate(json(object, max_depth = 2))]
+///   labels: String,
+/// }
+///
+/// let metadata = Metadata {
+///   any: "42".into(),
+///   labels: r#"{"tags": ["a", "b"]}"#.into(),
+/// };
+///
+/// assert!(metadata.validate(&()).is_ok());
+///
+/// let metadata = Metadata {
+///   any: "{".into(),
+///   labels: r#"{"tags": [["a"]]}"#.into(),
+/// };
+///
+/// assert!(metadata.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct JsonRule {
+	top: Top,
+	max_depth: usize,
+}
+
+impl JsonRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			top: Top::Any,
+			max_depth: MAX_DEPTH,
+		}
+	}
+
+	/// Require an object at the top level.
+	#[inline]
+	pub const fn object(mut self) -> Self {
+		self.top = Top::Object;
+		self
+	}
+
+	/// Require an array at the top level.
+	#[inline]
+	pub const fn array(mut self) -> Self {
+		self.top = Top::Array;
+		self
+	}
+
+	/// Set the maximum nesting depth (inclusive).
+	#[inline]
+	pub const fn max_depth(mut self, max_depth: usize) -> Self {
+		self.max_depth = if max_depth < MAX_DEPTH {
+			max_depth
+		} else {
+			MAX_DEPTH
+		};
+		self
+	}
+
+	fn check(&self, input: &str) -> Result<()> {
+		let mut parser = Parser {
+			input: input.as_bytes(),
+			pos: 0,
+		};
+
+		// one bit per open container, set for objects and unset for arrays
+		let mut stack = 0u128;
+		let mut depth = 0;
+
+		parser.skip_whitespace();
+		let first = parser.peek();
+
+		'value: loop {
+			parser.skip_whitespace();
+
+			match parser.peek() {
+				Some(open @ (b'{' | b'[')) => {
+					if depth == self.max_depth {
+						return Err(
+							Error::TooDeep {
+								max: self.max_depth,
+							}
+							.into(),
+						);
+					}
+
+					parser.pos += 1;
+					stack = stack << 1 | u128::from(open == b'{');
+					depth += 1;
+
+					parser.skip_whitespace();
+
+					let close = if open == b'{' { b'}' } else { b']' };
+
+					if parser.peek() == Some(close) {
+						parser.pos += 1;
+						stack >>= 1;
+						depth -= 1;
+					} else {
+						if open == b'{' {
+							parser.key()?;
+						}
+
+						continue 'value;
+					}
+				}
+				Some(b'"') => parser.string()?,
+				Some(b't') => parser.literal(b"true")?,
+				Some(b'f') => parser.literal(b"false")?,
+				Some(b'n') => parser.literal(b"null")?,
+				Some(b'-' | b'0'..=b'9') => parser.number()?,
+				_ => return Err(parser.error()),
+			}
+
+			// a value was just completed, so close containers until another value is
+			// expected
+			loop {
+				parser.skip_whitespace();
+
+				if depth == 0 {
+					break 'value;
+				}
+
+				let object = stack & 1 == 1;
+
+				match parser.peek() {
+					Some(b',') => {
+						parser.pos += 1;
+
+						if object {
+							parser.skip_whitespace();
+							parser.key()?;
+						}
+
+						continue 'value;
+					}
+					Some(b'}') if object => {}
+					Some(b']') if !object => {}
+					_ => return Err(parser.error()),
+				}
+
+				parser.pos += 1;
+				stack >>= 1;
+				depth -= 1;
+			}
+		}
+
+		if parser.pos != parser.input.len() {
+			return Err(parser.error());
+		}
+
+		match (self.top, first) {
+			(Top::Any, _) | (Top::Object, Some(b'{')) | (Top::Array, Some(b'[')) => Ok(()),
+			(Top::Object, _) => Err(Error::ExpectedObject.into()),
+			(Top::Array, _) => Err(Error::ExpectedArray.into()),
+		}
+	}
+}
+
+struct Parser<'i> {
+	input: &'i [u8],
+	pos: usize,
+}
+
+impl Parser<'_> {
+	fn peek(&self) -> Option<u8> {
+		self.input.get(self.pos).copied()
+	}
+
+	fn error(&self) -> crate::Error {
+		Error::Syntax { offset: self.pos }.into()
+	}
+
+	fn skip_whitespace(&mut self) {
+		while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+			self.pos += 1;
+		}
+	}
+
+	fn expect(&mut self, byte: u8) -> Result<()> {
+		if self.peek() == Some(byte) {
+			self.pos += 1;
+			Ok(())
+		} else {
+			Err(self.error())
+		}
+	}
+
+	fn literal(&mut self, literal: &[u8]) -> Result<()> {
+		for &byte in literal {
+			self.expect(byte)?;
+		}
+
+		Ok(())
+	}
+
+	/// Parses an object key and the `:` that follows it.
+	fn key(&mut self) -> Result<()> {
+		self.string()?;
+		self.skip_whitespace();
+		self.expect(b':')
+	}
+
+	fn string(&mut self) -> Result<()> {
+		self.expect(b'"')?;
+
+		loop {
+			match self.peek() {
+				Some(b'"') => {
+					self.pos += 1;
+					return Ok(());
+				}
+				Some(b'\\') => {
+					self.pos += 1;
+
+					match self.peek() {
+						Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => self.pos += 1,
+						Some(b'u') => {
+							self.pos += 1;
+
+							for _ in 0..4 {
+								if !self.peek().is_some_and(|b| b.is_ascii_hexdigit()) {
+									return Err(self.error());
+								}
+
+								self.pos += 1;
+							}
+						}
+						_ => return Err(self.error()),
+					}
+				}
+				Some(0x00..=0x1f) | None => return Err(self.error()),
+				Some(_) => self.pos += 1,
+			}
+		}
+	}
+
+	/// Parses one or more ASCII digits.
+	fn digits(&mut self) -> Result<()> {
+		if !self.peek().is_some_and(|b| b.is_ascii_digit()) {
+			return Err(self.error());
+		}
+
+		while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+			self.pos += 1;
+		}
+
+		Ok(())
+	}
+
+	fn number(&mut self) -> Result<()> {
+		if self.peek() == Some(b'-') {
+			self.pos += 1;
+		}
+
+		if self.peek() == Some(b'0') {
+			self.pos += 1;
+		} else {
+			self.digits()?;
+		}
+
+		if self.peek() == Some(b'.') {
+			self.pos += 1;
+			self.digits()?;
+		}
+
+		if matches!(self.peek(), Some(b'e' | b'E')) {
+			self.pos += 1;
+
+			if matches!(self.peek(), Some(b'+' | b'-')) {
+				self.pos += 1;
+			}
+
+			self.digits()?;
+		}
+
+		Ok(())
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for JsonRule
+where
+	I: AsRef<str>,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		self.check(item.as_ref())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{Error, JsonRule};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_json_rule() {
+		let rule = JsonRule::new();
+
+		for input in [
+			"null",
+			" true ",
+			"-0.5e+10",
+			r#""a\"bé""#,
+			"[]",
+			"{}",
+			r#"{"a": [1, 2, {"b": null}], "c": "d"}"#,
+		] {
+			assert!(rule.validate(&(), input).is_ok(), "{input}");
+		}
+
+		for (input, offset) in [
+			("", 0),
+			("01", 1),
+			("[1,]", 3),
+			(r#"{"a" 1}"#, 5),
+			(r#"{"a": 1,}"#, 8),
+			("[1] 2", 4),
+			("\"\n\"", 1),
+			("tru", 3),
+		] {
+			assert_eq!(
+				rule.validate(&(), input),
+				Err(Error::Syntax { offset }.into()),
+				"{input}"
+			);
+		}
+	}
+
+	#[test]
+	fn test_json_rule_top_and_depth() {
+		let rule = JsonRule::new().object();
+
+		assert!(rule.validate(&(), "{}").is_ok());
+		assert_eq!(rule.validate(&(), "[]"), Err(Error::ExpectedObject.into()));
+
+		let rule = JsonRule::new().array();
+
+		assert!(rule.validate(&(), "[]").is_ok());
+		assert_eq!(rule.validate(&(), "1"), Err(Error::ExpectedArray.into()));
+
+		let rule = JsonRule::new().max_depth(2);
+
+		assert!(rule.validate(&(), "[[1], {}]").is_ok());
+		assert_eq!(
+			rule.validate(&(), "[[[1]]]"),
+			Err(Error::TooDeep { max: 2 }.into())
+		);
+
+		let deep = "[".repeat(129) + &"]".repeat(129);
+
+		assert!(JsonRule::new().validate(&(), &deep).is_err());
+	}
+}
@@ -0,0 +1,73 @@
+//! Rules for validating serialized documents held in strings.
+//!
+//! Each format lives in its own module (re-exported at the top level of
+//! [`rule`](crate::options::rule)) and shares the [`Error`] type defined here.
+//!
+//! - [`json`]
+//! - [`toml`] (requires feature `toml`)
+//! - [`yaml`] (requires feature `yaml`)
+//!
+//! Depth is counted in containers, so a top-level scalar has a depth of 0 and
+//! `{"a": [1]}` has a depth of 2.
+
+pub mod json;
+#[cfg(feature = "toml")]
+pub mod toml;
+#[cfg(feature = "yaml")]
+pub mod yaml;
+
+use crate::toolbox::rule::*;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Error {
+	#[error("invalid syntax at byte offset {offset}")]
+	Syntax { offset: usize },
+	#[error("expected an object at the top level")]
+	ExpectedObject,
+	#[error("expected an array at the top level")]
+	ExpectedArray,
+	#[error("expected a nesting depth of at most {max}")]
+	TooDeep { max: usize },
+}
+
+impl Error {
+	#[must_use]
+	pub(crate) fn code(&self) -> &'static str {
+		match self {
+			Self::Syntax { .. } => "invalid_syntax",
+			Self::ExpectedObject => "expected_object",
+			Self::ExpectedArray => "expected_array",
+			Self::TooDeep { .. } => "too_deep",
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub(crate) fn message(&self) -> Cow<'static, str> {
+		match self {
+			Self::Syntax { offset } => format!("invalid syntax at byte offset {offset}").into(),
+			Self::ExpectedObject => "expected an object at the top level".into(),
+			Self::ExpectedArray => "expected an array at the top level".into(),
+			Self::TooDeep { max } => format!("expected a nesting depth of at most {max}").into(),
+		}
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	pub(crate) fn message(&self) -> &'static str {
+		match self {
+			Self::Syntax { .. } => "invalid syntax",
+			Self::ExpectedObject => "expected an object at the top level",
+			Self::ExpectedArray => "expected an array at the top level",
+			Self::TooDeep { .. } => "nesting is too deep",
+		}
+	}
+}
+
+/// The kind of value required at the top level of a document.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Top {
+	Any,
+	Object,
+	Array,
+}
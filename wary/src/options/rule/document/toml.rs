@@ -0,0 +1,120 @@
+//! Rule for TOML validation.
+//!
+//! See [`TomlRule`] for more information.
+
+use core::str::FromStr;
+
+use super::Error;
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = TomlRule;
+
+/// Rule for TOML validation.
+///
+/// The top level of a TOML document is always a table, so only the nesting
+/// depth can be restricted.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Config {
+///   #[validate(toml(max_depth = 2))]
+///   raw: String,
+/// }
+///
+/// let config = Config {
+///   raw: "[server]\nport = 8080".into(),
+/// };
+///
+/// assert!(config.validate(&()).is_ok());
+///
+/// let config = Config {
+///   raw: "[server\nport = 8080".into(),
+/// };
+///
+/// assert!(config.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct TomlRule {
+	max_depth: usize,
+}
+
+impl TomlRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			max_depth: usize::MAX,
+		}
+	}
+
+	/// Set the maximum nesting depth (inclusive).
+	#[inline]
+	pub const fn max_depth(mut self, max_depth: usize) -> Self {
+		self.max_depth = max_depth;
+		self
+	}
+}
+
+fn depth(value: &toml::Value) -> usize {
+	match value {
+		toml::Value::Table(table) => 1 + table.values().map(depth).max().unwrap_or_default(),
+		toml::Value::Array(array) => 1 + array.iter().map(depth).max().unwrap_or_default(),
+		_ => 0,
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for TomlRule
+where
+	I: AsRef<str>,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let table = toml::Table::from_str(item.as_ref()).map_err(|e| Error::Syntax {
+			offset: e.span().map_or(0, |span| span.start),
+		})?;
+
+		if self.max_depth != usize::MAX
+			&& 1 + table.values().map(depth).max().unwrap_or_default() > self.max_depth
+		{
+			return Err(
+				Error::TooDeep {
+					max: self.max_depth,
+				}
+				.into(),
+			);
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{Error, TomlRule};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_toml_rule() {
+		let rule = TomlRule::new();
+
+		assert!(rule.validate(&(), "").is_ok());
+		assert!(rule.validate(&(), "a = 1\n[b]\nc = [1, 2]").is_ok());
+		assert_eq!(
+			rule.validate(&(), "a = 1\nb = "),
+			Err(Error::Syntax { offset: 10 }.into())
+		);
+
+		let rule = TomlRule::new().max_depth(2);
+
+		assert!(rule.validate(&(), "a = [1]").is_ok());
+		assert_eq!(
+			rule.validate(&(), "a = [[1]]"),
+			Err(Error::TooDeep { max: 2 }.into())
+		);
+	}
+}
@@ -0,0 +1,169 @@
+//! Rule for YAML validation.
+//!
+//! See [`YamlRule`] for more information.
+
+use yaml_rust2::{Yaml, YamlLoader};
+
+use super::{Error, Top};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = YamlRule;
+
+/// Rule for YAML validation.
+///
+/// Every document in a multi-document stream must satisfy the `object`,
+/// `array` and `max_depth` options. With `object` or `array`, an input without
+/// any documents (such as an empty string) is rejected.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Manifest {
+///   #[validate(yaml(object))]
+///   raw: String,
+/// }
+///
+/// let manifest = Manifest {
+///   raw: "name: wary\ntags: [a, b]".into(),
+/// };
+///
+/// assert!(manifest.validate(&()).is_ok());
+///
+/// let manifest = Manifest {
+///   raw: "- name: wary".into(),
+/// };
+///
+/// assert!(manifest.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct YamlRule {
+	top: Top,
+	max_depth: usize,
+}
+
+impl YamlRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			top: Top::Any,
+			max_depth: usize::MAX,
+		}
+	}
+
+	/// Require a mapping at the top level.
+	#[inline]
+	pub const fn object(mut self) -> Self {
+		self.top = Top::Object;
+		self
+	}
+
+	/// Require a sequence at the top level.
+	#[inline]
+	pub const fn array(mut self) -> Self {
+		self.top = Top::Array;
+		self
+	}
+
+	/// Set the maximum nesting depth (inclusive).
+	#[inline]
+	pub const fn max_depth(mut self, max_depth: usize) -> Self {
+		self.max_depth = max_depth;
+		self
+	}
+}
+
+fn depth(value: &Yaml) -> usize {
+	match value {
+		Yaml::Hash(hash) => {
+			1 + hash
+				.iter()
+				.map(|(key, value)| depth(key).max(depth(value)))
+				.max()
+				.unwrap_or_default()
+		}
+		Yaml::Array(array) => 1 + array.iter().map(depth).max().unwrap_or_default(),
+		_ => 0,
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for YamlRule
+where
+	I: AsRef<str>,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let input = item.as_ref();
+		let documents = YamlLoader::load_from_str(input).map_err(|e| {
+			// the marker counts characters, not bytes
+			let offset = input
+				.char_indices()
+				.nth(e.marker().index())
+				.map_or(input.len(), |(offset, _)| offset);
+
+			Error::Syntax { offset }
+		})?;
+
+		// an empty input has no documents, so it is neither an object nor an array
+		match self.top {
+			Top::Object
+				if documents.is_empty() || !documents.iter().all(|doc| matches!(doc, Yaml::Hash(..))) =>
+			{
+				return Err(Error::ExpectedObject.into());
+			}
+			Top::Array
+				if documents.is_empty() || !documents.iter().all(|doc| matches!(doc, Yaml::Array(..))) =>
+			{
+				return Err(Error::ExpectedArray.into());
+			}
+			_ => {}
+		}
+
+		if self.max_depth != usize::MAX && documents.iter().any(|doc| depth(doc) > self.max_depth) {
+			return Err(
+				Error::TooDeep {
+					max: self.max_depth,
+				}
+				.into(),
+			);
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{Error, YamlRule};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_yaml_rule() {
+		let rule = YamlRule::new();
+
+		assert!(rule.validate(&(), "a: 1\nb: [1, 2]").is_ok());
+		assert!(rule.validate(&(), "- 1\n- 2").is_ok());
+		assert_eq!(
+			rule.validate(&(), "é: [1, 2"),
+			Err(Error::Syntax { offset: 9 }.into())
+		);
+
+		let rule = YamlRule::new().object().max_depth(2);
+
+		assert!(rule.validate(&(), "a:\n  b: 1").is_ok());
+		assert_eq!(rule.validate(&(), "- 1"), Err(Error::ExpectedObject.into()));
+		assert_eq!(rule.validate(&(), ""), Err(Error::ExpectedObject.into()));
+		assert_eq!(
+			YamlRule::new().array().validate(&(), "# comment"),
+			Err(Error::ExpectedArray.into())
+		);
+		assert_eq!(
+			rule.validate(&(), "a:\n  b:\n    c: 1"),
+			Err(Error::TooDeep { max: 2 }.into())
+		);
+	}
+}
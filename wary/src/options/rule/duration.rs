@@ -0,0 +1,400 @@
+//! Rule for duration range validation.
+//!
+//! See [`DurationRule`] for more information.
+
+use core::{fmt, time::Duration};
+
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = DurationRule;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Error {
+	#[error(
+		"expected a duration {} {}",
+		if *exclusive { "longer than" } else { "of at least" },
+		Human(*min)
+	)]
+	TooShort { min: Duration, exclusive: bool },
+	#[error(
+		"expected a duration {} {}",
+		if *exclusive { "shorter than" } else { "of at most" },
+		Human(*max)
+	)]
+	TooLong { max: Duration, exclusive: bool },
+	#[error("duration cannot be measured")]
+	Unmeasurable,
+}
+
+impl Error {
+	#[must_use]
+	pub(crate) fn code(&self) -> &'static str {
+		match self {
+			Self::TooShort { .. } => "duration_too_short",
+			Self::TooLong { .. } => "duration_too_long",
+			Self::Unmeasurable => "duration_unmeasurable",
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub(crate) fn message(&self) -> Cow<'static, str> {
+		match self {
+			Self::TooShort {
+				min,
+				exclusive: false,
+			} => format!("expected a duration of at least {}", Human(*min)).into(),
+			Self::TooShort {
+				min,
+				exclusive: true,
+			} => format!("expected a duration longer than {}", Human(*min)).into(),
+			Self::TooLong {
+				max,
+				exclusive: false,
+			} => format!("expected a duration of at most {}", Human(*max)).into(),
+			Self::TooLong {
+				max,
+				exclusive: true,
+			} => format!("expected a duration shorter than {}", Human(*max)).into(),
+			Self::Unmeasurable => "duration cannot be measured".into(),
+		}
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	pub(crate) fn message(&self) -> &'static str {
+		match self {
+			Self::TooShort { .. } => "duration is too short",
+			Self::TooLong { .. } => "duration is too long",
+			Self::Unmeasurable => "duration cannot be measured",
+		}
+	}
+}
+
+/// Formats a duration like the literals accepted by the derive macro, such as
+/// `1h30m` or `250ms`.
+pub(crate) struct Human(pub Duration);
+
+impl fmt::Display for Human {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		const UNITS: [(&str, u64); 4] = [("d", 86_400), ("h", 3600), ("m", 60), ("s", 1)];
+		const SUBSEC_UNITS: [(&str, u32); 3] = [("ms", 1_000_000), ("us", 1_000), ("ns", 1)];
+
+		if self.0.is_zero() {
+			return f.write_str("0s");
+		}
+
+		let mut secs = self.0.as_secs();
+		let mut nanos = self.0.subsec_nanos();
+
+		for (unit, size) in UNITS {
+			if secs >= size {
+				write!(f, "{}{unit}", secs / size)?;
+				secs %= size;
+			}
+		}
+
+		for (unit, size) in SUBSEC_UNITS {
+			if nanos >= size {
+				write!(f, "{}{unit}", nanos / size)?;
+				nanos %= size;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// A value that can be measured as a (possibly negative) duration.
+pub trait AsDuration {
+	/// Returns the length in nanoseconds, or `None` if it cannot be measured
+	/// without a reference point (like a span of months).
+	fn as_nanos(&self) -> Option<i128>;
+}
+
+impl AsDuration for Duration {
+	fn as_nanos(&self) -> Option<i128> {
+		i128::try_from(Duration::as_nanos(self)).ok()
+	}
+}
+
+impl<T: ?Sized> AsDuration for &T
+where
+	T: AsDuration,
+{
+	fn as_nanos(&self) -> Option<i128> {
+		(**self).as_nanos()
+	}
+}
+
+#[cfg(feature = "jiff")]
+impl AsDuration for jiff::SignedDuration {
+	fn as_nanos(&self) -> Option<i128> {
+		Some(jiff::SignedDuration::as_nanos(self))
+	}
+}
+
+/// Days and weeks are treated as 24 hours long, and spans with years or
+/// months cannot be measured.
+#[cfg(feature = "jiff")]
+impl AsDuration for jiff::Span {
+	fn as_nanos(&self) -> Option<i128> {
+		self
+			.to_duration(jiff::SpanRelativeTo::days_are_24_hours())
+			.ok()
+			.map(|duration| duration.as_nanos())
+	}
+}
+
+#[cfg(feature = "chrono")]
+impl AsDuration for chrono::TimeDelta {
+	fn as_nanos(&self) -> Option<i128> {
+		Some(i128::from(self.num_seconds()) * 1_000_000_000 + i128::from(self.subsec_nanos()))
+	}
+}
+
+/// Rule for duration range validation.
+///
+/// Works with [`core::time::Duration`], and `jiff::SignedDuration`,
+/// `jiff::Span` or `chrono::TimeDelta` with the respective features. Spans
+/// with units of months or years cannot be measured without a reference date,
+/// so they are rejected.
+///
+/// With the derive macro, bounds can be given as string literals which are
+/// parsed at compile time, made of a number and a unit any number of times:
+/// `ns`, `us`, `ms`, `s`, `m`, `h`, `d` (24 hours), `w` (7 days) and `y` (365
+/// days). For example, `"1h30m"` or `"500ms"`.
+///
+/// # Example
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Config {
+///   #[validate(duration(min = "5m", max = "24h"))]
+///   ttl: Duration,
+///   #[validate(duration("1s".."1m"))]
+///   timeout: Duration,
+/// }
+///
+/// let config = Config {
+///   ttl: Duration::from_secs(3600),
+///   timeout: Duration::from_secs(30),
+/// };
+///
+/// assert!(config.validate(&()).is_ok());
+///
+/// let config = Config {
+///   ttl: Duration::from_secs(60),
+///   timeout: Duration::from_secs(60),
+/// };
+///
+/// assert!(config.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct DurationRule {
+	min: Option<Duration>,
+	max: Option<Duration>,
+	exclusive_min: bool,
+	exclusive_max: bool,
+}
+
+impl DurationRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			min: None,
+			max: None,
+			exclusive_min: false,
+			exclusive_max: false,
+		}
+	}
+
+	/// Set the minimum duration (inclusive).
+	#[inline]
+	pub const fn min(mut self, min: Duration) -> Self {
+		self.min = Some(min);
+		self.exclusive_min = false;
+		self
+	}
+
+	/// Set the minimum duration (exclusive).
+	#[inline]
+	pub const fn exclusive_min(mut self, min: Duration) -> Self {
+		self.min = Some(min);
+		self.exclusive_min = true;
+		self
+	}
+
+	/// Set the maximum duration (inclusive).
+	#[inline]
+	pub const fn max(mut self, max: Duration) -> Self {
+		self.max = Some(max);
+		self.exclusive_max = false;
+		self
+	}
+
+	/// Set the maximum duration (exclusive).
+	#[inline]
+	pub const fn exclusive_max(mut self, max: Duration) -> Self {
+		self.max = Some(max);
+		self.exclusive_max = true;
+		self
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for DurationRule
+where
+	I: AsDuration,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let nanos = item.as_nanos().ok_or(Error::Unmeasurable)?;
+
+		if let Some(min) = self.min {
+			let bound = AsDuration::as_nanos(&min).unwrap_or(i128::MAX);
+
+			if nanos < bound || self.exclusive_min && nanos == bound {
+				return Err(
+					Error::TooShort {
+						min,
+						exclusive: self.exclusive_min,
+					}
+					.into(),
+				);
+			}
+		}
+
+		if let Some(max) = self.max {
+			let bound = AsDuration::as_nanos(&max).unwrap_or(i128::MAX);
+
+			if nanos > bound || self.exclusive_max && nanos == bound {
+				return Err(
+					Error::TooLong {
+						max,
+						exclusive: self.exclusive_max,
+					}
+					.into(),
+				);
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use core::time::Duration;
+
+	use super::{DurationRule, Error, Human};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_human() {
+		assert_eq!(Human(Duration::ZERO).to_string(), "0s");
+		assert_eq!(Human(Duration::from_secs(5400)).to_string(), "1h30m");
+		assert_eq!(Human(Duration::from_secs(90_061)).to_string(), "1d1h1m1s");
+		assert_eq!(Human(Duration::from_micros(1_500)).to_string(), "1ms500us");
+	}
+
+	#[test]
+	fn test_duration_rule() {
+		let rule = DurationRule::new()
+			.min(Duration::from_secs(60))
+			.exclusive_max(Duration::from_secs(120));
+
+		assert!(rule.validate(&(), &Duration::from_secs(60)).is_ok());
+		assert_eq!(
+			rule.validate(&(), &Duration::from_secs(59)),
+			Err(
+				Error::TooShort {
+					min: Duration::from_secs(60),
+					exclusive: false
+				}
+				.into()
+			)
+		);
+		assert_eq!(
+			rule.validate(&(), &Duration::from_secs(120)),
+			Err(
+				Error::TooLong {
+					max: Duration::from_secs(120),
+					exclusive: true
+				}
+				.into()
+			)
+		);
+	}
+
+	#[test]
+	fn test_error_display() {
+		let min = Duration::from_secs(60);
+		let max = Duration::from_secs(120);
+
+		for (error, expected) in [
+			(
+				Error::TooShort {
+					min,
+					exclusive: false,
+				},
+				"expected a duration of at least 1m",
+			),
+			(
+				Error::TooShort {
+					min,
+					exclusive: true,
+				},
+				"expected a duration longer than 1m",
+			),
+			(
+				Error::TooLong {
+					max,
+					exclusive: false,
+				},
+				"expected a duration of at most 2m",
+			),
+			(
+				Error::TooLong {
+					max,
+					exclusive: true,
+				},
+				"expected a duration shorter than 2m",
+			),
+		] {
+			assert_eq!(error.to_string(), expected);
+			assert_eq!(error.message(), expected);
+		}
+	}
+
+	#[test]
+	fn test_derive_literals() {
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		struct Config {
+			#[validate(duration(min = "1h30m", max = "1d"))]
+			ttl: Duration,
+			#[validate(duration(..="250ms"))]
+			timeout: Duration,
+		}
+
+		let config = Config {
+			ttl: Duration::from_secs(5400),
+			timeout: Duration::from_millis(250),
+		};
+
+		assert!(config.validate(&()).is_ok());
+
+		let config = Config {
+			ttl: Duration::from_secs(5399),
+			timeout: Duration::from_millis(251),
+		};
+
+		assert_eq!(config.validate(&()).unwrap_err().len(), 2);
+	}
+}
@@ -0,0 +1,165 @@
+//! Rule for base32 validation.
+//!
+//! See [`Base32Rule`] for more information.
+
+use super::{impl_bounds, split_padding, Bounds, Error};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = Base32Rule;
+
+/// Rule for base32 validation (RFC 4648).
+///
+/// By default, the standard alphabet (`A-Z`, `2-7`) is used and padding is
+/// required. Use `hex` for the extended hex alphabet (`0-9`, `A-V`), and
+/// `padding = false` to forbid padding. Unused trailing bits must be zero.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Secret {
+///   #[validate(base32(padding = false, len = 10))]
+///   totp: String,
+/// }
+///
+/// let secret = Secret {
+///   totp: "JBSWY3DPEHPK3PXP".into(),
+/// };
+///
+/// assert!(secret.validate(&()).is_ok());
+///
+/// let secret = Secret {
+///   totp: "JBSWY3DPEHPK3PX1".into(),
+/// };
+///
+/// assert!(secret.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct Base32Rule {
+	hex: bool,
+	padding: bool,
+	bounds: Bounds,
+}
+
+impl Base32Rule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			hex: false,
+			padding: true,
+			bounds: Bounds::new(),
+		}
+	}
+
+	/// Use the extended hex alphabet (`0-9`, `A-V`).
+	#[inline]
+	pub const fn hex(mut self) -> Self {
+		self.hex = true;
+		self
+	}
+
+	/// Whether padding is required (`true`, the default) or forbidden
+	/// (`false`).
+	#[inline]
+	pub const fn padding(mut self, padding: bool) -> Self {
+		self.padding = padding;
+		self
+	}
+
+	fn decode_char(&self, ch: u8) -> Option<u8> {
+		Some(match ch {
+			b'A'..=b'Z' if !self.hex => ch - b'A',
+			b'2'..=b'7' if !self.hex => ch - b'2' + 26,
+			b'0'..=b'9' if self.hex => ch - b'0',
+			b'A'..=b'V' if self.hex => ch - b'A' + 10,
+			_ => return None,
+		})
+	}
+}
+
+impl_bounds!(Base32Rule);
+
+impl<I: ?Sized> crate::Rule<I> for Base32Rule
+where
+	I: AsRef<str>,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let input = item.as_ref().as_bytes();
+		let (data, padding) = split_padding(input);
+
+		let mut last = 0;
+
+		for (position, &ch) in data.iter().enumerate() {
+			match self.decode_char(ch) {
+				Some(value) => last = value,
+				None if ch == b'=' => return Err(Error::InvalidPadding.into()),
+				None => return Err(Error::InvalidCharacter { position }.into()),
+			}
+		}
+
+		let remainder = data.len() % 8;
+
+		// the number of unused low bits of the final character
+		let unused_bits = match remainder {
+			0 => 0,
+			2 => 2,
+			4 => 4,
+			5 => 1,
+			7 => 3,
+			_ => return Err(Error::InvalidLength.into()),
+		};
+
+		let expected_padding = (8 - remainder) % 8;
+
+		if self.padding && padding != expected_padding || !self.padding && padding != 0 {
+			return Err(Error::InvalidPadding.into());
+		}
+
+		if last & ((1 << unused_bits) - 1) != 0 {
+			return Err(
+				Error::InvalidCharacter {
+					position: data.len() - 1,
+				}
+				.into(),
+			);
+		}
+
+		self.bounds.check(data.len() * 5 / 8)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::Base32Rule;
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_base32_rule() {
+		let rule = Base32Rule::new();
+
+		assert!(rule.validate(&(), "").is_ok());
+		assert!(rule.validate(&(), "NBSWY3DP").is_ok());
+		assert!(rule.validate(&(), "NBSWY3DPEE======").is_ok());
+
+		assert!(rule.validate(&(), "NBSWY3DPEE").is_err());
+		assert!(rule.validate(&(), "NBSWY3DPEF======").is_err());
+		assert!(rule.validate(&(), "NBSWY3D=").is_err());
+		assert!(rule.validate(&(), "nbswy3dp").is_err());
+
+		let rule = Base32Rule::new().padding(false).len(6);
+
+		assert!(rule.validate(&(), "NBSWY3DPEE").is_ok());
+		assert!(rule.validate(&(), "NBSWY3DPEE======").is_err());
+		assert!(rule.validate(&(), "NBSWY3DP").is_err());
+
+		let rule = Base32Rule::new().hex();
+
+		assert!(rule.validate(&(), "D1IMOR3F").is_ok());
+		assert!(rule.validate(&(), "NBSWY3DP").is_err());
+	}
+}
@@ -0,0 +1,152 @@
+//! Rule for base58 validation.
+//!
+//! See [`Base58Rule`] for more information.
+
+use super::{impl_bounds, Bounds, Error};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = Base58Rule;
+
+/// The largest decoded length that can be measured without allocating.
+const MAX_DECODED_LEN: usize = 256;
+
+/// Rule for base58 validation (Bitcoin alphabet).
+///
+/// Checking the decoded length requires decoding the input, which is only
+/// supported for values of up to 256 bytes. Longer values are reported with
+/// [`Error::TooLongToDecode`].
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Key {
+///   #[validate(base58(len = 32))]
+///   public: String,
+/// }
+///
+/// let key = Key {
+///   public: "4uQeVj5tqViQh7yWWGStvkEG1Zmhx6uasJtWCJziofM".into(),
+/// };
+///
+/// assert!(key.validate(&()).is_ok());
+///
+/// let key = Key {
+///   public: "0uQeVj5tqViQh7yWWGStvkEG1Zmhx6uasJtWCJziofM".into(),
+/// };
+///
+/// assert!(key.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct Base58Rule {
+	bounds: Bounds,
+}
+
+impl Base58Rule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			bounds: Bounds::new(),
+		}
+	}
+}
+
+impl_bounds!(Base58Rule);
+
+fn decode_char(ch: u8) -> Option<u8> {
+	const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+	#[allow(clippy::cast_possible_truncation)]
+	ALPHABET.iter().position(|&c| c == ch).map(|idx| idx as u8)
+}
+
+impl<I: ?Sized> crate::Rule<I> for Base58Rule
+where
+	I: AsRef<str>,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let input = item.as_ref().as_bytes();
+
+		if let Some(position) = input.iter().position(|&ch| decode_char(ch).is_none()) {
+			return Err(Error::InvalidCharacter { position }.into());
+		}
+
+		if !self.bounds.is_set() {
+			return Ok(());
+		}
+
+		// each leading '1' encodes a leading zero byte
+		let zeros = input.iter().take_while(|&&ch| ch == b'1').count();
+
+		// big-endian base256 digits of the remaining value, stored in reverse
+		let mut digits = [0u8; MAX_DECODED_LEN];
+		let mut len = 0;
+
+		for &ch in &input[zeros..] {
+			let mut carry = u32::from(decode_char(ch).unwrap_or_default());
+
+			for digit in &mut digits[..len] {
+				carry += u32::from(*digit) * 58;
+				*digit = (carry & 0xff) as u8;
+				carry >>= 8;
+			}
+
+			while carry > 0 {
+				if len == MAX_DECODED_LEN {
+					return Err(
+						Error::TooLongToDecode {
+							max: MAX_DECODED_LEN,
+						}
+						.into(),
+					);
+				}
+
+				digits[len] = (carry & 0xff) as u8;
+				len += 1;
+				carry >>= 8;
+			}
+		}
+
+		self.bounds.check(zeros + len)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{Base58Rule, Error};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_base58_rule() {
+		let rule = Base58Rule::new();
+
+		assert!(rule.validate(&(), "").is_ok());
+		assert!(rule.validate(&(), "Cn8eVZg").is_ok());
+
+		assert!(rule.validate(&(), "Cn8eVZ0").is_err());
+		assert!(rule.validate(&(), "CnOeVZg").is_err());
+		assert!(rule.validate(&(), "Il").is_err());
+
+		let rule = Base58Rule::new().len(5);
+
+		assert!(rule.validate(&(), "Cn8eVZg").is_ok());
+		assert!(rule.validate(&(), "1Cn8eVZg").is_err());
+
+		let rule = Base58Rule::new().len(6);
+
+		assert!(rule.validate(&(), "1Cn8eVZg").is_ok());
+
+		let rule = Base58Rule::new().max(1000);
+		let input = "z".repeat(400);
+
+		assert_eq!(
+			rule.validate(&(), input.as_str()),
+			Err(Error::TooLongToDecode { max: 256 }.into())
+		);
+	}
+}
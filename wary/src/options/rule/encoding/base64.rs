@@ -0,0 +1,176 @@
+//! Rule for base64 validation.
+//!
+//! See [`Base64Rule`] for more information.
+
+use super::{impl_bounds, split_padding, Bounds, Error};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = Base64Rule;
+
+/// Rule for base64 validation (RFC 4648).
+///
+/// By default, the standard alphabet is used and padding is required. Use
+/// `url_safe` for the URL and filename safe alphabet, and `padding = false`
+/// to forbid padding. Unused trailing bits must be zero.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Token {
+///   #[validate(base64)]
+///   standard: String,
+///   #[validate(base64(url_safe, padding = false, len = 4))]
+///   url: String,
+/// }
+///
+/// let token = Token {
+///   standard: "aGVsbG8=".into(),
+///   url: "3q2-7w".into(),
+/// };
+///
+/// assert!(token.validate(&()).is_ok());
+///
+/// let token = Token {
+///   standard: "aGVsbG8".into(),
+///   url: "3q2+7w==".into(),
+/// };
+///
+/// assert!(token.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct Base64Rule {
+	url_safe: bool,
+	padding: bool,
+	bounds: Bounds,
+}
+
+impl Base64Rule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			url_safe: false,
+			padding: true,
+			bounds: Bounds::new(),
+		}
+	}
+
+	/// Use the URL and filename safe alphabet (`-` and `_` instead of `+` and
+	/// `/`).
+	#[inline]
+	pub const fn url_safe(mut self) -> Self {
+		self.url_safe = true;
+		self
+	}
+
+	/// Whether padding is required (`true`, the default) or forbidden
+	/// (`false`).
+	#[inline]
+	pub const fn padding(mut self, padding: bool) -> Self {
+		self.padding = padding;
+		self
+	}
+
+	fn decode_char(&self, ch: u8) -> Option<u8> {
+		Some(match ch {
+			b'A'..=b'Z' => ch - b'A',
+			b'a'..=b'z' => ch - b'a' + 26,
+			b'0'..=b'9' => ch - b'0' + 52,
+			b'+' if !self.url_safe => 62,
+			b'/' if !self.url_safe => 63,
+			b'-' if self.url_safe => 62,
+			b'_' if self.url_safe => 63,
+			_ => return None,
+		})
+	}
+}
+
+impl_bounds!(Base64Rule);
+
+impl<I: ?Sized> crate::Rule<I> for Base64Rule
+where
+	I: AsRef<str>,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let input = item.as_ref().as_bytes();
+		let (data, padding) = split_padding(input);
+
+		let mut last = 0;
+
+		for (position, &ch) in data.iter().enumerate() {
+			match self.decode_char(ch) {
+				Some(value) => last = value,
+				None if ch == b'=' => return Err(Error::InvalidPadding.into()),
+				None => return Err(Error::InvalidCharacter { position }.into()),
+			}
+		}
+
+		let remainder = data.len() % 4;
+
+		if remainder == 1 {
+			return Err(Error::InvalidLength.into());
+		}
+
+		let expected_padding = (4 - remainder) % 4;
+
+		if self.padding && padding != expected_padding || !self.padding && padding != 0 {
+			return Err(Error::InvalidPadding.into());
+		}
+
+		// the unused low bits of the final character must be zero
+		let unused_bits = match remainder {
+			2 => 4,
+			3 => 2,
+			_ => 0,
+		};
+
+		if last & ((1 << unused_bits) - 1) != 0 {
+			return Err(
+				Error::InvalidCharacter {
+					position: data.len() - 1,
+				}
+				.into(),
+			);
+		}
+
+		self.bounds.check(data.len() * 3 / 4)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::Base64Rule;
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_base64_rule() {
+		let rule = Base64Rule::new();
+
+		assert!(rule.validate(&(), "").is_ok());
+		assert!(rule.validate(&(), "aGVsbG8=").is_ok());
+		assert!(rule.validate(&(), "aGVsbG8h").is_ok());
+		assert!(rule.validate(&(), "aGVsbA==").is_ok());
+
+		assert!(rule.validate(&(), "aGVsbG8").is_err());
+		assert!(rule.validate(&(), "aGVsbG9=").is_err());
+		assert!(rule.validate(&(), "aGV=sbG8").is_err());
+		assert!(rule.validate(&(), "aGVsbG8-").is_err());
+		assert!(rule.validate(&(), "a===").is_err());
+
+		let rule = Base64Rule::new().url_safe().padding(false);
+
+		assert!(rule.validate(&(), "3q2-7w").is_ok());
+		assert!(rule.validate(&(), "3q2-7w==").is_err());
+		assert!(rule.validate(&(), "3q2+7w").is_err());
+
+		let rule = Base64Rule::new().len(5);
+
+		assert!(rule.validate(&(), "aGVsbG8=").is_ok());
+		assert!(rule.validate(&(), "aGVsbA==").is_err());
+	}
+}
@@ -0,0 +1,138 @@
+//! Rule for hexadecimal validation.
+//!
+//! See [`HexRule`] for more information.
+
+use super::{impl_bounds, Bounds, Error};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = HexRule;
+
+#[derive(Clone, Copy)]
+enum Case {
+	Any,
+	Lower,
+	Upper,
+}
+
+/// Rule for hexadecimal validation.
+///
+/// The input must contain an even number of hex digits. Both cases are
+/// accepted unless `lowercase` or `uppercase` is set.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Hash {
+///   #[validate(hex(len = 4))]
+///   short: String,
+///   #[validate(hex(lowercase))]
+///   digest: String,
+/// }
+///
+/// let hash = Hash {
+///   short: "DEADbeef".into(),
+///   digest: "0123456789abcdef".into(),
+/// };
+///
+/// assert!(hash.validate(&()).is_ok());
+///
+/// let hash = Hash {
+///   short: "deadbee".into(),
+///   digest: "0123456789ABCDEF".into(),
+/// };
+///
+/// assert!(hash.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct HexRule {
+	case: Case,
+	bounds: Bounds,
+}
+
+impl HexRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			case: Case::Any,
+			bounds: Bounds::new(),
+		}
+	}
+
+	/// Only accept lowercase hex digits.
+	#[inline]
+	pub const fn lowercase(mut self) -> Self {
+		self.case = Case::Lower;
+		self
+	}
+
+	/// Only accept uppercase hex digits.
+	#[inline]
+	pub const fn uppercase(mut self) -> Self {
+		self.case = Case::Upper;
+		self
+	}
+}
+
+impl_bounds!(HexRule);
+
+impl<I: ?Sized> crate::Rule<I> for HexRule
+where
+	I: AsRef<str>,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let input = item.as_ref().as_bytes();
+
+		for (position, &ch) in input.iter().enumerate() {
+			let valid = match self.case {
+				Case::Any => ch.is_ascii_hexdigit(),
+				Case::Lower => matches!(ch, b'0'..=b'9' | b'a'..=b'f'),
+				Case::Upper => matches!(ch, b'0'..=b'9' | b'A'..=b'F'),
+			};
+
+			if !valid {
+				return Err(Error::InvalidCharacter { position }.into());
+			}
+		}
+
+		if input.len() % 2 != 0 {
+			return Err(Error::InvalidLength.into());
+		}
+
+		self.bounds.check(input.len() / 2)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::HexRule;
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_hex_rule() {
+		let rule = HexRule::new();
+
+		assert!(rule.validate(&(), "").is_ok());
+		assert!(rule.validate(&(), "00ff").is_ok());
+		assert!(rule.validate(&(), "00FF").is_ok());
+
+		assert!(rule.validate(&(), "0").is_err());
+		assert!(rule.validate(&(), "0g").is_err());
+
+		let rule = HexRule::new().lowercase();
+
+		assert!(rule.validate(&(), "00ff").is_ok());
+		assert!(rule.validate(&(), "00FF").is_err());
+
+		let rule = HexRule::new().uppercase().min(2).max(3);
+
+		assert!(rule.validate(&(), "00FF").is_ok());
+		assert!(rule.validate(&(), "00").is_err());
+		assert!(rule.validate(&(), "00112233").is_err());
+	}
+}
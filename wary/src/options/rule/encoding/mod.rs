@@ -0,0 +1,177 @@
+//! Rules for validating binary-to-text encodings.
+//!
+//! Each encoding lives in its own module (re-exported at the top level of
+//! [`rule`](crate::options::rule)) and shares the [`Error`] type defined here.
+//!
+//! - [`base32`] (RFC 4648)
+//! - [`base58`] (Bitcoin alphabet)
+//! - [`base64`] (RFC 4648, standard and URL-safe)
+//! - [`hex`]
+//!
+//! All of them can optionally bound the length of the decoded bytes with
+//! `len`, `min` and `max` (or range syntax), without allocating.
+
+pub mod base32;
+pub mod base58;
+pub mod base64;
+pub mod hex;
+
+use crate::toolbox::rule::*;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Error {
+	#[error("invalid character at position {position}")]
+	InvalidCharacter { position: usize },
+	#[error("invalid padding")]
+	InvalidPadding,
+	#[error("invalid length")]
+	InvalidLength,
+	#[error("expected decoded length of at least {min}, found {actual}")]
+	TooShort { min: usize, actual: usize },
+	#[error("expected decoded length of at most {max}, found {actual}")]
+	TooLong { max: usize, actual: usize },
+	#[error("input is too long to decode, expected decoded length of at most {max}")]
+	TooLongToDecode { max: usize },
+}
+
+impl Error {
+	#[must_use]
+	pub(crate) fn code(&self) -> &'static str {
+		match self {
+			Self::InvalidCharacter { .. } => "invalid_character",
+			Self::InvalidPadding => "invalid_padding",
+			Self::InvalidLength => "invalid_length",
+			Self::TooShort { .. } => "decoded_too_short",
+			Self::TooLong { .. } => "decoded_too_long",
+			Self::TooLongToDecode { .. } => "too_long_to_decode",
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub(crate) fn message(&self) -> Cow<'static, str> {
+		match self {
+			Self::InvalidCharacter { position } => {
+				format!("invalid character at position {position}").into()
+			}
+			Self::InvalidPadding => "invalid padding".into(),
+			Self::InvalidLength => "invalid length".into(),
+			Self::TooShort { min, actual } => {
+				format!("expected decoded length of at least {min}, found {actual}").into()
+			}
+			Self::TooLong { max, actual } => {
+				format!("expected decoded length of at most {max}, found {actual}").into()
+			}
+			Self::TooLongToDecode { max } => {
+				format!("input is too long to decode, expected decoded length of at most {max}").into()
+			}
+		}
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	pub(crate) fn message(&self) -> &'static str {
+		match self {
+			Self::InvalidCharacter { .. } => "invalid character",
+			Self::InvalidPadding => "invalid padding",
+			Self::InvalidLength => "invalid length",
+			Self::TooShort { .. } => "decoded length is too short",
+			Self::TooLong { .. } => "decoded length is too long",
+			Self::TooLongToDecode { .. } => "input is too long to decode",
+		}
+	}
+}
+
+/// Bounds on the decoded length, in bytes.
+#[derive(Clone, Copy)]
+pub(crate) struct Bounds {
+	min: usize,
+	max: usize,
+}
+
+impl Bounds {
+	pub(crate) const fn new() -> Self {
+		Self {
+			min: usize::MIN,
+			max: usize::MAX,
+		}
+	}
+
+	pub(crate) const fn is_set(&self) -> bool {
+		self.min != usize::MIN || self.max != usize::MAX
+	}
+
+	pub(crate) fn check(&self, actual: usize) -> Result<()> {
+		if actual < self.min {
+			return Err(
+				Error::TooShort {
+					min: self.min,
+					actual,
+				}
+				.into(),
+			);
+		}
+
+		if actual > self.max {
+			return Err(
+				Error::TooLong {
+					max: self.max,
+					actual,
+				}
+				.into(),
+			);
+		}
+
+		Ok(())
+	}
+}
+
+/// Implements the decoded length builder methods for an encoding rule with a
+/// `bounds: Bounds` field.
+macro_rules! impl_bounds {
+	($rule:ty) => {
+		impl $rule {
+			/// Set the exact decoded length, in bytes.
+			#[inline]
+			pub const fn len(mut self, len: usize) -> Self {
+				self.bounds.min = len;
+				self.bounds.max = len;
+				self
+			}
+
+			/// Set the minimum decoded length, in bytes (inclusive).
+			#[inline]
+			pub const fn min(mut self, min: usize) -> Self {
+				self.bounds.min = min;
+				self
+			}
+
+			/// Set the maximum decoded length, in bytes (inclusive).
+			#[inline]
+			pub const fn max(mut self, max: usize) -> Self {
+				self.bounds.max = max;
+				self
+			}
+
+			/// Set the maximum decoded length, in bytes (exclusive).
+			#[inline]
+			pub const fn exclusive_max(mut self, max: usize) -> Self {
+				self.bounds.max = max.saturating_sub(1);
+				self
+			}
+		}
+	};
+}
+
+pub(crate) use impl_bounds;
+
+/// Splits trailing `=` padding off of the input, returning the data and the
+/// number of padding characters.
+pub(crate) fn split_padding(input: &[u8]) -> (&[u8], usize) {
+	let data_len = input
+		.iter()
+		.rposition(|&b| b != b'=')
+		.map_or(0, |idx| idx + 1);
+
+	(&input[..data_len], input.len() - data_len)
+}
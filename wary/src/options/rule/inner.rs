@@ -52,4 +52,88 @@ mod test {
 
 		assert!(container.validate(&()).is_err());
 	}
+
+	#[allow(clippy::linkedlist)]
+	#[test]
+	fn test_inner_rule_collections() {
+		use std::collections::{BTreeSet, HashSet, LinkedList, VecDeque};
+
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		struct Collections {
+			#[validate(inner(ascii), length(max = 2))]
+			set: HashSet<&'static str>,
+			#[validate(inner(range(..10)), length(max = 2))]
+			sorted: BTreeSet<u8>,
+			#[validate(inner(range(..10)), length(max = 2))]
+			queue: VecDeque<u8>,
+			#[validate(inner(dive), length(max = 2))]
+			list: LinkedList<Item>,
+		}
+
+		let collections = Collections {
+			set: HashSet::from(["a"]),
+			sorted: BTreeSet::from([1, 2]),
+			queue: VecDeque::from([1, 2]),
+			list: LinkedList::from([Item { name: "a" }]),
+		};
+
+		assert!(collections.validate(&()).is_ok());
+
+		let collections = Collections {
+			set: HashSet::from(["😃"]),
+			sorted: BTreeSet::from([1, 20, 10]),
+			queue: VecDeque::from([1, 2, 3]),
+			list: LinkedList::from([Item { name: "a" }, Item { name: "😃" }]),
+		};
+
+		let report = collections.validate(&()).unwrap_err();
+		let debug = format!("{report:?}");
+
+		assert_eq!(report.len(), 6);
+		assert!(debug.contains("(set.0, Ascii("));
+		assert!(debug.contains("(sorted.1, Range("));
+		assert!(debug.contains("(sorted.2, Range("));
+		assert!(debug.contains("(sorted, Length("));
+		assert!(debug.contains("(queue, Length("));
+		assert!(debug.contains("(list.1.name, Ascii("));
+	}
+
+	#[cfg(all(feature = "smallvec", feature = "arrayvec", feature = "heapless"))]
+	#[test]
+	fn test_inner_rule_inline_vecs() {
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		struct Inline {
+			#[validate(inner(range(..10)), length(max = 2))]
+			small: smallvec::SmallVec<[u8; 2]>,
+			#[validate(inner(range(..10)), length(max = 2))]
+			array: arrayvec::ArrayVec<u8, 4>,
+			#[validate(inner(range(..10)), length(max = 2))]
+			heapless: heapless::Vec<u8, 4>,
+		}
+
+		let inline = Inline {
+			small: smallvec::smallvec![1, 2],
+			array: [1, 2].into_iter().collect(),
+			heapless: heapless::Vec::from_slice(&[1, 2]).unwrap(),
+		};
+
+		assert!(inline.validate(&()).is_ok());
+
+		let inline = Inline {
+			small: smallvec::smallvec![1, 20, 3],
+			array: [10].into_iter().collect(),
+			heapless: heapless::Vec::from_slice(&[1, 2, 3]).unwrap(),
+		};
+
+		let report = inline.validate(&()).unwrap_err();
+		let debug = format!("{report:?}");
+
+		assert_eq!(report.len(), 4);
+		assert!(debug.contains("(small.1, Range("));
+		assert!(debug.contains("(small, Length("));
+		assert!(debug.contains("(array.0, Range("));
+		assert!(debug.contains("(heapless, Length("));
+	}
 }
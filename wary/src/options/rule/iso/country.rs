@@ -0,0 +1,143 @@
+//! Rule for country code validation.
+//!
+//! See [`CountryRule`] for more information.
+
+use super::{lookup, table, Case, Error};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = CountryRule;
+
+#[derive(Clone, Copy)]
+enum Kind {
+	Alpha2,
+	Alpha3,
+	Numeric,
+}
+
+/// Rule for country code validation (ISO 3166-1).
+///
+/// By default, alpha-2 codes (`US`) are expected. Use `alpha3` for alpha-3
+/// codes (`USA`) or `numeric` for numeric codes (`840`). Letters must be
+/// uppercase unless `ignore_case` is set.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Address {
+///   #[validate(country)]
+///   country: String,
+///   #[validate(country(alpha3, ignore_case))]
+///   billing_country: String,
+/// }
+///
+/// let address = Address {
+///   country: "CA".into(),
+///   billing_country: "usa".into(),
+/// };
+///
+/// assert!(address.validate(&()).is_ok());
+///
+/// let address = Address {
+///   country: "XX".into(),
+///   billing_country: "US".into(),
+/// };
+///
+/// assert!(address.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct CountryRule {
+	kind: Kind,
+	ignore_case: bool,
+}
+
+impl CountryRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			kind: Kind::Alpha2,
+			ignore_case: false,
+		}
+	}
+
+	/// Expect alpha-2 codes (the default).
+	#[inline]
+	pub const fn alpha2(mut self) -> Self {
+		self.kind = Kind::Alpha2;
+		self
+	}
+
+	/// Expect alpha-3 codes.
+	#[inline]
+	pub const fn alpha3(mut self) -> Self {
+		self.kind = Kind::Alpha3;
+		self
+	}
+
+	/// Expect three-digit numeric codes.
+	#[inline]
+	pub const fn numeric(mut self) -> Self {
+		self.kind = Kind::Numeric;
+		self
+	}
+
+	/// Accept lowercase letters.
+	#[inline]
+	pub const fn ignore_case(mut self) -> Self {
+		self.ignore_case = true;
+		self
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for CountryRule
+where
+	I: AsRef<str>,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let code = item.as_ref().as_bytes();
+
+		let valid = match self.kind {
+			Kind::Alpha2 => lookup::<2>(table::COUNTRY_ALPHA2, Case::Upper, self.ignore_case, code),
+			Kind::Alpha3 => lookup::<3>(table::COUNTRY_ALPHA3, Case::Upper, self.ignore_case, code),
+			Kind::Numeric => lookup::<3>(table::COUNTRY_NUMERIC, Case::Upper, false, code),
+		};
+
+		if valid {
+			Ok(())
+		} else {
+			Err(Error::InvalidCountry.into())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::CountryRule;
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_country_rule() {
+		let rule = CountryRule::new();
+
+		assert!(rule.validate(&(), "US").is_ok());
+		assert!(rule.validate(&(), "us").is_err());
+		assert!(rule.validate(&(), "USA").is_err());
+		assert!(rule.validate(&(), "").is_err());
+
+		let rule = CountryRule::new().alpha3().ignore_case();
+
+		assert!(rule.validate(&(), "deu").is_ok());
+		assert!(rule.validate(&(), "DE").is_err());
+
+		let rule = CountryRule::new().numeric();
+
+		assert!(rule.validate(&(), "004").is_ok());
+		assert!(rule.validate(&(), "4").is_err());
+		assert!(rule.validate(&(), "999").is_err());
+	}
+}
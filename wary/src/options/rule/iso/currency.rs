@@ -0,0 +1,119 @@
+//! Rule for currency code validation.
+//!
+//! See [`CurrencyRule`] for more information.
+
+use super::{lookup, table, Case, Error};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = CurrencyRule;
+
+/// Rule for currency code validation (ISO 4217).
+///
+/// By default, alphabetic codes (`EUR`) are expected. Use `numeric` for
+/// numeric codes (`978`). Letters must be uppercase unless `ignore_case` is
+/// set.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Price {
+///   amount: u64,
+///   #[validate(currency)]
+///   currency: String,
+/// }
+///
+/// let price = Price {
+///   amount: 100,
+///   currency: "EUR".into(),
+/// };
+///
+/// assert!(price.validate(&()).is_ok());
+///
+/// let price = Price {
+///   amount: 100,
+///   currency: "ABC".into(),
+/// };
+///
+/// assert!(price.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct CurrencyRule {
+	numeric: bool,
+	ignore_case: bool,
+}
+
+impl CurrencyRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			numeric: false,
+			ignore_case: false,
+		}
+	}
+
+	/// Expect three-digit numeric codes.
+	#[inline]
+	pub const fn numeric(mut self) -> Self {
+		self.numeric = true;
+		self
+	}
+
+	/// Accept lowercase letters.
+	#[inline]
+	pub const fn ignore_case(mut self) -> Self {
+		self.ignore_case = true;
+		self
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for CurrencyRule
+where
+	I: AsRef<str>,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let code = item.as_ref().as_bytes();
+
+		let valid = if self.numeric {
+			lookup::<3>(table::CURRENCY_NUMERIC, Case::Upper, false, code)
+		} else {
+			lookup::<3>(table::CURRENCY_ALPHA3, Case::Upper, self.ignore_case, code)
+		};
+
+		if valid {
+			Ok(())
+		} else {
+			Err(Error::InvalidCurrency.into())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::CurrencyRule;
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_currency_rule() {
+		let rule = CurrencyRule::new();
+
+		assert!(rule.validate(&(), "USD").is_ok());
+		assert!(rule.validate(&(), "usd").is_err());
+		assert!(rule.validate(&(), "US").is_err());
+
+		assert!(CurrencyRule::new()
+			.ignore_case()
+			.validate(&(), "jpy")
+			.is_ok());
+
+		let rule = CurrencyRule::new().numeric();
+
+		assert!(rule.validate(&(), "840").is_ok());
+		assert!(rule.validate(&(), "USD").is_err());
+	}
+}
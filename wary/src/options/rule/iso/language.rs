@@ -0,0 +1,135 @@
+//! Rule for language code validation.
+//!
+//! See [`LanguageRule`] for more information.
+
+use super::{lookup, table, Case, Error};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = LanguageRule;
+
+#[derive(Clone, Copy)]
+enum Kind {
+	Any,
+	Alpha2,
+	Alpha3,
+}
+
+/// Rule for language code validation.
+///
+/// By default, both two-letter ISO 639-1 codes (`en`) and three-letter ISO
+/// 639-3 codes (`eng`) are accepted. Use `alpha2` or `alpha3` to only accept
+/// one of them. Letters must be lowercase unless `ignore_case` is set.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Profile {
+///   #[validate(language)]
+///   language: String,
+///   #[validate(language(alpha2))]
+///   ui_language: String,
+/// }
+///
+/// let profile = Profile {
+///   language: "yue".into(),
+///   ui_language: "fr".into(),
+/// };
+///
+/// assert!(profile.validate(&()).is_ok());
+///
+/// let profile = Profile {
+///   language: "zz".into(),
+///   ui_language: "fra".into(),
+/// };
+///
+/// assert!(profile.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct LanguageRule {
+	kind: Kind,
+	ignore_case: bool,
+}
+
+impl LanguageRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			kind: Kind::Any,
+			ignore_case: false,
+		}
+	}
+
+	/// Only accept two-letter ISO 639-1 codes.
+	#[inline]
+	pub const fn alpha2(mut self) -> Self {
+		self.kind = Kind::Alpha2;
+		self
+	}
+
+	/// Only accept three-letter ISO 639-3 codes.
+	#[inline]
+	pub const fn alpha3(mut self) -> Self {
+		self.kind = Kind::Alpha3;
+		self
+	}
+
+	/// Accept uppercase letters.
+	#[inline]
+	pub const fn ignore_case(mut self) -> Self {
+		self.ignore_case = true;
+		self
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for LanguageRule
+where
+	I: AsRef<str>,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let code = item.as_ref().as_bytes();
+
+		let alpha2 = || lookup::<2>(table::LANGUAGE_ALPHA2, Case::Lower, self.ignore_case, code);
+		let alpha3 = || lookup::<3>(table::LANGUAGE_ALPHA3, Case::Lower, self.ignore_case, code);
+
+		let valid = match self.kind {
+			Kind::Any => alpha2() || alpha3(),
+			Kind::Alpha2 => alpha2(),
+			Kind::Alpha3 => alpha3(),
+		};
+
+		if valid {
+			Ok(())
+		} else {
+			Err(Error::InvalidLanguage.into())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::LanguageRule;
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_language_rule() {
+		let rule = LanguageRule::new();
+
+		assert!(rule.validate(&(), "en").is_ok());
+		assert!(rule.validate(&(), "eng").is_ok());
+		assert!(rule.validate(&(), "EN").is_err());
+		assert!(rule.validate(&(), "english").is_err());
+
+		assert!(LanguageRule::new()
+			.ignore_case()
+			.validate(&(), "EN")
+			.is_ok());
+		assert!(LanguageRule::new().alpha2().validate(&(), "eng").is_err());
+		assert!(LanguageRule::new().alpha3().validate(&(), "en").is_err());
+	}
+}
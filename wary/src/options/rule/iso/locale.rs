@@ -0,0 +1,242 @@
+//! Rule for locale (BCP 47 language tag) validation.
+//!
+//! See [`LocaleRule`] for more information.
+
+use core::iter::Peekable;
+
+use super::{lookup, table, Case, Error};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = LocaleRule;
+
+/// Rule for locale validation, as BCP 47 language tags (RFC 5646).
+///
+/// Tags are matched case-insensitively, and the language, extended language,
+/// script and region subtags are checked against the ISO 639, ISO 15924 and
+/// ISO 3166-1 tables. Variants and extensions are only checked for their shape,
+/// and irregular grandfathered tags (like `i-klingon`) are not accepted.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Settings {
+///   #[validate(locale)]
+///   locale: String,
+/// }
+///
+/// let settings = Settings {
+///   locale: "zh-Hant-TW".into(),
+/// };
+///
+/// assert!(settings.validate(&()).is_ok());
+///
+/// let settings = Settings {
+///   locale: "en_US".into(),
+/// };
+///
+/// assert!(settings.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct LocaleRule;
+
+impl LocaleRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self
+	}
+}
+
+fn is_alpha(subtag: &[u8]) -> bool {
+	subtag.iter().all(u8::is_ascii_alphabetic)
+}
+
+fn is_digit(subtag: &[u8]) -> bool {
+	subtag.iter().all(u8::is_ascii_digit)
+}
+
+fn is_alphanumeric(subtag: &[u8]) -> bool {
+	subtag.iter().all(u8::is_ascii_alphanumeric)
+}
+
+/// Checks a private use sequence (`x-...`) that started at `position`.
+fn private_use<'t>(
+	mut subtags: impl Iterator<Item = (usize, &'t [u8])>,
+	position: usize,
+) -> core::result::Result<(), usize> {
+	let mut count = 0;
+
+	for (position, subtag) in &mut subtags {
+		if !(1..=8).contains(&subtag.len()) || !is_alphanumeric(subtag) {
+			return Err(position);
+		}
+
+		count += 1;
+	}
+
+	if count == 0 {
+		Err(position)
+	} else {
+		Ok(())
+	}
+}
+
+/// Checks a language tag, returning the byte offset of the first invalid
+/// subtag.
+fn check(tag: &str) -> core::result::Result<(), usize> {
+	let mut subtags: Peekable<_> = tag
+		.split('-')
+		.scan(0, |offset, subtag| {
+			let position = *offset;
+			*offset += subtag.len() + 1;
+			Some((position, subtag.as_bytes()))
+		})
+		.peekable();
+
+	let Some((position, language)) = subtags.next() else {
+		return Err(0);
+	};
+
+	if language.eq_ignore_ascii_case(b"x") {
+		return private_use(subtags, position);
+	}
+
+	let valid = is_alpha(language)
+		&& match language.len() {
+			2 => lookup::<2>(table::LANGUAGE_ALPHA2, Case::Lower, true, language),
+			3 => {
+				lookup::<3>(table::LANGUAGE_ALPHA3, Case::Lower, true, language)
+					|| lookup::<3>(table::LANGUAGE_FAMILY, Case::Lower, true, language)
+			}
+			_ => false,
+		};
+
+	if !valid {
+		return Err(position);
+	}
+
+	if language.len() <= 3 {
+		for _ in 0..3 {
+			let Some((position, extlang)) =
+				subtags.next_if(|(_, subtag)| subtag.len() == 3 && is_alpha(subtag))
+			else {
+				break;
+			};
+
+			if !lookup::<3>(table::LANGUAGE_ALPHA3, Case::Lower, true, extlang) {
+				return Err(position);
+			}
+		}
+	}
+
+	if let Some((position, script)) =
+		subtags.next_if(|(_, subtag)| subtag.len() == 4 && is_alpha(subtag))
+	{
+		if !lookup::<4>(table::SCRIPT, Case::Title, true, script) {
+			return Err(position);
+		}
+	}
+
+	if let Some((position, region)) = subtags.next_if(|(_, subtag)| {
+		subtag.len() == 2 && is_alpha(subtag) || subtag.len() == 3 && is_digit(subtag)
+	}) {
+		if is_alpha(region) && !lookup::<2>(table::COUNTRY_ALPHA2, Case::Upper, true, region) {
+			return Err(position);
+		}
+	}
+
+	while subtags
+		.next_if(|(_, subtag)| {
+			is_alphanumeric(subtag)
+				&& ((5..=8).contains(&subtag.len()) || subtag.len() == 4 && subtag[0].is_ascii_digit())
+		})
+		.is_some()
+	{}
+
+	while let Some((position, singleton)) = subtags.next() {
+		if singleton.len() != 1 || !is_alphanumeric(singleton) {
+			return Err(position);
+		}
+
+		if singleton.eq_ignore_ascii_case(b"x") {
+			return private_use(subtags, position);
+		}
+
+		let mut count = 0;
+
+		while subtags
+			.next_if(|(_, subtag)| (2..=8).contains(&subtag.len()) && is_alphanumeric(subtag))
+			.is_some()
+		{
+			count += 1;
+		}
+
+		if count == 0 {
+			return Err(position);
+		}
+	}
+
+	Ok(())
+}
+
+impl<I: ?Sized> crate::Rule<I> for LocaleRule
+where
+	I: AsRef<str>,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		check(item.as_ref()).map_err(|position| Error::InvalidLocale { position }.into())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{Error, LocaleRule};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_locale_rule() {
+		let rule = LocaleRule::new();
+
+		for input in [
+			"en",
+			"en-US",
+			"EN-us",
+			"zh-Hant-TW",
+			"zh-yue-HK",
+			"es-419",
+			"sl-rozaj-biske",
+			"de-CH-1901",
+			"en-US-u-ca-gregory",
+			"en-a-bbb-x-a-ccc",
+			"x-whatever",
+			"cmn",
+		] {
+			assert!(rule.validate(&(), input).is_ok(), "{input}");
+		}
+
+		for (input, position) in [
+			("", 0),
+			("english", 0),
+			("zz-US", 0),
+			("en-ZZ", 3),
+			("en-Abcd", 3),
+			("en_US", 0),
+			("en-", 3),
+			("en--US", 3),
+			("en-u", 3),
+			("en-US-x", 6),
+			("en-US-toolongvariant", 6),
+		] {
+			assert_eq!(
+				rule.validate(&(), input),
+				Err(Error::InvalidLocale { position }.into()),
+				"{input}"
+			);
+		}
+	}
+}
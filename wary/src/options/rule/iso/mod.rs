@@ -0,0 +1,154 @@
+//! Rules for validating ISO codes, backed by embedded code tables.
+//!
+//! Each rule lives in its own module (re-exported at the top level of
+//! [`rule`](crate::options::rule)) and shares the [`Error`] type defined here.
+//!
+//! - [`country`] (ISO 3166-1)
+//! - [`currency`] (ISO 4217)
+//! - [`language`] (ISO 639-1 and ISO 639-3)
+//! - [`locale`] (BCP 47 language tags)
+
+pub mod country;
+pub mod currency;
+pub mod language;
+pub mod locale;
+mod table;
+
+use crate::toolbox::rule::*;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Error {
+	#[error("expected a valid country code")]
+	InvalidCountry,
+	#[error("expected a valid currency code")]
+	InvalidCurrency,
+	#[error("expected a valid language code")]
+	InvalidLanguage,
+	#[error("invalid language tag subtag at position {position}")]
+	InvalidLocale { position: usize },
+}
+
+impl Error {
+	#[must_use]
+	pub(crate) fn code(&self) -> &'static str {
+		match self {
+			Self::InvalidCountry => "invalid_country",
+			Self::InvalidCurrency => "invalid_currency",
+			Self::InvalidLanguage => "invalid_language",
+			Self::InvalidLocale { .. } => "invalid_locale",
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub(crate) fn message(&self) -> Cow<'static, str> {
+		match self {
+			Self::InvalidCountry => "expected a valid country code".into(),
+			Self::InvalidCurrency => "expected a valid currency code".into(),
+			Self::InvalidLanguage => "expected a valid language code".into(),
+			Self::InvalidLocale { position } => {
+				format!("invalid language tag subtag at position {position}").into()
+			}
+		}
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	pub(crate) fn message(&self) -> &'static str {
+		match self {
+			Self::InvalidCountry => "expected a valid country code",
+			Self::InvalidCurrency => "expected a valid currency code",
+			Self::InvalidLanguage => "expected a valid language code",
+			Self::InvalidLocale { .. } => "invalid language tag",
+		}
+	}
+}
+
+/// The letter case that codes in a table are stored in.
+#[derive(Clone, Copy)]
+pub(crate) enum Case {
+	Upper,
+	Lower,
+	/// Uppercase first letter, like ISO 15924 script codes (`Latn`).
+	Title,
+}
+
+/// Returns `true` if the code is in the table of `N`-byte codes.
+///
+/// If `ignore_case` is set, the code is first converted to the case of the
+/// table.
+pub(crate) fn lookup<const N: usize>(
+	table: &[u8],
+	case: Case,
+	ignore_case: bool,
+	code: &[u8],
+) -> bool {
+	let Ok(mut code) = <[u8; N]>::try_from(code) else {
+		return false;
+	};
+
+	if ignore_case {
+		for (idx, byte) in code.iter_mut().enumerate() {
+			*byte = match case {
+				Case::Title if idx == 0 => byte.to_ascii_uppercase(),
+				Case::Upper => byte.to_ascii_uppercase(),
+				Case::Lower | Case::Title => byte.to_ascii_lowercase(),
+			};
+		}
+	}
+
+	let (mut low, mut high) = (0, table.len() / N);
+
+	while low < high {
+		let mid = (low + high) / 2;
+
+		match table[mid * N..(mid + 1) * N].cmp(&code) {
+			core::cmp::Ordering::Less => low = mid + 1,
+			core::cmp::Ordering::Greater => high = mid,
+			core::cmp::Ordering::Equal => return true,
+		}
+	}
+
+	false
+}
+
+#[cfg(test)]
+mod test {
+	use super::{lookup, table, Case};
+
+	#[test]
+	fn test_tables_are_sorted() {
+		fn check<const N: usize>(table: &[u8]) {
+			assert_eq!(table.len() % N, 0);
+			assert!(table
+				.chunks_exact(N)
+				.zip(table.chunks_exact(N).skip(1))
+				.all(|(a, b)| a < b));
+		}
+
+		check::<2>(table::COUNTRY_ALPHA2);
+		check::<3>(table::COUNTRY_ALPHA3);
+		check::<3>(table::COUNTRY_NUMERIC);
+		check::<3>(table::CURRENCY_ALPHA3);
+		check::<3>(table::CURRENCY_NUMERIC);
+		check::<2>(table::LANGUAGE_ALPHA2);
+		check::<3>(table::LANGUAGE_ALPHA3);
+		check::<3>(table::LANGUAGE_FAMILY);
+		check::<4>(table::SCRIPT);
+	}
+
+	#[test]
+	fn test_lookup() {
+		let country =
+			|ignore_case, code: &[u8]| lookup::<2>(table::COUNTRY_ALPHA2, Case::Upper, ignore_case, code);
+
+		assert!(country(false, b"AD"));
+		assert!(country(false, b"ZW"));
+		assert!(!country(false, b"zw"));
+		assert!(country(true, b"zw"));
+		assert!(!country(true, b"ZWE"));
+
+		assert!(lookup::<4>(table::SCRIPT, Case::Title, true, b"LATN"));
+		assert!(!lookup::<4>(table::SCRIPT, Case::Title, false, b"latn"));
+	}
+}
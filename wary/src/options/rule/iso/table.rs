@@ -0,0 +1,319 @@
+//! Code tables, generated from version 4.15.0 of the `iso-codes` project.
+//!
+//! Each table is a sorted, concatenated list of fixed-width codes.
+
+/// ISO 3166-1 alpha-2 country codes.
+pub(super) static COUNTRY_ALPHA2: &[u8] = b"ADAEAFAGAIALAMAOAQARASATAUAWAXAZBABBBDBEBFBGBHBIBJBLBMBNBOBQBRBSBTBVBWBYBZCACCCDCFCGCHCICKCLCMCN\
+	COCRCUCVCWCXCYCZDEDJDKDMDODZECEEEGEHERESETFIFJFKFMFOFRGAGBGDGEGFGGGHGIGLGMGNGPGQGRGSGTGUGWGYHKHM\
+	HNHRHTHUIDIEILIMINIOIQIRISITJEJMJOJPKEKGKHKIKMKNKPKRKWKYKZLALBLCLILKLRLSLTLULVLYMAMCMDMEMFMGMHMK\
+	MLMMMNMOMPMQMRMSMTMUMVMWMXMYMZNANCNENFNGNINLNONPNRNUNZOMPAPEPFPGPHPKPLPMPNPRPSPTPWPYQARERORSRURW\
+	SASBSCSDSESGSHSISJSKSLSMSNSOSRSSSTSVSXSYSZTCTDTFTGTHTJTKTLTMTNTOTRTTTVTWTZUAUGUMUSUYUZVAVCVEVGVI\
+	VNVUWFWSYEYTZAZMZW";
+
+/// ISO 3166-1 alpha-3 country codes.
+pub(super) static COUNTRY_ALPHA3: &[u8] = b"ABWAFGAGOAIAALAALBANDAREARGARMASMATAATFATGAUSAUTAZEBDIBELBENBESBFABGDBGRBHRBHSBIHBLMBLRBLZBMUBOL\
+	BRABRBBRNBTNBVTBWACAFCANCCKCHECHLCHNCIVCMRCODCOGCOKCOLCOMCPVCRICUBCUWCXRCYMCYPCZEDEUDJIDMADNKDOM\
+	DZAECUEGYERIESHESPESTETHFINFJIFLKFRAFROFSMGABGBRGEOGGYGHAGIBGINGLPGMBGNBGNQGRCGRDGRLGTMGUFGUMGUY\
+	HKGHMDHNDHRVHTIHUNIDNIMNINDIOTIRLIRNIRQISLISRITAJAMJEYJORJPNKAZKENKGZKHMKIRKNAKORKWTLAOLBNLBRLBY\
+	LCALIELKALSOLTULUXLVAMACMAFMARMCOMDAMDGMDVMEXMHLMKDMLIMLTMMRMNEMNGMNPMOZMRTMSRMTQMUSMWIMYSMYTNAM\
+	NCLNERNFKNGANICNIUNLDNORNPLNRUNZLOMNPAKPANPCNPERPHLPLWPNGPOLPRIPRKPRTPRYPSEPYFQATREUROURUSRWASAU\
+	SDNSENSGPSGSSHNSJMSLBSLESLVSMRSOMSPMSRBSSDSTPSURSVKSVNSWESWZSXMSYCSYRTCATCDTGOTHATJKTKLTKMTLSTON\
+	TTOTUNTURTUVTWNTZAUGAUKRUMIURYUSAUZBVATVCTVENVGBVIRVNMVUTWLFWSMYEMZAFZMBZWE";
+
+/// ISO 3166-1 numeric country codes.
+pub(super) static COUNTRY_NUMERIC: &[u8] = b"004008010012016020024028031032036040044048050051052056060064068070072074076084086090092096100104\
+	108112116120124132136140144148152156158162166170174175178180184188191192196203204208212214218222\
+	226231232233234238239242246248250254258260262266268270275276288292296300304308312316320324328332\
+	334336340344348352356360364368372376380384388392398400404408410414417418422426428430434438440442\
+	446450454458462466470474478480484492496498499500504508512516520524528531533534535540548554558562\
+	566570574578580581583584585586591598600604608612616620624626630634638642643646652654659660662663\
+	666670674678682686688690694702703704705706710716724728729732740744748752756760762764768772776780\
+	784788792795796798800804807818826831832833834840850854858860862876882887894";
+
+/// ISO 4217 alphabetic currency codes.
+pub(super) static CURRENCY_ALPHA3: &[u8] = b"AEDAFNALLAMDANGAOAARSAUDAWGAZNBAMBBDBDTBGNBHDBIFBMDBNDBOBBOVBRLBSDBTNBWPBYNBZDCADCDFCHECHFCHWCLF\
+	CLPCNYCOPCOUCRCCUCCUPCVECZKDJFDKKDOPDZDEGPERNETBEURFJDFKPGBPGELGHSGIPGMDGNFGTQGYDHKDHNLHRKHTGHUF\
+	IDRILSINRIQDIRRISKJMDJODJPYKESKGSKHRKMFKPWKRWKWDKYDKZTLAKLBPLKRLRDLSLLYDMADMDLMGAMKDMMKMNTMOPMRU\
+	MURMVRMWKMXNMXVMYRMZNNADNGNNIONOKNPRNZDOMRPABPENPGKPHPPKRPLNPYGQARRONRSDRUBRWFSARSBDSCRSDGSEKSGD\
+	SHPSLESLLSOSSRDSSPSTNSVCSYPSZLTHBTJSTMTTNDTOPTRYTTDTWDTZSUAHUGXUSDUSNUYIUYUUYWUZSVEDVESVNDVUVWST\
+	XAFXAGXAUXBAXBBXBCXBDXCDXDRXOFXPDXPFXPTXSUXTSXUAXXXYERZARZMWZWL";
+
+/// ISO 4217 numeric currency codes.
+pub(super) static CURRENCY_NUMERIC: &[u8] = b"008012032036044048050051052060064068072084090096104108116124132136144152156170174188191192203208\
+	214222230232238242262270292320324328332340344348352356360364368376388392398400404408410414417418\
+	422426430434446454458462480484496498504512516524532533548554558566578586590598600604608634643646\
+	654682690694702704706710728748752756760764776780784788800807818826834840858860882886901925926927\
+	928929930931932933934936938940941943944946947948949950951952953955956957958959960961962963964965\
+	967968969970971972973975976977978979980981984985986990994997999";
+
+/// ISO 639-1 language codes.
+pub(super) static LANGUAGE_ALPHA2: &[u8] = b"aaabaeafakamanarasavayazbabebgbibmbnbobrbscacechcocrcscucvcydadedvdzeeeleneoeseteufafffifjfofrfy\
+	gagdglgngugvhahehihohrhthuhyhziaidieigiiikioisitiujajvkakgkikjkkklkmknkokrkskukvkwkylalblglilnlo\
+	ltlulvmgmhmimkmlmnmrmsmtmynanbndnengnlnnnonrnvnyocojomorospapiplpsptqurmrnrorurwsascsdsesgshsisk\
+	slsmsnsosqsrssstsusvswtatetgthtitktltntotrtstttwtyugukuruzvevivowawoxhyiyozazhzu";
+
+/// ISO 639-3 language codes.
+pub(super) static LANGUAGE_ALPHA3: &[u8] = b"aaaaabaacaadaaeaafaagaahaaiaakaalaanaaoaapaaqaaraasaataauaawaaxaazabaabbabcabdabeabfabgabhabiabj\
+	abkablabmabnaboabpabqabrabsabtabuabvabwabxabyabzacaacbacdaceacfachaciackaclacmacnacpacqacracsact\
+	acuacvacwacxacyaczadaadbaddadeadfadgadhadiadjadladnadoadqadradsadtaduadwadxadyadzaeaaebaecaedaee\
+	aekaelaemaenaeqaeraesaeuaewaeyaezafbafdafeafgafhafiafkafnafoafpafrafsaftafuafzagaagbagcagdageagf\
+	aggaghagiagjagkaglagmagnagoagqagragsagtaguagvagwagxagyagzahaahbahgahhahiahkahlahmahnahoahpahrahs\
+	ahtaiaaibaicaidaieaifaigaihaiiaijaikailaimainaioaipaiqairaitaiwaixaiyajaajgajiajnajpajsajuajwajz\
+	akaakbakcakdakeakfakgakhakiakjakkaklakmakoakpakqakraksaktakuakvakwakxakyakzalaalcaldalealfalhali\
+	aljalkallalmalnaloalpalqalralsaltalualwalxalyalzamaambamcameamfamgamhamiamjamkamlammamnamoampamq\
+	amramsamtamuamvamwamxamyamzanaanbancandaneanfanganhanianjankanlanmannanoanpanqanransantanuanvanw\
+	anxanyanzaoaaobaocaodaoeaofaogaoiaojaokaolaomaonaoraosaotaouaoxaozapbapcapdapeapfapgaphapiapjapk\
+	aplapmapnapoappapqaprapsaptapuapvapwapxapyapzaqcaqdaqgaqkaqmaqnaqpaqraqtaqzaraarbarcardareargarh\
+	ariarjarkarlarnaroarparqarrarsaruarvarwarxaryarzasaasbascaseasfasgashasiasjaskaslasmasnasoaspasq\
+	asrassastasuasvaswasxasyaszataatbatcatdateatgatiatjatkatlatmatnatoatpatqatratsattatuatvatwatxaty\
+	atzauaaubaucaudaugauhauiaujaukaulaumaunauoaupauqaurautauuauwauxauyauzavaavbavdaveaviavkavlavmavn\
+	avoavsavtavuavvawaawbawcaweawgawhawiawkawmawnawoawrawsawtawuawvawwawxawyaxbaxeaxgaxkaxlaxmaxxaya\
+	aybaycaydayeaygayhayiaykaylaymaynayoaypayqayraysaytayuayzazaazbazdazeazgazjazmaznazoaztazzbaabab\
+	bacbaebafbagbahbajbakbalbambanbaobapbarbasbaubavbawbaxbaybbabbbbbcbbdbbebbfbbgbbhbbibbjbbkbblbbm\
+	bbnbbobbpbbqbbrbbsbbtbbubbvbbwbbxbbybcabcbbccbcdbcebcfbcgbchbcibcjbckbclbcmbcnbcobcpbcqbcrbcsbct\
+	bcubcvbcwbcybczbdabdbbdcbddbdebdfbdgbdhbdibdjbdkbdlbdmbdnbdobdpbdqbdrbdsbdtbdubdvbdwbdxbdybdzbea\
+	bebbecbedbeebefbegbehbeibejbekbelbembenbeobepbeqbesbetbeubevbewbexbeybezbfabfbbfcbfdbfebffbfgbfh\
+	bfibfjbfkbflbfmbfnbfobfpbfqbfrbfsbftbfubfwbfxbfybfzbgabgbbgcbgdbgebgfbggbgibgjbgkbglbgnbgobgpbgq\
+	bgrbgsbgtbgubgvbgwbgxbgybgzbhabhbbhcbhdbhebhfbhgbhhbhibhjbhlbhmbhnbhobhpbhqbhrbhsbhtbhubhvbhwbhx\
+	bhybhzbiabibbidbiebifbigbikbilbimbinbiobipbiqbirbisbitbiubivbiwbixbiybizbjabjbbjcbjebjfbjgbjhbji\
+	bjjbjkbjlbjmbjnbjobjpbjrbjsbjtbjubjvbjwbjxbjybjzbkabkcbkdbkfbkgbkhbkibkjbkkbklbkmbknbkobkpbkqbkr\
+	bksbktbkubkvbkwbkxbkybkzblablbblcbldbleblfblhblibljblkbllblmblnbloblpblqblrblsbltblvblwblxblyblz\
+	bmabmbbmcbmdbmebmfbmgbmhbmibmjbmkbmlbmmbmnbmobmpbmqbmrbmsbmtbmubmvbmwbmxbmzbnabnbbncbndbnebnfbng\
+	bnibnjbnkbnlbnmbnnbnobnpbnqbnrbnsbnubnvbnwbnxbnybnzboabobbodboebofbogbohboibojbokbolbombonboobop\
+	boqborbosbotboubovbowboxboybozbpabpcbpdbpebpgbphbpibpjbpkbplbpmbpnbpobppbpqbprbpsbptbpubpvbpwbpx\
+	bpybpzbqabqbbqcbqdbqfbqgbqhbqibqjbqkbqlbqmbqnbqobqpbqqbqrbqsbqtbqubqvbqwbqxbqybqzbrabrbbrcbrdbre\
+	brfbrgbrhbribrjbrkbrlbrmbrnbrobrpbrqbrrbrsbrtbrubrvbrwbrxbrybrzbsabsbbscbsebsfbsgbshbsibsjbskbsl\
+	bsmbsnbsobspbsqbsrbssbstbsubsvbswbsxbsybtabtcbtdbtebtfbtgbthbtibtjbtmbtnbtobtpbtqbtrbtsbttbtubtv\
+	btwbtxbtybtzbuabubbucbudbuebufbugbuhbuibujbukbulbumbunbuobupbuqbusbutbuubuvbuwbuxbuybuzbvabvbbvc\
+	bvdbvebvfbvgbvhbvibvjbvkbvlbvmbvnbvobvpbvqbvrbvtbvubvvbvwbvxbvybvzbwabwbbwcbwdbwebwfbwgbwhbwibwj\
+	bwkbwlbwmbwnbwobwpbwqbwrbwsbwtbwubwwbwxbwybwzbxabxbbxcbxdbxebxfbxgbxhbxibxjbxkbxlbxmbxnbxobxpbxq\
+	bxrbxsbxubxvbxwbxzbyabybbycbydbyebyfbygbyhbyibyjbykbylbymbynbyobypbyqbyrbysbytbyvbywbyxbyzbzabzb\
+	bzcbzdbzebzfbzgbzhbzibzjbzkbzlbzmbznbzobzpbzqbzrbzsbztbzubzvbzwbzxbzybzzcaacabcaccadcaecafcagcah\
+	cajcakcalcamcancaocapcaqcarcascatcavcawcaxcaycazcbbcbccbdcbgcbicbjcbkcblcbncbocbqcbrcbscbtcbucbv\
+	cbwcbycccccdcceccgcchccjcclccmccoccpccrcdacdecdfcdhcdicdjcdmcdncdocdrcdscdycdzceacebcegcekcences\
+	cetceycfacfdcfgcfmcgacgccggcgkchachbchcchdchechfchgchhchjchkchlchmchnchochpchqchrchtchuchvchwchx\
+	chychzciacibciccidciecihcikcimcincipcirciwciycjacjecjhcjicjkcjmcjncjocjpcjscjvcjyckbckhcklckmckn\
+	ckockqckrckscktckuckvckxckyckzclaclccldcleclhclicljclkcllclmclocltcluclwclycmacmecmgcmicmlcmmcmn\
+	cmocmrcmscmtcnacnbcnccngcnhcnicnkcnlcnocnpcnqcnrcnscntcnucnwcnxcoacobcoccodcoecofcogcohcojcokcol\
+	comconcoocopcoqcorcoscotcoucovcowcoxcozcpacpbcpccpgcpicpncpocpscpucpxcpycqdcracrbcrccrdcrecrfcrg\
+	crhcricrjcrkcrlcrmcrncrocrqcrrcrscrtcrvcrwcrxcrycrzcsacsbcsccsdcsecsfcsgcshcsicsjcskcslcsmcsncso\
+	cspcsqcsrcsscstcsvcswcsxcsycszctactcctdctectgcthctlctmctnctoctpctscttctuctyctzcuacubcuccuhcuicuj\
+	cukculcuocupcuqcurcutcuucuvcuwcuxcuycvgcvncwacwbcwdcwecwgcwtcyacybcymcyoczhczkcznczocztdaadacdad\
+	daedagdahdaidajdakdaldamdandaodaqdardasdaudavdawdaxdazdbadbbdbddbedbfdbgdbidbjdbldbmdbndbodbpdbq\
+	dbrdbtdbudbvdbwdbydccdcrddadddddeddgddiddjddnddoddrddsddwdecdeddeedefdegdehdeidekdeldemdendepdeq\
+	derdesdeudevdezdgadgbdgcdgddgedggdghdgidgkdgldgndgodgrdgsdgtdgwdgxdgzdhddhgdhidhldhmdhndhodhrdhs\
+	dhudhvdhwdhxdiadibdicdiddifdigdihdiidijdikdildimdindiodipdiqdirdisdiudivdiwdixdiydizdjadjbdjcdjd\
+	djedjfdjidjjdjkdjmdjndjodjrdjudjwdkadkgdkkdkrdksdkxdlgdlkdlmdlndmadmbdmcdmddmedmfdmgdmkdmldmmdmo\
+	dmrdmsdmudmvdmwdmxdmydnadnddnedngdnidnjdnkdnndnodnrdntdnudnvdnwdnydoadobdocdoedofdohdoidokdoldon\
+	doodopdoqdordosdotdovdowdoxdoydozdppdrbdrcdrddredrgdridrldrndrodrqdrsdrtdrudrydsbdsedshdsidsldsn\
+	dsodsqdszdtadtbdtddthdtidtkdtmdtndtodtpdtrdtsdttdtudtyduadubducduedufdugduhduidukduldumdunduodup\
+	duqdurdusduuduvduwduxduyduzdvadwadwkdwrdwsdwudwwdwydwzdyadybdyddygdyidymdyndyodyudyydzadzedzgdzl\
+	dzndzoeaaebcebgebkeboebrebuecrecsecyeeeefaefeefiegaeglegmegoegyehsehueipeiteivejaekaekeekgekiekk\
+	eklekmekoekpekrekyeleelhelielkellelmeloeluelxemaembemeemgemiemkemmemnempemqemsemuemwemxemyemzena\
+	enbencendenfengenhenlenmennenoenqenrenuenvenwenxeotepiepoeraergerherierkeroerrerserterweseesgesh\
+	esieskeslesmesnesoesqessestesuesyetbetcethetnetoetretsettetuetxetzeuseveevhevneweewoexteyaeyoeza\
+	ezefaafabfadfaffagfahfaifajfakfalfamfanfaofapfarfasfatfaufaxfayfazfblfcsferffiffmfgrfiafiefiffij\
+	filfinfipfirfitfiwfkkfkvflaflhflifllflnflrflyfmpfmufnbfngfnifodfoifomfonforfosfpefqsfrafrcfrdfrk\
+	frmfrofrpfrqfrrfrsfrtfryfsefslfssfubfucfudfuefuffuhfuifujfulfumfunfuqfurfutfuufuvfuyfvrfwafwegaa\
+	gabgacgadgaegafgaggahgaigajgakgalgamgangaogapgaqgargasgatgaugawgaxgaygazgbagbbgbdgbegbfgbggbhgbi\
+	gbjgbkgblgbmgbngbogbpgbqgbrgbsgbugbvgbwgbxgbygbzgccgcdgcegcfgclgcngcrgctgdagdbgdcgddgdegdfgdggdh\
+	gdigdjgdkgdlgdmgdngdogdqgdrgdsgdtgdugdxgeagebgecgedgefgeggehgeigejgekgelgeqgesgevgewgexgeygezgfk\
+	gftggaggbggdggegggggkgglggtgguggwghaghcgheghhghkghlghnghoghrghsghtgiagibgicgidgiegiggihgiigilgim\
+	gingipgiqgirgisgitgiugiwgixgiygizgjkgjmgjngjrgjugkagkdgkegkngkogkpgkuglaglbglcgldgleglgglhgljglk\
+	gllgloglrgluglvglwglygmagmbgmdgmggmhgmlgmmgmngmrgmugmvgmxgmygmzgnagnbgncgndgnegnggnhgnignjgnkgnl\
+	gnmgnngnognqgnrgntgnugnwgnzgoagobgocgodgoegofgoggohgoigojgokgolgomgongoogopgoqgorgosgotgougovgow\
+	goxgoygozgpagpegpngqagqigqngqrgqugragrbgrcgrdgrggrhgrigrjgrmgrngrogrqgrrgrsgrtgrugrvgrwgrxgrygrz\
+	gsegsggslgsmgsngsogspgssgswgtagtuguagubgucgudguegufgugguhguigujgukgulgumgunguogupguqgurgusgutguu\
+	guwguxguzgvagvcgvegvfgvjgvlgvmgvngvogvpgvrgvsgvygwagwbgwcgwdgwegwfgwggwigwjgwmgwngwrgwtgwugwwgwx\
+	gxxgyagybgydgyegyfgyggyigylgymgyngyogyrgyygyzgzagzigznhaahabhachadhaehafhaghahhaihajhakhalhamhan\
+	haohaphaqharhashathauhavhawhaxhayhazhbahbbhbnhbohbshbuhcahchhdnhdshdyheahebhedheghehheihemherhgm\
+	hgwhhihhrhhyhiahibhidhifhighihhiihijhikhilhinhiohirhithiwhixhjihkahkehkhhkkhknhkshlahlbhldhlehlt\
+	hluhmahmbhmchmdhmehmfhmghmhhmihmjhmkhmlhmmhmnhmohmphmqhmrhmshmthmuhmvhmwhmyhmzhnahndhnehnghnhhni\
+	hnjhnnhnohnshnuhoahobhochodhoehohhoihojholhomhoohophorhoshothovhowhoyhozhpohpshrahrchrehrkhrmhro\
+	hrphrthruhrvhrwhrxhrzhsbhshhslhsnhsshtihtohtshtuhtxhubhuchudhuehufhughuhhuihujhukhulhumhunhuohup\
+	huqhurhushuthuuhuvhuwhuxhuyhuzhvchvehvkhvnhvvhwahwchwohyahyehywiaiianiaribaibbibdibeibgibhiblibm\
+	ibniboibribuibyicaichiclicridaidbidciddideidiidoidridsidtiduifaifbifeiffifkifmifuifyigbigeiggigl\
+	igmignigoigsigwihbihiihpihwiiiiinijcijeijjijnijsikeikiikkiklikoikpikriksiktikuikvikwikxikzilailb\
+	ileilgiliilkilmiloilpilsiluilvimaimiimlimnimoimrimsimtimyinainbindinginhinjinlinminninoinpinsint\
+	inzioriouiowipiipkipoiquiqwireirhiriirkirnirriruirxiryisaiscisdiseisgishisiiskislismisnisoisrist\
+	isuitaitbitditeitiitkitlitmitoitritsittitvitwitxityitziumivbivviwkiwmiwoiwsixcixliyaiyoiyxizhizr\
+	izzjaajabjacjadjaejafjahjajjakjaljamjanjaojaqjasjatjaujavjaxjayjazjbejbijbjjbkjbmjbnjbojbrjbtjbu\
+	jbwjcsjctjdajdgjdtjebjeejehjeijekjeljenjerjetjeujgbjgejgkjgojhijhsjiajibjicjidjiejigjihjiijiljim\
+	jiojiqjitjiujivjiyjjejjrjkajkmjkojkpjkrjksjkujlejlsjmajmbjmcjmdjmijmljmnjmrjmsjmwjmxjnajndjngjni\
+	jnjjnljnsjobjodjogjorjosjowjpajpnjprjqrjrajrbjrrjrtjrujsljuajubjucjudjuhjuijukjuljumjunjuojupjur\
+	jusjutjuujuwjuyjvdjvnjwijyajyejyykaakabkackadkaekafkagkahkaikajkakkalkamkankaokapkaqkaskatkaukav\
+	kawkaxkaykazkbakbbkbckbdkbekbgkbhkbikbjkbkkblkbmkbnkbokbpkbqkbrkbskbtkbukbvkbwkbxkbykbzkcakcbkcc\
+	kcdkcekcfkcgkchkcikcjkckkclkcmkcnkcokcpkcqkcrkcskctkcukcvkcwkcxkcykczkdakdckddkdekdfkdgkdhkdikdj\
+	kdkkdlkdmkdnkdpkdqkdrkdtkdukdwkdxkdykdzkeakebkeckedkeekefkegkehkeikejkekkelkemkenkeokepkeqkerkes\
+	ketkeukevkewkexkeykezkfakfbkfckfdkfekffkfgkfhkfikfjkfkkflkfmkfnkfokfpkfqkfrkfskftkfukfvkfwkfxkfy\
+	kfzkgakgbkgekgfkggkgikgjkgkkglkgmkgnkgokgpkgqkgrkgskgtkgukgvkgwkgxkgykhakhbkhckhdkhekhfkhgkhhkhj\
+	khkkhlkhmkhnkhokhpkhqkhrkhskhtkhukhvkhwkhxkhykhzkiakibkickidkiekifkigkihkiikijkikkilkimkinkiokip\
+	kiqkirkiskitkiukivkiwkixkiykizkjakjbkjckjdkjekjgkjhkjikjjkjkkjlkjmkjnkjokjpkjqkjrkjskjtkjukjvkjx\
+	kjykjzkkakkbkkckkdkkekkfkkgkkhkkikkjkkkkklkkmkknkkokkpkkqkkrkkskktkkukkvkkwkkxkkykkzklaklbklckld\
+	kleklfklgklhklikljklkkllklmklnkloklpklqklrklskltkluklvklwklxklyklzkmakmbkmckmdkmekmfkmgkmhkmikmj\
+	kmkkmlkmmkmnkmokmpkmqkmrkmskmtkmukmvkmwkmxkmykmzknaknbknckndkneknfkngkniknjknkknlknmknnknoknpknq\
+	knrknskntknuknvknwknxknyknzkoakockodkoekofkogkohkoikokkolkomkonkookopkoqkorkoskotkoukovkowkoykoz\
+	kpakpbkpckpdkpekpfkpgkphkpikpjkpkkplkpmkpnkpokpqkprkpskptkpukpvkpwkpxkpykpzkqakqbkqckqdkqekqfkqg\
+	kqhkqikqjkqkkqlkqmkqnkqokqpkqqkqrkqskqtkqukqvkqwkqxkqykqzkrakrbkrckrdkrekrfkrhkrikrjkrkkrlkrnkrp\
+	krrkrskrtkrukrvkrwkrxkrykrzksaksbkscksdkseksfksgkshksiksjkskkslksmksnksokspksqksrksskstksuksvksw\
+	ksxksykszktaktbktcktdktektfktgkthktiktjktkktlktmktnktoktpktqktskttktuktvktwktxktyktzkuakubkuckud\
+	kuekufkugkuhkuikujkukkulkumkunkuokupkuqkurkuskutkuukuvkuwkuxkuykuzkvakvbkvckvdkvekvfkvgkvhkvikvj\
+	kvkkvlkvmkvnkvokvpkvqkvrkvtkvukvvkvwkvxkvykvzkwakwbkwckwdkwekwfkwgkwhkwikwjkwkkwlkwmkwnkwokwpkwr\
+	kwskwtkwukwvkwwkwxkwykwzkxakxbkxckxdkxfkxhkxikxjkxkkxmkxnkxokxpkxqkxrkxskxtkxvkxwkxxkxykxzkyakyb\
+	kyckydkyekyfkygkyhkyikyjkykkylkymkynkyokypkyqkyrkyskytkyukyvkywkyxkyykyzkzakzbkzckzdkzekzfkzgkzi\
+	kzkkzlkzmkznkzokzpkzqkzrkzskzukzvkzwkzxkzykzzlaalablacladlaelaflaglahlailajlallamlanlaolaplaqlar\
+	laslatlaulavlawlaxlaylazlbblbclbelbflbglbilbjlbklbllbmlbnlbolbqlbrlbslbtlbulbvlbwlbxlbylbzlcclcd\
+	lcelcflchlcllcmlcplcqlcsldaldblddldgldhldildjldkldlldmldnldoldpldqlealeblecledleeleflehleilejlek\
+	lellemlenleolepleqlerlesletleulevlewlexleylezlfalfnlgalgblgglghlgilgklgllgmlgnlgolgqlgrlgtlgulgz\
+	lhalhhlhilhllhmlhnlhplhslhtlhulialibliclidlielifliglihlijliklillimlinliolipliqlirlislitliulivliw\
+	lixliylizljaljeljiljlljpljwljxlkalkblkclkdlkelkhlkilkjlkllkmlknlkolkrlkslktlkulkyllallbllclldlle\
+	llfllgllhllilljllklllllmllnllpllqllsllullxlmalmblmclmdlmelmflmglmhlmilmjlmklmllmnlmolmplmqlmrlmu\
+	lmvlmwlmxlmylnalnblndlnglnhlnilnjlnllnmlnnlnslnulnwlnzloaloblocloelofloglohloilojloklollomlonloo\
+	loploqlorloslotloulovlowloxloylozlpalpelpnlpolpxlqrlralrclrelrglrilrklrllrmlrnlrolrrlrtlrvlrzlsa\
+	lsblsclsdlselshlsilsllsmlsnlsolsplsrlsslstlsvlswlsyltcltglthltiltnltoltsltultzlualublucludlueluf\
+	lugluilujluklullumlunluolupluqlurluslutluuluvluwluyluzlvalvilvklvslvulwalwelwglwhlwllwmlwolwslwt\
+	lwulwwlxmlyalyglynlzhlzllznlzzmaamabmadmaemafmagmahmaimajmakmalmammanmaqmarmasmatmaumavmawmaxmaz\
+	mbambbmbcmbdmbembfmbhmbimbjmbkmblmbmmbnmbombpmbqmbrmbsmbtmbumbvmbwmbxmbymbzmcamcbmccmcdmcemcfmcg\
+	mchmcimcjmckmclmcmmcnmcomcpmcqmcrmcsmctmcumcvmcwmcxmcymczmdamdbmdcmddmdemdfmdgmdhmdimdjmdkmdlmdm\
+	mdnmdpmdqmdrmdsmdtmdumdvmdwmdxmdymdzmeamebmecmedmeemefmehmeimejmekmelmemmenmeomepmeqmermesmetmeu\
+	mevmewmeymezmfamfbmfcmfdmfemffmfgmfhmfimfjmfkmflmfmmfnmfomfpmfqmfrmfsmftmfumfvmfwmfxmfymfzmgamgb\
+	mgcmgdmgemgfmggmghmgimgjmgkmglmgmmgnmgomgpmgqmgrmgsmgtmgumgvmgwmgymgzmhamhbmhcmhdmhemhfmhgmhimhj\
+	mhkmhlmhmmhnmhomhpmhqmhrmhsmhtmhumhwmhxmhymhzmiamibmicmidmiemifmigmihmiimijmikmilmimminmiomipmiq\
+	mirmismitmiumiwmixmiymizmjbmjcmjdmjemjgmjhmjimjjmjkmjlmjmmjnmjomjpmjqmjrmjsmjtmjumjvmjwmjxmjymjz\
+	mkamkbmkcmkdmkemkfmkgmkimkjmkkmklmkmmknmkomkpmkqmkrmksmktmkumkvmkwmkxmkymkzmlamlbmlcmlemlfmlgmlh\
+	mlimljmlkmllmlmmlnmlomlpmlqmlrmlsmltmlumlvmlwmlxmlzmmammbmmcmmdmmemmfmmgmmhmmimmjmmkmmlmmmmmnmmo\
+	mmpmmqmmrmmtmmummvmmwmmxmmymmzmnamnbmncmndmnemnfmngmnhmnimnjmnkmnlmnmmnnmnpmnqmnrmnsmnumnvmnwmnx\
+	mnymnzmoamocmodmoemogmohmoimojmokmommonmoomopmoqmormosmotmoumovmowmoxmoymozmpampbmpcmpdmpempgmph\
+	mpimpjmpkmplmpmmpnmpomppmpqmprmpsmptmpumpvmpwmpxmpympzmqamqbmqcmqemqfmqgmqhmqimqjmqkmqlmqmmqnmqo\
+	mqpmqqmqrmqsmqtmqumqvmqwmqxmqymqzmramrbmrcmrdmremrfmrgmrhmrimrjmrkmrlmrmmrnmromrpmrqmrrmrsmrtmru\
+	mrvmrwmrxmrymrzmsamsbmscmsdmsemsfmsgmshmsimsjmskmslmsmmsnmsomspmsqmsrmssmsumsvmswmsxmsymszmtamtb\
+	mtcmtdmtemtfmtgmthmtimtjmtkmtlmtmmtnmtomtpmtqmtrmtsmttmtumtvmtwmtxmtymuamubmucmudmuemugmuhmuimuj\
+	mukmulmummuomupmuqmurmusmutmuumuvmuxmuymuzmvamvbmvdmvemvfmvgmvhmvimvkmvlmvnmvomvpmvqmvrmvsmvtmvu\
+	mvvmvwmvxmvymvzmwamwbmwcmwemwfmwgmwhmwimwkmwlmwmmwnmwomwpmwqmwrmwsmwtmwumwvmwwmwzmxamxbmxcmxdmxe\
+	mxfmxgmxhmximxjmxkmxlmxmmxnmxomxpmxqmxrmxsmxtmxumxvmxwmxxmxymxzmyamybmycmyemyfmygmyhmyjmykmylmym\
+	myomypmyrmysmyumyvmywmyxmyymyzmzamzbmzcmzdmzemzgmzhmzimzjmzkmzlmzmmznmzomzpmzqmzrmzsmztmzumzvmzw\
+	mzxmzymzznaanabnacnaenafnagnajnaknalnamnannaonapnaqnarnasnatnaunavnawnaxnaynaznbanbbnbcnbdnbenbg\
+	nbhnbinbjnbknblnbmnbnnbonbpnbqnbrnbsnbtnbunbvnbwnbyncancbnccncdncencfncgnchncincjncknclncmncnnco\
+	ncqncrncsnctncuncxnczndandbndcnddndendfndgndhndindjndkndlndmndnndondpndqndrndsndtndundvndwndxndy\
+	ndzneanebnecnedneenefnegnehneinejneknemnenneonepneqnernesnetneunevnewnexneyneznfanfdnflnfrnfunga\
+	ngbngcngdngenggnghngingjngknglngmngnngpngqngrngsngtngungvngwngxngyngznhanhbnhcnhdnhenhfnhgnhhnhi\
+	nhknhmnhnnhonhpnhqnhrnhtnhunhvnhwnhxnhynhznianibnidnienifnignihniinijniknilnimninnioniqnirnisnit\
+	niunivniwnixniyniznjanjbnjdnjhnjinjjnjlnjmnjnnjonjrnjsnjtnjunjxnjynjznkankbnkcnkdnkenkfnkgnkhnki\
+	nkjnkknkmnknnkonkpnkqnkrnksnktnkunkvnkwnkxnkznlanlcnldnlenlgnlinljnlknllnlmnlonlqnlunlvnlwnlxnly\
+	nlznmanmbnmcnmdnmenmfnmgnmhnminmjnmknmlnmmnmnnmonmpnmqnmrnmsnmtnmunmvnmwnmxnmynmznnannbnncnndnne\
+	nnfnngnnhnninnjnnknnlnnmnnnnnonnpnnqnnrnntnnunnvnnwnnynnznoanobnocnodnoenofnognohnoinojnoknolnom\
+	nonnopnoqnornosnotnounovnownoynoznpanpbnpgnphnpinplnpnnponpsnpunpxnpynqgnqknqlnqmnqnnqonqqnqtnqy\
+	nranrbnrcnrenrfnrgnrinrknrlnrmnrnnrpnrrnrtnrunrxnrznsansbnscnsdnsensfnsgnshnsinsknslnsmnsnnsonsp\
+	nsqnsrnssnstnsunsvnswnsxnsynszntdntentgntintjntkntmntontpntrntuntwntxntyntznuanucnudnuenufnugnuh\
+	nuinujnuknulnumnunnuonupnuqnurnusnutnuunuvnuwnuxnuynuznvhnvmnvonwanwbnwcnwenwgnwinwmnwonwrnwwnwx\
+	nwynxanxdnxenxgnxinxknxlnxmnxnnxonxqnxrnxxnyanybnycnydnyenyfnygnyhnyinyjnyknylnymnynnyonypnyqnyr\
+	nysnytnyunyvnywnyxnyynzanzbnzdnzinzknzmnzsnzunzynzzoaaoacoaroavobiobkoblobmoboobrobtobuocaochoci\
+	ocmocoocuodaodkodtoduofoofsofuogbogcogeoggogooguohtohuoiaoieoinojbojcojgojiojpojsojvojwokaokbokc\
+	okdokeokgokhokiokjokkoklokmoknokookroksokuokvokxokzolaoldoleolkolmoloolroltoluomaombomcomgomiomk\
+	omlomnomoompomromtomuomwomxomyonaonboneongonionjonkonnonoonponronsontonuonwonxoodoogoonooroosopa\
+	opkopmopooptopyoraorcoreorgorhoriormornoroorrorsortoruorvorworxoryorzosaoscosiosnosoospossostosu\
+	osxotaotbotdoteotiotkotlotmotnotqotrotsottotuotwotxotyotzouaouboueouioumovdowiowloyboydoymoyyozm\
+	pabpacpadpaepafpagpahpaipakpalpampanpaopappaqparpaspaupavpawpaxpaypazpbbpbcpbepbfpbgpbhpbipblpbm\
+	pbnpbopbppbrpbspbtpbupbvpbypcapcbpccpcdpcepcfpcgpchpcipcjpckpclpcmpcnpcppcwpdapdcpdipdnpdopdtpdu\
+	peapebpedpeepefpegpehpeipejpekpelpempeopeppeqpespevpexpeypezpfapfepflpgapgdpggpgipgkpglpgnpgspgu\
+	pgzphaphdphgphhphjphkphlphmphnphophqphrphtphuphvphwpiapibpicpidpiepifpigpihpijpilpimpinpiopippir\
+	pispitpiupivpiwpixpiypizpjtpkapkbpkcpkgpkhpknpkopkppkrpkspktpkuplaplbplcpldpleplgplhplipljplkpll\
+	plnploplqplrplspltpluplvplwplyplzpmapmbpmdpmepmfpmhpmipmjpmkpmlpmmpmnpmopmqpmrpmspmtpmwpmxpmypmz\
+	pnapnbpncpndpnepngpnhpnipnjpnkpnlpnmpnnpnopnppnqpnrpnspntpnupnvpnwpnxpnypnzpocpoepofpogpohpoipok\
+	polpomponpoopoppoqporpospotpovpowpoxpoyppeppippkpplppmppnppopppppqppspptppupqapqmprcprdpreprfprg\
+	prhpriprkprlprmprnproprpprqprrprsprtpruprwprxprzpsapscpsdpsepsgpshpsipslpsmpsnpsopsppsqpsrpsspst\
+	psupswpsyptapthptiptnptoptpptqptrpttptuptvptwptypuapubpucpudpuepufpugpuipujpumpuopuppuqpurpusput\
+	puupuwpuxpuypwapwbpwgpwipwmpwnpwopwrpwwpxmpyepympynpyspyupyxpyypzhpznquaqubqucqudquequfqugquhqui\
+	qukqulqumqunqupquqqurqusquvquwquxquyquzqvaqvcqveqvhqviqvjqvlqvmqvnqvoqvpqvsqvwqvyqvzqwaqwcqwhqwm\
+	qwsqwtqxaqxcqxhqxlqxnqxoqxpqxqqxrqxsqxtqxuqxwqyaqypraarabracradrafragrahrairajrakralramranraorap\
+	raqrarrasratrauravrawraxrayrazrbbrbkrblrbprcfrdbrearebreeregreirejrelremrenrerresretreyrgargergk\
+	rgnrgrrgsrgurhgrhpriaribrifrilrimrinrirritriurjgrjirjsrkarkbrkhrkirkmrktrkwrmarmbrmcrmdrmermfrmg\
+	rmhrmirmkrmlrmmrmnrmormprmqrmsrmtrmurmvrmwrmxrmyrmzrnbrndrngrnlrnnrnprnrrnwrobrocrodroerofrogroh\
+	rolromronrooroprorrourowrpnrptrrirrorrtrsbrskrslrsmrsnrtcrthrtmrtsrtwrubrucruerufrugruhruirukrun\
+	ruorupruqrusrutruuruyruzrwarwkrwlrwmrworwrrxdrxwrynrysryurzhsaasabsacsadsaesafsagsahsajsaksamsan\
+	saosaqsarsassatsausavsawsaxsaysazsbasbbsbcsbdsbesbfsbgsbhsbisbjsbksblsbmsbnsbosbpsbqsbrsbssbtsbu\
+	sbvsbwsbxsbysbzscbscescfscgschsciscksclscnscoscpscqscssctscuscvscwscxsdasdbsdcsdesdfsdgsdhsdjsdk\
+	sdlsdnsdosdpsdqsdrsdssdtsdusdxsdzseasebsecsedseesefsegsehseisejsekselsenseosepseqsersessetseusev\
+	sewseysezsfbsfesfmsfssfwsgasgbsgcsgdsgesggsghsgisgjsgksgmsgpsgrsgssgtsgusgwsgxsgysgzshashbshcshd\
+	sheshgshhshishjshkshlshmshnshoshpshqshrshsshtshushvshwshxshyshzsiasibsidsiesifsigsihsiisijsiksil\
+	simsinsipsiqsirsissiusivsiwsixsiysizsjasjbsjdsjesjgsjksjlsjmsjnsjosjpsjrsjssjtsjusjwskaskbskcskd\
+	skeskfskgskhskiskjskmsknskoskpskqskrskssktskuskvskwskxskyskzslcsldsleslfslgslhslisljslksllslmsln\
+	slpslqslrslssltsluslvslwslxslyslzsmasmbsmcsmesmfsmgsmhsmjsmksmlsmmsmnsmosmpsmqsmrsmssmtsmusmvsmw\
+	smxsmysmzsnasncsndsnesnfsngsnisnjsnksnlsnmsnnsnosnpsnqsnrsnssnusnvsnwsnxsnysnzsoasobsocsodsoesog\
+	sohsoisojsoksolsomsoosopsoqsorsossotsousovsowsoxsoysozspaspbspcspdspespgspispksplspmspnsposppspq\
+	sprspssptspuspvspxspysqasqhsqisqksqmsqnsqosqqsqrsqssqtsqusqxsrasrbsrcsrdsresrfsrgsrhsrisrksrlsrm\
+	srnsrosrpsrqsrrsrssrtsrusrvsrwsrxsrysrzssbsscssdssessfssgsshssissjssksslssmssnssosspssqssrssssst\
+	ssussvsswssxssysszstastbstdstestfstgsthstistjstkstlstmstnstostpstqstrstssttstustvstwstysuasubsuc\
+	suesugsuisujsuksunsuosuqsursussutsuvsuwsuxsuysuzsvasvbsvcsvesvksvmsvssvxswaswbswcsweswfswgswhswi\
+	swjswkswlswmswnswoswpswqswrswsswtswuswvswwswxswysxbsxcsxesxgsxksxlsxmsxnsxosxrsxssxusxwsyasybsyc\
+	syisyksylsymsynsyosyrsyssywsyxsyyszaszbszcszdszeszgszlsznszpszsszvszwszytaatabtactadtaetaftagtah\
+	tajtaktaltamtantaotaptaqtartastattautavtawtaxtaytaztbatbctbdtbetbftbgtbhtbitbjtbktbltbmtbntbotbp\
+	tbrtbstbttbutbvtbwtbxtbytbztcatcbtcctcdtcetcftcgtchtcitcktcltcmtcntcotcptcqtcstcttcutcwtcxtcytcz\
+	tdatdbtdctddtdetdftdgtdhtditdjtdktdltdmtdntdotdqtdrtdstdttdvtdxtdyteatebtectedteeteftegtehteitek\
+	teltemtenteotepteqtertestetteutevtewtexteyteztfitfntfotfrtfttgatgbtgctgdtgetgftghtgitgjtgktgltgn\
+	tgotgptgqtgrtgstgttgutgvtgwtgxtgytgzthathdthethfthhthithkthlthmthnthpthqthrthsthtthuthvthythztia\
+	tictiftigtihtiitijtiktiltimtintiotiptiqtirtistittiutivtiwtixtiytiztjatjgtjitjjtjltjmtjntjotjptjs\
+	tjutjwtkatkbtkdtketkftkgtkltkmtkntkptkqtkrtkstkttkutkvtkwtkxtkztlatlbtlctldtlftlgtlhtlitljtlktll\
+	tlmtlntlotlptlqtlrtlstlttlutlvtlxtlytmatmbtmctmdtmetmftmgtmhtmitmjtmktmltmmtmntmotmqtmrtmstmttmu\
+	tmvtmwtmytmztnatnbtnctndtngtnhtnitnktnltnmtnntnotnptnqtnrtnstnttnutnvtnwtnxtnytnztobtoctodtoftog\
+	tohtoitojtoktoltomtontootoptoqtortostoutovtowtoxtoytoztpatpctpetpftpgtpitpjtpktpltpmtpntpotpptpq\
+	tprtpttputpvtpwtpxtpytpztqbtqltqmtqntqotqptqqtqrtqttqutqwtratrbtrctrdtretrftrgtrhtritrjtrltrmtrn\
+	trotrptrqtrrtrstrttrutrvtrwtrxtrytrztsatsbtsctsdtsetsgtshtsitsjtsktsltsmtsntsotsptsqtsrtsststtsu\
+	tsvtswtsxtsytszttattbttcttdttettfttgtthttittjttkttlttmttnttottpttqttrttstttttuttvttwttyttztuatub\
+	tuctudtuetuftugtuhtuitujtuktultumtuntuotuqturtustuutuvtuxtuytuztvatvdtvetvktvltvmtvntvotvstvttvu\
+	tvwtvxtvytwatwbtwctwdtwetwftwgtwhtwitwltwmtwntwotwptwqtwrtwttwutwwtwxtwytxatxbtxctxetxgtxhtxitxj\
+	txmtxntxotxqtxrtxstxttxutxxtxytyatyetyhtyityjtyltyntyptyrtystyttyutyvtyxtyytyztzatzhtzjtzltzmtzn\
+	tzotzxuamuanuarubaubiublubrubuubyudaudeudgudiudjudludmuduuesufiugaugbugeughugnugougyuhauhnuiguis\
+	uivujiukaukgukhukiukkuklukpukqukruksukuukvukwukyulaulbulculeulfuliulkullulmulnuluulwumaumbumcumd\
+	umgumiummumnumoumpumrumsumuunaunduneunguniunkunmunnunrunuunxunzuonupiupvuraurburcurdureurfurgurh\
+	uriurkurlurmurnurourpurrurturuurvurwurxuryurzusaushusiuskuspussusuutauteuthutputrutuuumuuruuuuve\
+	uvhuvluwauyauzbuznuzsvaavaevafvagvahvaivajvalvamvanvaovapvarvasvauvavvayvbbvbkvecvedvelvemvenveo\
+	vepvervgrvgtvicvidvievifvigvilvinvisvitvivvkavkjvkkvklvkmvknvkovkpvktvkuvkzvlpvlsvmavmbvmcvmdvme\
+	vmfvmgvmhvmivmjvmkvmlvmmvmpvmqvmrvmsvmuvmvvmwvmxvmyvmzvnkvnmvnpvolvorvotvravrovrsvrtvsivslvsvvto\
+	vumvunvutvwawaawabwacwadwaewafwagwahwaiwajwalwamwanwaowapwaqwarwaswatwauwavwawwaxwaywazwbawbbwbe\
+	wbfwbhwbiwbjwbkwblwbmwbpwbqwbrwbswbtwbvwbwwcawciwddwdgwdjwdkwdtwduwdyweawecwedwegwehweiwemweowep\
+	werweswetweuwewwfgwgawgbwggwgiwgowguwgywhawhgwhkwhuwibwicwiewifwigwihwiiwijwikwilwimwinwirwiuwiv\
+	wiywjawjiwkawkbwkdwklwkrwkuwkwwkywlawlcwlewlgwlhwliwlkwllwlmwlnwlowlrwlswluwlvwlwwlxwlywmawmbwmc\
+	wmdwmewmgwmhwmiwmmwmnwmowmswmtwmwwmxwnbwncwndwnewngwniwnkwnmwnnwnownpwnuwnwwnywoawobwocwodwoewof\
+	wogwoiwokwolwomwonwooworwoswowwoywpcwrbwrgwrhwriwrkwrlwrmwrnwrowrpwrrwrswruwrvwrwwrxwrywrzwsawsg\
+	wsiwskwsrwsswsuwsvwtfwthwtiwtkwtmwtwwuawubwudwuhwulwumwunwurwutwuuwuvwuxwuywwawwbwwowwrwwwwxawxw\
+	wybwyiwymwynwyrwyyxaaxabxacxadxaexagxaixajxakxalxamxanxaoxapxaqxarxasxatxauxavxawxayxbbxbcxbdxbe\
+	xbgxbixbjxbmxbnxboxbpxbrxbwxbyxcbxccxcexcgxchxclxcmxcnxcoxcrxctxcuxcvxcwxcyxdaxdcxdkxdmxdoxdqxdy\
+	xebxedxegxelxemxepxerxesxetxeuxfaxgaxgbxgdxgfxggxgixglxgmxgrxguxgwxhaxhcxhdxhexhmxhoxhrxhtxhuxhv\
+	xibxiixilxinxirxisxivxiyxjbxjtxkaxkbxkcxkdxkexkfxkgxkixkjxkkxklxknxkoxkpxkqxkrxksxktxkuxkvxkwxkx\
+	xkyxkzxlaxlbxlcxldxlexlgxlixlnxloxlpxlsxluxlyxmaxmbxmcxmdxmexmfxmgxmhxmjxmkxmlxmmxmnxmoxmpxmqxmr\
+	xmsxmtxmuxmvxmwxmxxmyxmzxnaxnbxngxnhxnixnjxnkxnmxnnxnoxnqxnrxnsxntxnuxnyxnzxocxodxogxoixokxomxon\
+	xooxopxorxowxpaxpbxpcxpdxpexpfxpgxphxpixpjxpkxplxpmxpnxpoxppxpqxprxpsxptxpuxpvxpwxpxxpyxpzxqaxqt\
+	xraxrbxrdxrexrgxrixrmxrnxrrxrtxruxrwxsaxsbxscxsdxsexshxsixsjxslxsmxsnxsoxspxsqxsrxssxsuxsvxsyxta\
+	xtbxtcxtdxtextgxthxtixtjxtlxtmxtnxtoxtpxtqxtrxtsxttxtuxtvxtwxtyxuaxubxudxugxujxulxumxunxuoxupxur\
+	xutxuuxvexvixvnxvoxvsxwaxwcxwdxwexwgxwjxwkxwlxwoxwrxwtxwwxxbxxkxxmxxrxxtxyaxybxyjxykxylxytxyyxzh\
+	xzmxzpyaayabyacyadyaeyafyagyahyaiyajyakyalyamyanyaoyapyaqyaryasyatyauyavyawyaxyayyazybaybbybeybh\
+	ybiybjybkyblybmybnyboybxybyychyclycnycpydayddydeydgydkyeayecyeeyeiyejyelyeryesyetyeuyevyeyygaygi\
+	yglygmygpygrygsyguygwyhayhdyhlyhsyiayidyifyigyihyiiyijyikyilyimyinyipyiqyiryisyityiuyivyixyizyka\
+	ykgykiykkyklykmyknykoykryktykuykyylaylbyleylgyliyllylmylnyloylryluylyymbymcymdymeymgymhymiymkyml\
+	ymmymnymoympymqymrymsymxymzynayndyneyngynkynlynnynoynqynsynuyobyogyoiyokyolyomyonyoryotyoxyoyypa\
+	ypbypgyphypmypnypoyppypzyrayrbyreyrkyrlyrmyrnyroyrsyrwyryyscysdysgyslysmysnysoyspysryssysyytaytl\
+	ytpytwytyyuayubyucyudyueyufyugyuiyujyukyulyumyunyupyuqyuryutyuwyuxyuyyuzyvayvtywaywgywlywnywqywr\
+	ywtywuywwyxayxgyxlyxmyxuyxyyyryyuyyzyzgyzkzaazabzaczadzaezafzagzahzaizajzakzalzamzaozapzaqzarzas\
+	zatzauzavzawzaxzayzazzbazbczbezblzbtzbuzbwzcazcdzchzdjzeazegzehzenzgazgbzghzgmzgnzgrzhazhbzhdzhi\
+	zhnzhozhwziazibzikzilzimzinziwzizzkazkbzkdzkgzkhzkkzknzkozkpzkrzktzkuzkvzkzzlazljzlmzlnzlqzmazmb\
+	zmczmdzmezmfzmgzmhzmizmjzmkzmlzmmzmnzmozmpzmqzmrzmszmtzmuzmvzmwzmxzmyzmzznaznezngznkznszoczohzom\
+	zoozoqzorzoszpazpbzpczpdzpezpfzpgzphzpizpjzpkzplzpmzpnzpozppzpqzprzpszptzpuzpvzpwzpxzpyzpzzqezra\
+	zrgzrnzrozrpzrszsazskzslzsmzsrzsuzteztgztlztmztnztpztqztszttztuztxztyzuazuhzulzumzunzuyzwazxxzyb\
+	zygzyjzynzypzzazzj";
+
+/// ISO 639-5 language family and group codes.
+pub(super) static LANGUAGE_FAMILY: &[u8] = b"aavafaalgalvapaaqaaqlartathaufausawdazcbadbaibatberbihbntbtkcaicaucbaccnccscdccddcelcmccpecpfcpp\
+	crpcsucusdaydmndraegxesxeuqfiufoxgemgmegmqgmwgrkhmxhokhyxiirijoincineirairoitcjpxkarkdokhikromap\
+	mkhmnomunmynnahnaingfnicnubomqomvotopaaphiplfpozpqepqwpraqweroasaisalsdvsemsgnsiositslasmisonsqj\
+	ssasydtaitbqtrktuptuttuwurjwakwenxgnxndypkzhxzlezlszlwznd";
+
+/// ISO 15924 script codes.
+pub(super) static SCRIPT: &[u8] = b"AdlmAfakAghbAhomArabAranArmiArmnAvstBaliBamuBassBatkBengBhksBlisBopoBrahBraiBugiBuhdCakmCansCari\
+	ChamCherCirtCoptCprtCyrlCyrsDevaDsrtDuplEgydEgyhEgypElbaEthiGeokGeorGlagGothGranGrekGujrGuruHanb\
+	HangHaniHanoHansHantHatrHebrHiraHluwHmngHrktHungIndsItalJamoJavaJpanJurcKaliKanaKharKhmrKhojKitl\
+	KitsKndaKoreKpelKthiLanaLaooLatfLatgLatnLekeLepcLimbLinaLinbLisuLomaLyciLydiMahjMandManiMarcMaya\
+	MendMercMeroMlymModiMongMoonMrooMteiMultMymrNarbNbatNewaNkgbNkooNshuOgamOlckOrkhOryaOsgeOsmaPalm\
+	PaucPermPhagPhliPhlpPhlvPhnxPiqdPlrdPrtiQaaaQabxRjngRoroRunrSamrSaraSarbSaurSgnwShawShrdSiddSind\
+	SinhSoraSundSyloSyrcSyreSyrjSyrnTagbTakrTaleTaluTamlTangTavtTeluTengTfngTglgThaaThaiTibtTirhUgar\
+	VaiiVispWaraWoleXpeoXsuxYiiiZinhZmthZsyeZsymZxxxZyyyZzzz";
@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod test {
+	use std::collections::{BTreeMap, HashMap};
+
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_keys_rule() {
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		struct Labels {
+			#[validate(keys(length(1..=8), lowercase))]
+			labels: HashMap<String, String>,
+			#[validate(keys(range(..100)))]
+			ports: Option<BTreeMap<u32, &'static str>>,
+		}
+
+		let labels = Labels {
+			labels: HashMap::from([
+				("app".into(), "web".into()),
+				("tier".into(), "Front".into()),
+			]),
+			ports: Some(BTreeMap::from([(80, "http")])),
+		};
+
+		assert!(labels.validate(&()).is_ok());
+
+		let labels = Labels {
+			labels: HashMap::from([("App".into(), "web".into()), (String::new(), "none".into())]),
+			ports: Some(BTreeMap::from([(80, "http"), (443, "https")])),
+		};
+
+		let report = labels.validate(&()).unwrap_err();
+		let debug = format!("{report:?}");
+
+		assert_eq!(report.len(), 3);
+		assert!(debug.contains("(labels.App.$key, Lowercase("));
+		assert!(debug.contains("(labels..$key, Length("));
+		assert!(debug.contains("(ports.443.$key, Range("));
+	}
+}
@@ -337,6 +337,37 @@ where
 	}
 }
 
+macro_rules! impl_length {
+	($($(#[$meta:meta])* $ty:ty: [$($param:ident),*]),* $(,)?) => {
+		$(
+			$(#[$meta])*
+			impl<$($param),*> Length for $ty {
+				#[inline]
+				fn length(&self) -> usize {
+					self.len()
+				}
+			}
+		)*
+	};
+}
+
+impl_length!(
+	#[cfg(feature = "alloc")]
+	crate::alloc::collections::VecDeque<T>: [T],
+	#[cfg(feature = "alloc")]
+	crate::alloc::collections::LinkedList<T>: [T],
+	#[cfg(feature = "alloc")]
+	crate::alloc::collections::BTreeSet<T>: [T],
+	#[cfg(feature = "alloc")]
+	crate::alloc::collections::BTreeMap<K, V>: [K, V],
+	#[cfg(feature = "std")]
+	std::collections::HashSet<T, S>: [T, S],
+	#[cfg(feature = "std")]
+	std::collections::HashMap<K, V, S>: [K, V, S],
+	#[cfg(feature = "indexmap")]
+	indexmap::IndexMap<K, V, S>: [K, V, S],
+);
+
 impl<T: ?Sized> Length for BytesLength<'_, T>
 where
 	T: AsRef<[u8]>,
@@ -420,4 +451,75 @@ mod test {
 		let rule = LengthRule::new().min(6).max(6);
 		assert!(rule.validate(&(), &[1, 2, 3, 4, 5]).is_err());
 	}
+
+	#[test]
+	fn test_length_from_context() {
+		struct Limits {
+			name: usize,
+			tags: usize,
+		}
+
+		#[derive(Wary)]
+		#[wary(crate = "crate", context = Limits)]
+		struct Profile {
+			#[validate(length(max = ctx.name))]
+			name: &'static str,
+			#[validate(length(..=ctx.tags), inner(length(chars, max = ctx.name)))]
+			tags: Vec<&'static str>,
+		}
+
+		let profile = Profile {
+			name: "jane",
+			tags: vec!["rust", "go"],
+		};
+
+		assert!(profile.validate(&Limits { name: 4, tags: 2 }).is_ok());
+		assert_eq!(
+			profile
+				.validate(&Limits { name: 3, tags: 1 })
+				.unwrap_err()
+				.len(),
+			3
+		);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_length_override() {
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		struct User {
+			#[validate(length(
+				chars,
+				3..=16,
+				code = "username_length",
+				message = "Username must be 3-16 characters"
+			))]
+			username: &'static str,
+			#[validate(
+				length(max = 1, message = "Too many tags"),
+				unique(code = "duplicate_tag")
+			)]
+			tags: Vec<&'static str>,
+		}
+
+		let user = User {
+			username: "jo",
+			tags: vec!["a", "b", "a", "b"],
+		};
+
+		let report = user.validate(&()).unwrap_err();
+		let debug = format!("{report:?}");
+
+		assert_eq!(report.len(), 4);
+		assert!(debug.contains(
+			r#"(username, Override { code: "username_length", message: Some("Username must be 3-16 characters"), detail: Length("#
+		));
+		assert!(debug.contains(
+			r#"(tags, Override { code: "too_long", message: Some("Too many tags"), detail: Length("#
+		));
+		assert!(debug.contains(
+			r#"(tags.3, Override { code: "duplicate_tag", message: None, detail: Unique(Duplicate { first: 1 }) })"#
+		));
+	}
 }
@@ -0,0 +1,190 @@
+//! Rule for file signature validation.
+//!
+//! See [`MagicRule`] for more information.
+
+use super::{Allow, Error};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule<A> = MagicRule<A>;
+
+/// A file signature, made of byte patterns at fixed offsets.
+struct Signature {
+	media_type: &'static str,
+	parts: &'static [(usize, &'static [u8])],
+}
+
+const fn sig(media_type: &'static str, parts: &'static [(usize, &'static [u8])]) -> Signature {
+	Signature { media_type, parts }
+}
+
+/// Known signatures, where more specific ones come before the generic ones
+/// they overlap with (e.g. `ftyp` brands before `video/mp4`).
+static SIGNATURES: &[Signature] = &[
+	sig("image/png", &[(0, b"\x89PNG\r\n\x1a\n")]),
+	sig("image/jpeg", &[(0, b"\xff\xd8\xff")]),
+	sig("image/gif", &[(0, b"GIF87a")]),
+	sig("image/gif", &[(0, b"GIF89a")]),
+	sig("image/webp", &[(0, b"RIFF"), (8, b"WEBP")]),
+	sig("image/bmp", &[(0, b"BM")]),
+	sig("image/tiff", &[(0, b"II*\x00")]),
+	sig("image/tiff", &[(0, b"MM\x00*")]),
+	sig("image/vnd.microsoft.icon", &[(0, b"\x00\x00\x01\x00")]),
+	sig("image/avif", &[(4, b"ftypavif")]),
+	sig("image/heic", &[(4, b"ftypheic")]),
+	sig("video/quicktime", &[(4, b"ftypqt  ")]),
+	sig("video/mp4", &[(4, b"ftyp")]),
+	sig("video/webm", &[(0, b"\x1a\x45\xdf\xa3")]),
+	sig("audio/wav", &[(0, b"RIFF"), (8, b"WAVE")]),
+	sig("audio/ogg", &[(0, b"OggS")]),
+	sig("audio/flac", &[(0, b"fLaC")]),
+	sig("audio/mpeg", &[(0, b"ID3")]),
+	sig("audio/mpeg", &[(0, b"\xff\xfb")]),
+	sig("audio/mpeg", &[(0, b"\xff\xf3")]),
+	sig("audio/mpeg", &[(0, b"\xff\xf2")]),
+	sig("application/pdf", &[(0, b"%PDF-")]),
+	sig("application/zip", &[(0, b"PK\x03\x04")]),
+	sig("application/zip", &[(0, b"PK\x05\x06")]),
+	sig("application/gzip", &[(0, b"\x1f\x8b")]),
+	sig("application/zstd", &[(0, b"\x28\xb5\x2f\xfd")]),
+	sig("application/x-bzip2", &[(0, b"BZh")]),
+	sig("application/x-xz", &[(0, b"\xfd7zXZ\x00")]),
+	sig("application/x-7z-compressed", &[(0, b"7z\xbc\xaf\x27\x1c")]),
+	sig("application/vnd.rar", &[(0, b"Rar!\x1a\x07")]),
+	sig("application/wasm", &[(0, b"\x00asm")]),
+	sig("application/vnd.sqlite3", &[(0, b"SQLite format 3\x00")]),
+	sig("font/woff", &[(0, b"wOFF")]),
+	sig("font/woff2", &[(0, b"wOF2")]),
+	sig("font/otf", &[(0, b"OTTO")]),
+	sig("font/ttf", &[(0, b"\x00\x01\x00\x00")]),
+];
+
+/// Returns the media type of the first signature matching the bytes.
+fn detect(bytes: &[u8]) -> Option<&'static str> {
+	SIGNATURES
+		.iter()
+		.find(|signature| {
+			signature.parts.iter().all(|(offset, pattern)| {
+				bytes
+					.get(*offset..offset + pattern.len())
+					.is_some_and(|window| window == *pattern)
+			})
+		})
+		.map(|signature| signature.media_type)
+}
+
+/// Rule for file signature validation.
+///
+/// The leading bytes of the input are matched against the "magic numbers" of
+/// common image, audio, video, document, archive and font formats, and the
+/// detected media type is checked against the allowlist (see the
+/// [module documentation](super) for the pattern syntax). Input that does not
+/// match any known signature is always rejected.
+///
+/// Text formats (like JSON or CSV) have no signature and cannot be detected.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Upload {
+///   #[validate(mime(allow = ["image/*"]))]
+///   content_type: String,
+///   #[validate(magic(allow = ["image/png", "image/jpeg"]))]
+///   content: Vec<u8>,
+/// }
+///
+/// let upload = Upload {
+///   content_type: "image/png".into(),
+///   content: b"\x89PNG\r\n\x1a\n...".to_vec(),
+/// };
+///
+/// assert!(upload.validate(&()).is_ok());
+///
+/// let upload = Upload {
+///   content_type: "image/png".into(),
+///   content: b"%PDF-1.7...".to_vec(),
+/// };
+///
+/// assert!(upload.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct MagicRule<A> {
+	allow: A,
+}
+
+impl MagicRule<Unset> {
+	#[inline]
+	pub const fn new() -> Self {
+		Self { allow: Unset }
+	}
+
+	/// Set the allowed media types.
+	#[inline]
+	pub fn allow<A>(self, allow: A) -> MagicRule<A>
+	where
+		A: Allow,
+	{
+		MagicRule { allow }
+	}
+}
+
+impl<I: ?Sized, A> crate::Rule<I> for MagicRule<A>
+where
+	I: AsRef<[u8]>,
+	A: Allow,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let Some(detected) = detect(item.as_ref()) else {
+			return Err(Error::UnknownSignature.into());
+		};
+
+		if self.allow.allows(detected) {
+			Ok(())
+		} else {
+			Err(Error::SignatureNotAllowed { detected }.into())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{detect, Error, MagicRule};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_detect() {
+		assert_eq!(detect(b"GIF89a\x01\x00"), Some("image/gif"));
+		assert_eq!(detect(b"RIFF\x00\x00\x00\x00WEBPVP8 "), Some("image/webp"));
+		assert_eq!(detect(b"RIFF\x00\x00\x00\x00WAVEfmt "), Some("audio/wav"));
+		assert_eq!(detect(b"\x00\x00\x00\x1cftypavif"), Some("image/avif"));
+		assert_eq!(detect(b"\x00\x00\x00\x1cftypisom"), Some("video/mp4"));
+		assert_eq!(detect(b"RIFF"), None);
+		assert_eq!(detect(b""), None);
+	}
+
+	#[test]
+	fn test_magic_rule() {
+		let rule = MagicRule::new().allow(["image/*", "application/pdf"]);
+
+		assert!(rule.validate(&(), b"\xff\xd8\xff\xe0").is_ok());
+		assert!(rule.validate(&(), b"%PDF-1.4").is_ok());
+		assert_eq!(
+			rule.validate(&(), b"PK\x03\x04"),
+			Err(
+				Error::SignatureNotAllowed {
+					detected: "application/zip"
+				}
+				.into()
+			)
+		);
+		assert_eq!(
+			rule.validate(&(), b"{}"),
+			Err(Error::UnknownSignature.into())
+		);
+	}
+}
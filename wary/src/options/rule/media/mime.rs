@@ -0,0 +1,258 @@
+//! Rule for media type validation.
+//!
+//! See [`MimeRule`] for more information.
+
+use super::{Allow, Error};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule<A> = MimeRule<A>;
+
+/// Rule for media type validation (RFC 6838), with optional parameters as in
+/// a `Content-Type` header (`text/plain; charset=utf-8`).
+///
+/// Use `allow` to only accept some media types (see the
+/// [module documentation](super) for the pattern syntax). Parameters are
+/// ignored when checking the allowlist.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Upload {
+///   #[validate(mime(allow = ["image/*", "application/pdf"]))]
+///   content_type: String,
+/// }
+///
+/// let upload = Upload {
+///   content_type: "image/png".into(),
+/// };
+///
+/// assert!(upload.validate(&()).is_ok());
+///
+/// let upload = Upload {
+///   content_type: "text/html; charset=utf-8".into(),
+/// };
+///
+/// assert!(upload.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct MimeRule<A> {
+	allow: A,
+}
+
+impl MimeRule<Unset> {
+	#[inline]
+	pub const fn new() -> Self {
+		Self { allow: Unset }
+	}
+
+	/// Set the allowed media types.
+	#[inline]
+	pub fn allow<A>(self, allow: A) -> MimeRule<A>
+	where
+		A: Allow,
+	{
+		MimeRule { allow }
+	}
+}
+
+struct Parser<'i> {
+	input: &'i [u8],
+	pos: usize,
+}
+
+impl Parser<'_> {
+	fn peek(&self) -> Option<u8> {
+		self.input.get(self.pos).copied()
+	}
+
+	fn expect(&mut self, byte: u8) -> core::result::Result<(), usize> {
+		if self.peek() == Some(byte) {
+			self.pos += 1;
+			Ok(())
+		} else {
+			Err(self.pos)
+		}
+	}
+
+	fn skip_whitespace(&mut self) {
+		while matches!(self.peek(), Some(b' ' | b'\t')) {
+			self.pos += 1;
+		}
+	}
+
+	/// Parses a `restricted-name` from RFC 6838.
+	fn restricted_name(&mut self) -> core::result::Result<(), usize> {
+		if !self.peek().is_some_and(|b| b.is_ascii_alphanumeric()) {
+			return Err(self.pos);
+		}
+
+		let start = self.pos;
+
+		while self
+			.peek()
+			.is_some_and(|b| b.is_ascii_alphanumeric() || b"!#$&-^_.+".contains(&b))
+		{
+			if self.pos - start == 127 {
+				return Err(self.pos);
+			}
+
+			self.pos += 1;
+		}
+
+		Ok(())
+	}
+
+	/// Parses a `token` from RFC 9110.
+	fn token(&mut self) -> core::result::Result<(), usize> {
+		let start = self.pos;
+
+		while self
+			.peek()
+			.is_some_and(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+		{
+			self.pos += 1;
+		}
+
+		if self.pos == start {
+			Err(self.pos)
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Parses a `quoted-string` from RFC 9110.
+	fn quoted_string(&mut self) -> core::result::Result<(), usize> {
+		self.expect(b'"')?;
+
+		loop {
+			match self.peek() {
+				Some(b'"') => {
+					self.pos += 1;
+					return Ok(());
+				}
+				Some(b'\\') => {
+					self.pos += 1;
+
+					match self.peek() {
+						Some(b'\t' | b' '..=b'~' | 0x80..) => self.pos += 1,
+						_ => return Err(self.pos),
+					}
+				}
+				Some(b'\t' | b' '..=b'~' | 0x80..) => self.pos += 1,
+				_ => return Err(self.pos),
+			}
+		}
+	}
+
+	/// Parses a media type, returning the length of the `type/subtype` part.
+	fn media_type(&mut self) -> core::result::Result<usize, usize> {
+		self.restricted_name()?;
+		self.expect(b'/')?;
+		self.restricted_name()?;
+
+		let essence = self.pos;
+
+		loop {
+			let start = self.pos;
+			self.skip_whitespace();
+
+			if self.peek().is_none() {
+				// trailing whitespace is not allowed
+				return if start == self.pos {
+					Ok(essence)
+				} else {
+					Err(start)
+				};
+			}
+
+			self.expect(b';')?;
+			self.skip_whitespace();
+			self.token()?;
+			self.expect(b'=')?;
+
+			if self.peek() == Some(b'"') {
+				self.quoted_string()?;
+			} else {
+				self.token()?;
+			}
+		}
+	}
+}
+
+impl<I: ?Sized, A> crate::Rule<I> for MimeRule<A>
+where
+	I: AsRef<str>,
+	A: Allow,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let input = item.as_ref();
+		let mut parser = Parser {
+			input: input.as_bytes(),
+			pos: 0,
+		};
+
+		let essence = parser
+			.media_type()
+			.map_err(|position| Error::Syntax { position })?;
+
+		if self.allow.allows(&input[..essence]) {
+			Ok(())
+		} else {
+			Err(Error::NotAllowed.into())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{Error, MimeRule};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_mime_rule() {
+		let rule = MimeRule::new();
+
+		for input in [
+			"text/plain",
+			"application/vnd.api+json",
+			"text/plain;charset=utf-8",
+			"multipart/form-data; boundary=\"a b\\\"c\"",
+			"text/plain ; a=b;c=d",
+		] {
+			assert!(rule.validate(&(), input).is_ok(), "{input}");
+		}
+
+		for (input, position) in [
+			("", 0),
+			("text", 4),
+			("text/", 5),
+			("/plain", 0),
+			("text/plain;", 11),
+			("text/plain; charset", 19),
+			("text/plain; charset=\"utf-8", 26),
+			("text/plain ", 10),
+			("text/pl ain", 8),
+		] {
+			assert_eq!(
+				rule.validate(&(), input),
+				Err(Error::Syntax { position }.into()),
+				"{input}"
+			);
+		}
+
+		let rule = MimeRule::new().allow(["image/*", "application/pdf"]);
+
+		assert!(rule.validate(&(), "image/webp").is_ok());
+		assert!(rule.validate(&(), "application/pdf; version=1.7").is_ok());
+		assert_eq!(
+			rule.validate(&(), "application/zip"),
+			Err(Error::NotAllowed.into())
+		);
+	}
+}
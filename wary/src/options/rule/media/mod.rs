@@ -0,0 +1,117 @@
+//! Rules for validating media types, both declared and sniffed from content.
+//!
+//! Each rule lives in its own module (re-exported at the top level of
+//! [`rule`](crate::options::rule)) and shares the [`Error`] type and [`Allow`]
+//! trait defined here.
+//!
+//! - [`mime`] (RFC 6838 media types, like a `Content-Type` header)
+//! - [`magic`] (file signatures of raw bytes)
+//!
+//! Allowlist entries are media types without parameters, where either part
+//! may be a `*` wildcard (`image/*` or `*/*`). Matching is case-insensitive.
+
+pub mod magic;
+pub mod mime;
+
+use crate::toolbox::rule::*;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Error {
+	#[error("invalid media type at position {position}")]
+	Syntax { position: usize },
+	#[error("media type is not allowed")]
+	NotAllowed,
+	#[error("unknown file signature")]
+	UnknownSignature,
+	#[error("file signature of {detected} is not allowed")]
+	SignatureNotAllowed { detected: &'static str },
+}
+
+impl Error {
+	#[must_use]
+	pub(crate) fn code(&self) -> &'static str {
+		match self {
+			Self::Syntax { .. } => "invalid_media_type",
+			Self::NotAllowed => "media_type_not_allowed",
+			Self::UnknownSignature => "unknown_signature",
+			Self::SignatureNotAllowed { .. } => "signature_not_allowed",
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub(crate) fn message(&self) -> Cow<'static, str> {
+		match self {
+			Self::Syntax { position } => format!("invalid media type at position {position}").into(),
+			Self::NotAllowed => "media type is not allowed".into(),
+			Self::UnknownSignature => "unknown file signature".into(),
+			Self::SignatureNotAllowed { detected } => {
+				format!("file signature of {detected} is not allowed").into()
+			}
+		}
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	pub(crate) fn message(&self) -> &'static str {
+		match self {
+			Self::Syntax { .. } => "invalid media type",
+			Self::NotAllowed => "media type is not allowed",
+			Self::UnknownSignature => "unknown file signature",
+			Self::SignatureNotAllowed { .. } => "file signature is not allowed",
+		}
+	}
+}
+
+/// An allowlist of media types.
+///
+/// This trait is implemented for every [`StrList`], where
+/// [`Unset`](crate::options::Unset) allows any media type.
+pub trait Allow {
+	/// Returns `true` if the media type (without parameters) is allowed.
+	fn allows(&self, media_type: &str) -> bool;
+}
+
+impl<T> Allow for T
+where
+	T: StrList + ?Sized,
+{
+	fn allows(&self, media_type: &str) -> bool {
+		self.strs().map_or(true, |mut patterns| {
+			patterns.any(|pattern| matches(pattern, media_type))
+		})
+	}
+}
+
+/// Returns `true` if the media type matches the allowlist pattern.
+fn matches(pattern: &str, media_type: &str) -> bool {
+	let Some((pattern_type, pattern_subtype)) = pattern.split_once('/') else {
+		return false;
+	};
+
+	let Some((ty, subtype)) = media_type.split_once('/') else {
+		return false;
+	};
+
+	(pattern_type == "*" || pattern_type.eq_ignore_ascii_case(ty))
+		&& (pattern_subtype == "*" || pattern_subtype.eq_ignore_ascii_case(subtype))
+}
+
+#[cfg(test)]
+mod test {
+	use super::Allow;
+
+	#[test]
+	fn test_allow() {
+		let allow = ["image/*", "application/PDF"];
+
+		assert!(allow.allows("image/png"));
+		assert!(allow.allows("IMAGE/png"));
+		assert!(allow.allows("application/pdf"));
+		assert!(!allow.allows("application/zip"));
+		assert!(!allow.allows("imagepng"));
+
+		assert!(["*/*"].allows("text/plain"));
+		assert!(![""; 0].allows("text/plain"));
+	}
+}
@@ -0,0 +1,165 @@
+//! Rule for set exclusion validation.
+//!
+//! See [`NoneOfRule`] for more information.
+
+use core::fmt;
+
+use super::one_of::{eq_ignore_case, Error, IgnoreCase};
+use crate::{options::DebugDisplay, toolbox::rule::*};
+
+#[doc(hidden)]
+pub type Rule<V, Mode> = NoneOfRule<V, Mode>;
+
+/// Rule for set exclusion validation, the inverse of
+/// [`one_of`](super::one_of).
+///
+/// Works with any type that can be compared to the denied values with
+/// [`PartialEq`], such as strings and numbers. Strings can be compared without
+/// regard to case with `ignore_case`.
+///
+/// With the derive macro, the denied values are given as the first argument.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Account {
+///   #[validate(none_of(["admin", "root"], ignore_case))]
+///   username: String,
+///   #[validate(none_of([0]))]
+///   quota: u32,
+/// }
+///
+/// let account = Account {
+///   username: "alice".into(),
+///   quota: 10,
+/// };
+///
+/// assert!(account.validate(&()).is_ok());
+///
+/// let account = Account {
+///   username: "Admin".into(),
+///   quota: 0,
+/// };
+///
+/// assert_eq!(account.validate(&()).unwrap_err().len(), 2);
+/// ```
+#[must_use]
+pub struct NoneOfRule<V, Mode> {
+	values: V,
+	mode: PhantomData<Mode>,
+}
+
+impl NoneOfRule<Unset, Unset> {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			values: Unset,
+			mode: PhantomData,
+		}
+	}
+}
+
+impl<M> NoneOfRule<Unset, M> {
+	/// Set the denied values.
+	#[inline]
+	pub fn value<V>(self, values: V) -> NoneOfRule<V, M>
+	where
+		V: AsSlice,
+	{
+		NoneOfRule {
+			values,
+			mode: PhantomData,
+		}
+	}
+}
+
+impl<V> NoneOfRule<V, Unset> {
+	/// Compare strings without regard to case.
+	#[inline]
+	pub fn ignore_case(self) -> NoneOfRule<V, IgnoreCase> {
+		NoneOfRule {
+			values: self.values,
+			mode: PhantomData,
+		}
+	}
+}
+
+impl<I: ?Sized, V> crate::Rule<I> for NoneOfRule<V, Unset>
+where
+	I: PartialEq<V::Item> + fmt::Debug,
+	V: AsSlice,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		if self.values.as_slice().iter().any(|value| item == value) {
+			return Err(
+				Error::Disallowed {
+					value: DebugDisplay(item).to_string(),
+				}
+				.into(),
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl<I: ?Sized, V> crate::Rule<I> for NoneOfRule<V, IgnoreCase>
+where
+	I: AsRef<str>,
+	V: AsSlice,
+	V::Item: AsRef<str>,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let item = item.as_ref();
+
+		if self
+			.values
+			.as_slice()
+			.iter()
+			.any(|value| eq_ignore_case(item, value.as_ref()))
+		{
+			return Err(
+				Error::Disallowed {
+					value: DebugDisplay(item).to_string(),
+				}
+				.into(),
+			);
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{Error, NoneOfRule};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_none_of_rule() {
+		let rule = NoneOfRule::new().value(["admin", "root"]);
+
+		assert!(rule.validate(&(), &"Admin".to_string()).is_ok());
+		assert_eq!(
+			rule.validate(&(), &"root".to_string()),
+			Err(
+				Error::Disallowed {
+					value: "\"root\"".into()
+				}
+				.into()
+			)
+		);
+
+		let rule = NoneOfRule::new().value(["admin", "root"]).ignore_case();
+
+		assert!(rule.validate(&(), "ADMIN").is_err());
+		assert!(rule.validate(&(), "alice").is_ok());
+	}
+}
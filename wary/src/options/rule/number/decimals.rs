@@ -0,0 +1,104 @@
+//! Rule for decimal place validation.
+//!
+//! See [`DecimalsRule`] for more information.
+
+use super::{Digits, Error};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = DecimalsRule;
+
+/// Rule for decimal place validation.
+///
+/// Works with floats, integers and numeric strings through [`Digits`].
+/// Values that are not finite decimal numbers are rejected.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Payment {
+///   #[validate(decimals(max = 2))]
+///   amount: f64,
+///   #[validate(decimals(max = 2))]
+///   fee: String,
+/// }
+///
+/// let payment = Payment {
+///   amount: 19.99,
+///   fee: "0.50".into(),
+/// };
+///
+/// assert!(payment.validate(&()).is_ok());
+///
+/// let payment = Payment {
+///   amount: 19.999,
+///   fee: "0.5.0".into(),
+/// };
+///
+/// let report = payment.validate(&()).unwrap_err();
+///
+/// assert_eq!(report.len(), 2);
+/// ```
+#[must_use]
+pub struct DecimalsRule {
+	max: Option<u32>,
+}
+
+impl DecimalsRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self { max: None }
+	}
+
+	/// Set the maximum number of decimal places.
+	#[inline]
+	pub const fn max(mut self, max: u32) -> Self {
+		self.max = Some(max);
+		self
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for DecimalsRule
+where
+	I: Digits,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let Some((_, actual)) = item.digits() else {
+			return Err(Error::NotNumber.into());
+		};
+
+		match self.max {
+			Some(max) if actual > max => Err(Error::TooManyDecimals { max, actual }.into()),
+			_ => Ok(()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{DecimalsRule, Error};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_decimals_rule() {
+		let rule = DecimalsRule::new().max(2);
+
+		assert!(rule.validate(&(), &0.1_f64).is_ok());
+		assert!(rule.validate(&(), &10_u32).is_ok());
+		assert!(rule.validate(&(), "1.25").is_ok());
+		assert_eq!(
+			rule.validate(&(), &0.125_f32),
+			Err(Error::TooManyDecimals { max: 2, actual: 3 }.into())
+		);
+		assert_eq!(
+			rule.validate(&(), "1.250"),
+			Err(Error::TooManyDecimals { max: 2, actual: 3 }.into())
+		);
+		assert_eq!(rule.validate(&(), &f64::NAN), Err(Error::NotNumber.into()));
+	}
+}
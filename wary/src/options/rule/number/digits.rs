@@ -0,0 +1,138 @@
+//! Rule for significant digit validation.
+//!
+//! See [`DigitsRule`] for more information.
+
+use super::{Digits, Error};
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = DigitsRule;
+
+/// Rule for significant digit validation.
+///
+/// Limits the number of integer digits (ignoring leading zeros) and fraction
+/// digits, like a SQL `NUMERIC(integer + fraction, fraction)` column. Works
+/// with floats, integers and numeric strings through [`Digits`]. Values that
+/// are not finite decimal numbers are rejected.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Invoice {
+///   #[validate(digits(integer = 6, fraction = 2))]
+///   total: f64,
+///   #[validate(digits(integer = 3))]
+///   quantity: String,
+/// }
+///
+/// let invoice = Invoice {
+///   total: 123456.78,
+///   quantity: "012".into(),
+/// };
+///
+/// assert!(invoice.validate(&()).is_ok());
+///
+/// let invoice = Invoice {
+///   total: 1234567.8,
+///   quantity: "1000".into(),
+/// };
+///
+/// let report = invoice.validate(&()).unwrap_err();
+///
+/// assert_eq!(report.len(), 2);
+/// ```
+#[must_use]
+pub struct DigitsRule {
+	integer: Option<u32>,
+	fraction: Option<u32>,
+}
+
+impl DigitsRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			integer: None,
+			fraction: None,
+		}
+	}
+
+	/// Set the maximum number of integer digits.
+	#[inline]
+	pub const fn integer(mut self, max: u32) -> Self {
+		self.integer = Some(max);
+		self
+	}
+
+	/// Set the maximum number of fraction digits.
+	#[inline]
+	pub const fn fraction(mut self, max: u32) -> Self {
+		self.fraction = Some(max);
+		self
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for DigitsRule
+where
+	I: Digits,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let Some((integer, fraction)) = item.digits() else {
+			return Err(Error::NotNumber.into());
+		};
+
+		if let Some(max) = self.integer {
+			if integer > max {
+				return Err(
+					Error::TooManyDigits {
+						max,
+						actual: integer,
+					}
+					.into(),
+				);
+			}
+		}
+
+		if let Some(max) = self.fraction {
+			if fraction > max {
+				return Err(
+					Error::TooManyDecimals {
+						max,
+						actual: fraction,
+					}
+					.into(),
+				);
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{DigitsRule, Error};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_digits_rule() {
+		let rule = DigitsRule::new().integer(3).fraction(2);
+
+		assert!(rule.validate(&(), &999.99_f64).is_ok());
+		assert!(rule.validate(&(), "-0.5").is_ok());
+		assert!(rule.validate(&(), &100_i32).is_ok());
+		assert_eq!(
+			rule.validate(&(), &1000.5_f64),
+			Err(Error::TooManyDigits { max: 3, actual: 4 }.into())
+		);
+		assert_eq!(
+			rule.validate(&(), "1.005"),
+			Err(Error::TooManyDecimals { max: 2, actual: 3 }.into())
+		);
+		assert_eq!(rule.validate(&(), "abc"), Err(Error::NotNumber.into()));
+	}
+}
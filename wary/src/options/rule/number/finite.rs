@@ -0,0 +1,102 @@
+//! Rule for finite number validation.
+//!
+//! See [`FiniteRule`] for more information.
+
+use super::Error;
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = FiniteRule;
+
+/// A number that may be `NaN` or infinite.
+pub trait Finite {
+	/// Returns `true` if the number is neither `NaN` nor infinite.
+	fn is_finite(&self) -> bool;
+}
+
+impl<T: ?Sized> Finite for &T
+where
+	T: Finite,
+{
+	fn is_finite(&self) -> bool {
+		(**self).is_finite()
+	}
+}
+
+impl Finite for f32 {
+	fn is_finite(&self) -> bool {
+		f32::is_finite(*self)
+	}
+}
+
+impl Finite for f64 {
+	fn is_finite(&self) -> bool {
+		f64::is_finite(*self)
+	}
+}
+
+/// Rule for finite number validation.
+///
+/// Rejects `NaN` and both infinities. Note that [`range`](super::super::range)
+/// already rejects `NaN` whenever a bound is set, since it cannot be compared.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Reading {
+///   #[validate(finite)]
+///   celsius: f64,
+/// }
+///
+/// let reading = Reading { celsius: 21.5 };
+///
+/// assert!(reading.validate(&()).is_ok());
+///
+/// let reading = Reading { celsius: f64::NAN };
+///
+/// assert!(reading.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct FiniteRule;
+
+impl FiniteRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for FiniteRule
+where
+	I: Finite,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		if item.is_finite() {
+			Ok(())
+		} else {
+			Err(Error::NotFinite.into())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::FiniteRule;
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_finite_rule() {
+		let rule = FiniteRule::new();
+
+		assert!(rule.validate(&(), &1.5_f32).is_ok());
+		assert!(rule.validate(&(), &-0.0_f64).is_ok());
+		assert!(rule.validate(&(), &f64::NAN).is_err());
+		assert!(rule.validate(&(), &f32::INFINITY).is_err());
+		assert!(rule.validate(&(), &f64::NEG_INFINITY).is_err());
+	}
+}
@@ -0,0 +1,379 @@
+//! Rules for validating numbers beyond their range.
+//!
+//! Each rule lives in its own module (re-exported at the top level of
+//! [`rule`](crate::options::rule)) and shares the [`Error`] type defined here.
+//!
+//! - [`decimals`] (decimal places)
+//! - [`digits`] (integer and fraction digits)
+//! - [`finite`] (no `NaN` or infinity)
+//! - [`multiple_of`] (divisible by a step)
+
+pub mod decimals;
+pub mod digits;
+pub mod finite;
+pub mod multiple_of;
+
+use core::fmt;
+
+pub use self::{finite::Finite, multiple_of::MultipleOf};
+use crate::{options::ItemSlice, toolbox::rule::*};
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Error {
+	#[error("expected a finite number")]
+	NotFinite,
+	#[error("expected a decimal number")]
+	NotNumber,
+	#[error("expected at most {max} decimal places, found {actual}")]
+	TooManyDecimals { max: u32, actual: u32 },
+	#[error("expected at most {max} integer digits, found {actual}")]
+	TooManyDigits { max: u32, actual: u32 },
+	#[error("expected a multiple")]
+	NotMultipleOf { value: ItemSlice },
+}
+
+impl Error {
+	#[must_use]
+	pub(crate) fn code(&self) -> &'static str {
+		match self {
+			Self::NotFinite => "not_finite",
+			Self::NotNumber => "not_a_number",
+			Self::TooManyDecimals { .. } => "too_many_decimals",
+			Self::TooManyDigits { .. } => "too_many_digits",
+			Self::NotMultipleOf { .. } => "not_multiple_of",
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub(crate) fn message(&self) -> Cow<'static, str> {
+		match self {
+			Self::NotFinite => "expected a finite number".into(),
+			Self::NotNumber => "expected a decimal number".into(),
+			Self::TooManyDecimals { max, actual } => {
+				format!("expected at most {max} decimal places, found {actual}").into()
+			}
+			Self::TooManyDigits { max, actual } => {
+				format!("expected at most {max} integer digits, found {actual}").into()
+			}
+			Self::NotMultipleOf { value } => format!("expected a multiple of {value}").into(),
+		}
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	pub(crate) fn message(&self) -> &'static str {
+		match self {
+			Self::NotFinite => "expected a finite number",
+			Self::NotNumber => "expected a decimal number",
+			Self::TooManyDecimals { .. } => "too many decimal places",
+			Self::TooManyDigits { .. } => "too many integer digits",
+			Self::NotMultipleOf { .. } => "expected a multiple",
+		}
+	}
+}
+
+/// A number with a decimal representation.
+///
+/// Floats use their shortest representation that round-trips (so `0.1_f64`
+/// has one decimal place), and numeric strings are counted as written (so
+/// `"1.50"` has two).
+pub trait Digits {
+	/// Returns the number of significant integer digits and the number of
+	/// fraction digits, or `None` if the value is not a finite decimal number.
+	fn digits(&self) -> Option<(u32, u32)>;
+}
+
+impl<T: ?Sized> Digits for &T
+where
+	T: Digits,
+{
+	fn digits(&self) -> Option<(u32, u32)> {
+		(**self).digits()
+	}
+}
+
+/// Counts the digits of a number written as `[+-]123[.456]`.
+#[derive(Default)]
+struct Counter {
+	integer: u32,
+	fraction: u32,
+	part: Part,
+}
+
+#[derive(Default, Clone, Copy, PartialEq)]
+enum Part {
+	#[default]
+	Start,
+	Sign,
+	Integer,
+	Point,
+	Fraction,
+	Invalid,
+}
+
+impl Counter {
+	fn push(&mut self, c: char) {
+		self.part = match (self.part, c) {
+			(Part::Start, '+' | '-') => Part::Sign,
+			(Part::Integer, '.') => Part::Point,
+			(Part::Point | Part::Fraction, '0'..='9') => {
+				self.fraction += 1;
+				Part::Fraction
+			}
+			(Part::Start | Part::Sign | Part::Integer, '0'..='9') => {
+				// leading zeros are not significant
+				if c != '0' || self.integer > 0 {
+					self.integer += 1;
+				}
+
+				Part::Integer
+			}
+			_ => Part::Invalid,
+		};
+	}
+
+	fn finish(self) -> Option<(u32, u32)> {
+		match self.part {
+			Part::Integer | Part::Fraction => Some((self.integer, self.fraction)),
+			_ => None,
+		}
+	}
+}
+
+impl fmt::Write for Counter {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		s.chars().for_each(|c| self.push(c));
+		Ok(())
+	}
+}
+
+fn count(s: &str) -> Option<(u32, u32)> {
+	let mut counter = Counter::default();
+
+	s.chars().for_each(|c| counter.push(c));
+	counter.finish()
+}
+
+/// Counts the digits of a value's [`Display`](fmt::Display) output without
+/// allocating.
+fn count_display(value: &impl fmt::Display) -> Option<(u32, u32)> {
+	use fmt::Write;
+
+	let mut counter = Counter::default();
+
+	write!(counter, "{value}").ok()?;
+	counter.finish()
+}
+
+/// A buffer on the stack for the [`Display`](fmt::Display) output of any
+/// primitive number. The longest are negative subnormal `f64`s, which print
+/// without an exponent in up to 327 bytes.
+#[cfg(any(
+	feature = "rust_decimal",
+	feature = "bigdecimal",
+	feature = "num-bigint"
+))]
+struct Buffer {
+	bytes: [u8; 384],
+	len: usize,
+}
+
+#[cfg(any(
+	feature = "rust_decimal",
+	feature = "bigdecimal",
+	feature = "num-bigint"
+))]
+impl fmt::Write for Buffer {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		let end = self.len + s.len();
+
+		self
+			.bytes
+			.get_mut(self.len..end)
+			.ok_or(fmt::Error)?
+			.copy_from_slice(s.as_bytes());
+		self.len = end;
+
+		Ok(())
+	}
+}
+
+/// Converts a primitive number to a decimal type through its shortest
+/// representation, so `0.1_f64` becomes exactly `0.1`.
+#[cfg(any(
+	feature = "rust_decimal",
+	feature = "bigdecimal",
+	feature = "num-bigint"
+))]
+pub(crate) fn convert<T: core::str::FromStr>(value: &impl fmt::Display) -> Option<T> {
+	use fmt::Write;
+
+	let mut buffer = Buffer {
+		bytes: [0; 384],
+		len: 0,
+	};
+
+	write!(buffer, "{value}").ok()?;
+	core::str::from_utf8(&buffer.bytes[..buffer.len])
+		.ok()?
+		.parse()
+		.ok()
+}
+
+macro_rules! impl_digits {
+	($($ty:ty),*) => {
+		$(
+			impl Digits for $ty {
+				fn digits(&self) -> Option<(u32, u32)> {
+					count_display(self)
+				}
+			}
+		)*
+	};
+}
+
+impl_digits!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Digits for str {
+	fn digits(&self) -> Option<(u32, u32)> {
+		count(self)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl Digits for String {
+	fn digits(&self) -> Option<(u32, u32)> {
+		count(self)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl Digits for Cow<'_, str> {
+	fn digits(&self) -> Option<(u32, u32)> {
+		count(self)
+	}
+}
+
+#[cfg(feature = "rust_decimal")]
+impl Digits for rust_decimal::Decimal {
+	fn digits(&self) -> Option<(u32, u32)> {
+		let mut mantissa = self.mantissa().unsigned_abs();
+		let mut digits = 0_u32;
+
+		while mantissa > 0 {
+			mantissa /= 10;
+			digits += 1;
+		}
+
+		Some((digits.saturating_sub(self.scale()), self.scale()))
+	}
+}
+
+#[cfg(feature = "bigdecimal")]
+impl Digits for bigdecimal::BigDecimal {
+	fn digits(&self) -> Option<(u32, u32)> {
+		let scale = self.fractional_digit_count();
+		let digits = if bigdecimal::Zero::is_zero(self) {
+			0
+		} else {
+			i64::try_from(bigdecimal::BigDecimal::digits(self)).ok()?
+		};
+
+		Some((
+			u32::try_from((digits - scale).max(0)).ok()?,
+			u32::try_from(scale.max(0)).ok()?,
+		))
+	}
+}
+
+#[cfg(feature = "num-bigint")]
+impl Digits for num_bigint::BigInt {
+	fn digits(&self) -> Option<(u32, u32)> {
+		count_display(self)
+	}
+}
+
+#[cfg(feature = "num-bigint")]
+impl Digits for num_bigint::BigUint {
+	fn digits(&self) -> Option<(u32, u32)> {
+		count_display(self)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::Digits;
+
+	#[test]
+	fn test_digits() {
+		assert_eq!(0.1_f64.digits(), Some((0, 1)));
+		assert_eq!(12.25_f32.digits(), Some((2, 2)));
+		assert_eq!((-100.0_f64).digits(), Some((3, 0)));
+		assert_eq!(f64::NAN.digits(), None);
+		assert_eq!(f64::INFINITY.digits(), None);
+		assert_eq!(1234_u32.digits(), Some((4, 0)));
+		assert_eq!("1.50".digits(), Some((1, 2)));
+		assert_eq!("-007.5".digits(), Some((1, 1)));
+		assert_eq!("0".digits(), Some((0, 0)));
+		assert_eq!("1.".digits(), None);
+		assert_eq!(".5".digits(), None);
+		assert_eq!("1e5".digits(), None);
+		assert_eq!("".digits(), None);
+		assert_eq!("-".digits(), None);
+		assert_eq!("+-1".digits(), None);
+	}
+
+	#[cfg(feature = "rust_decimal")]
+	#[test]
+	fn test_rust_decimal_digits() {
+		use core::str::FromStr;
+
+		use rust_decimal::Decimal;
+
+		let digits = |s| Digits::digits(&Decimal::from_str(s).unwrap());
+
+		assert_eq!(digits("1.50"), Some((1, 2)));
+		assert_eq!(digits("-123.4"), Some((3, 1)));
+		assert_eq!(digits("0.05"), Some((0, 2)));
+		assert_eq!(digits("0"), Some((0, 0)));
+	}
+
+	#[cfg(feature = "bigdecimal")]
+	#[test]
+	fn test_bigdecimal_digits() {
+		use core::str::FromStr;
+
+		use bigdecimal::BigDecimal;
+
+		let digits = |s| Digits::digits(&BigDecimal::from_str(s).unwrap());
+
+		assert_eq!(digits("1.50"), Some((1, 2)));
+		assert_eq!(digits("-123.4"), Some((3, 1)));
+		assert_eq!(digits("0.05"), Some((0, 2)));
+		assert_eq!(digits("5e9"), Some((10, 0)));
+		assert_eq!(digits("0"), Some((0, 0)));
+	}
+
+	#[cfg(feature = "bigdecimal")]
+	#[test]
+	fn test_convert() {
+		use core::str::FromStr;
+
+		use bigdecimal::BigDecimal;
+
+		use super::convert;
+
+		let convert = |value: f64| convert::<BigDecimal>(&value);
+
+		assert_eq!(convert(0.1), BigDecimal::from_str("0.1").ok());
+		assert_eq!(convert(1e300), BigDecimal::from_str("1e300").ok());
+		assert_eq!(
+			convert(-f64::MAX),
+			BigDecimal::from_str(&f64::MAX.to_string())
+				.ok()
+				.map(|max| -max)
+		);
+		assert_eq!(convert(-5e-324), BigDecimal::from_str("-5e-324").ok());
+	}
+}
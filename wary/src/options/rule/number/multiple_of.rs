@@ -0,0 +1,283 @@
+//! Rule for step validation.
+//!
+//! See [`MultipleOfRule`] for more information.
+
+use core::fmt;
+
+use super::Error;
+use crate::{options::DebugDisplay, toolbox::rule::*};
+
+#[doc(hidden)]
+pub type Rule<V> = MultipleOfRule<V>;
+
+/// A number that can be checked for divisibility.
+pub trait MultipleOf<M: ?Sized = Self> {
+	/// Returns `true` if the number is an integer multiple of `step`.
+	fn multiple_of(&self, step: &M) -> bool;
+}
+
+impl<T: ?Sized, M: ?Sized> MultipleOf<M> for &T
+where
+	T: MultipleOf<M>,
+{
+	fn multiple_of(&self, step: &M) -> bool {
+		(**self).multiple_of(step)
+	}
+}
+
+macro_rules! impl_multiple_of_int {
+	($($ty:ty),*) => {
+		$(
+			impl MultipleOf for $ty {
+				fn multiple_of(&self, step: &Self) -> bool {
+					match self.checked_rem(*step) {
+						Some(rem) => rem == 0,
+						// only `MIN % -1` overflows, which is a multiple
+						None => *step != 0 || *self == 0,
+					}
+				}
+			}
+		)*
+	};
+}
+
+impl_multiple_of_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_multiple_of_float {
+	($($ty:ident),*) => {
+		$(
+			impl MultipleOf for $ty {
+				#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+				fn multiple_of(&self, step: &Self) -> bool {
+					// `abs` and `round` are not available in `core`
+					fn abs(value: $ty) -> $ty {
+						if value < 0.0 { -value } else { value }
+					}
+
+					let (value, step) = (*self, *step);
+
+					if !value.is_finite() || !step.is_finite() {
+						return false;
+					}
+
+					if step == 0.0 {
+						return value == 0.0;
+					}
+
+					let quotient = value / step;
+					// every float this large is already an integer
+					let rounded = if abs(quotient) >= (1u64 << $ty::MANTISSA_DIGITS) as $ty {
+						quotient
+					} else if quotient < 0.0 {
+						(quotient - 0.5) as i64 as $ty
+					} else {
+						(quotient + 0.5) as i64 as $ty
+					};
+
+					// allow for the error of representing both in binary
+					abs(value - rounded * step) <= abs(value).max(abs(step)) * $ty::EPSILON * 4.0
+				}
+			}
+		)*
+	};
+}
+
+impl_multiple_of_float!(f32, f64);
+
+/// Implements [`MultipleOf`] for a decimal type with steps of its own type and
+/// of primitive numbers, which are converted through their shortest
+/// representation.
+#[cfg(any(
+	feature = "rust_decimal",
+	feature = "bigdecimal",
+	feature = "num-bigint"
+))]
+macro_rules! impl_multiple_of_decimal {
+	($ty:ty, |$value:ident, $step:ident| $is_multiple:expr, [$($lit:ty),*]) => {
+		impl MultipleOf for $ty {
+			fn multiple_of(&self, step: &Self) -> bool {
+				let ($value, $step) = (self, step);
+				$is_multiple
+			}
+		}
+
+		$(
+			impl MultipleOf<$lit> for $ty {
+				fn multiple_of(&self, step: &$lit) -> bool {
+					super::convert::<$ty>(step).is_some_and(|step| self.multiple_of(&step))
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "rust_decimal")]
+impl_multiple_of_decimal!(
+	rust_decimal::Decimal,
+	|value, step| if step.is_zero() {
+		value.is_zero()
+	} else {
+		value.checked_rem(*step).is_some_and(|rem| rem.is_zero())
+	},
+	[i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64]
+);
+
+#[cfg(feature = "bigdecimal")]
+impl_multiple_of_decimal!(
+	bigdecimal::BigDecimal,
+	|value, step| if bigdecimal::Zero::is_zero(step) {
+		bigdecimal::Zero::is_zero(value)
+	} else {
+		bigdecimal::Zero::is_zero(&(value % step))
+	},
+	[i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64]
+);
+
+#[cfg(feature = "num-bigint")]
+impl_multiple_of_decimal!(
+	num_bigint::BigInt,
+	|value, step| if *step == num_bigint::BigInt::default() {
+		*value == num_bigint::BigInt::default()
+	} else {
+		value % step == num_bigint::BigInt::default()
+	},
+	[i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize]
+);
+
+/// Rule for step validation.
+///
+/// Works with integers and floats through [`MultipleOf`]. Floats are compared
+/// with a small tolerance, so `0.3` is a multiple of `0.1` even though neither
+/// can be represented exactly. `NaN` and infinity are never multiples.
+///
+/// With the derive macro, the step is given as the first argument.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Order {
+///   #[validate(multiple_of(0.25))]
+///   hours: f64,
+///   #[validate(multiple_of(6))]
+///   eggs: u32,
+/// }
+///
+/// let order = Order {
+///   hours: 1.75,
+///   eggs: 12,
+/// };
+///
+/// assert!(order.validate(&()).is_ok());
+///
+/// let order = Order {
+///   hours: 1.3,
+///   eggs: 10,
+/// };
+///
+/// let report = order.validate(&()).unwrap_err();
+///
+/// assert_eq!(report.len(), 2);
+/// ```
+#[must_use]
+pub struct MultipleOfRule<V> {
+	step: V,
+}
+
+impl MultipleOfRule<Unset> {
+	#[inline]
+	pub const fn new() -> Self {
+		Self { step: Unset }
+	}
+
+	/// Set the step that values must be a multiple of.
+	#[inline]
+	pub fn value<V>(self, step: V) -> MultipleOfRule<V> {
+		MultipleOfRule { step }
+	}
+}
+
+impl<I: ?Sized, V> crate::Rule<I> for MultipleOfRule<V>
+where
+	I: MultipleOf<V>,
+	V: fmt::Debug,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		if item.multiple_of(&self.step) {
+			Ok(())
+		} else {
+			Err(
+				Error::NotMultipleOf {
+					value: DebugDisplay(&self.step).to_string(),
+				}
+				.into(),
+			)
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{MultipleOf, MultipleOfRule};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_multiple_of() {
+		assert!(12_u32.multiple_of(&4));
+		assert!(!13_u32.multiple_of(&4));
+		assert!(i8::MIN.multiple_of(&-1));
+		assert!(0_i32.multiple_of(&0));
+		assert!(!1_i32.multiple_of(&0));
+
+		assert!(0.75_f64.multiple_of(&0.25));
+		assert!(0.3_f64.multiple_of(&0.1));
+		assert!(0.3_f32.multiple_of(&0.1));
+		assert!((-1.5_f64).multiple_of(&0.5));
+		assert!(1e300_f64.multiple_of(&0.5));
+		assert!(!0.8_f64.multiple_of(&0.25));
+		assert!(!f64::NAN.multiple_of(&0.25));
+		assert!(!1.0_f64.multiple_of(&0.0));
+	}
+
+	#[test]
+	fn test_multiple_of_rule() {
+		let rule = MultipleOfRule::new().value(0.25);
+
+		assert!(rule.validate(&(), &2.5_f64).is_ok());
+		assert!(rule.validate(&(), &2.6_f64).is_err());
+	}
+
+	#[cfg(feature = "rust_decimal")]
+	#[test]
+	fn test_rust_decimal_multiple_of() {
+		use core::str::FromStr;
+
+		use rust_decimal::Decimal;
+
+		let rule = MultipleOfRule::new().value(0.05);
+
+		assert!(rule
+			.validate(&(), &Decimal::from_str("19.95").unwrap())
+			.is_ok());
+		assert!(rule
+			.validate(&(), &Decimal::from_str("19.99").unwrap())
+			.is_err());
+		assert!(Decimal::from(12).multiple_of(&Decimal::from(4)));
+		assert!(!Decimal::from(1).multiple_of(&0));
+	}
+
+	#[cfg(feature = "num-bigint")]
+	#[test]
+	fn test_bigint_multiple_of() {
+		use num_bigint::BigInt;
+
+		let rule = MultipleOfRule::new().value(3);
+
+		assert!(rule.validate(&(), &BigInt::from(9)).is_ok());
+		assert!(rule.validate(&(), &BigInt::from(10)).is_err());
+	}
+}
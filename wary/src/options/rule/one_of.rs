@@ -0,0 +1,359 @@
+//! Rule for set membership validation.
+//!
+//! See [`OneOfRule`] for more information.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::options::DebugDisplay;
+use crate::{options::ItemSlice, toolbox::rule::*};
+
+#[doc(hidden)]
+pub type Rule<V, Mode> = OneOfRule<V, Mode>;
+
+#[cfg(feature = "alloc")]
+pub(crate) type ItemList = Vec<String>;
+#[cfg(not(feature = "alloc"))]
+pub(crate) type ItemList = ();
+
+pub struct IgnoreCase;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Error {
+	#[error("expected one of the allowed values")]
+	NotOneOf {
+		allowed: ItemList,
+		suggestion: Option<ItemSlice>,
+	},
+	#[error("value is not allowed")]
+	Disallowed { value: ItemSlice },
+}
+
+impl Error {
+	#[must_use]
+	pub(crate) fn code(&self) -> &'static str {
+		match self {
+			Self::NotOneOf { .. } => "not_one_of",
+			Self::Disallowed { .. } => "disallowed_value",
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub(crate) fn message(&self) -> Cow<'static, str> {
+		match self {
+			Self::NotOneOf {
+				allowed,
+				suggestion: Some(suggestion),
+			} => format!(
+				"expected one of {}; did you mean {suggestion}?",
+				allowed.join(", ")
+			),
+			Self::NotOneOf {
+				allowed,
+				suggestion: None,
+			} => format!("expected one of {}", allowed.join(", ")),
+			Self::Disallowed { value } => format!("{value} is not allowed"),
+		}
+		.into()
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	pub(crate) fn message(&self) -> &'static str {
+		match self {
+			Self::NotOneOf { .. } => "expected one of the allowed values",
+			Self::Disallowed { .. } => "value is not allowed",
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+fn list<T: fmt::Debug>(values: &[T]) -> ItemList {
+	values
+		.iter()
+		.map(|value| DebugDisplay(value).to_string())
+		.collect()
+}
+
+#[cfg(not(feature = "alloc"))]
+fn list<T>(_values: &[T]) -> ItemList {}
+
+/// Returns the case-insensitive edit distance between two strings, counting
+/// insertions, deletions, substitutions and swaps of adjacent characters.
+#[cfg(feature = "alloc")]
+fn distance(a: &str, b: &str) -> usize {
+	let a = a.chars().flat_map(char::to_lowercase).collect::<Vec<_>>();
+	let b = b.chars().flat_map(char::to_lowercase).collect::<Vec<_>>();
+
+	// the last three rows of the distance matrix
+	let mut before = vec![0; b.len() + 1];
+	let mut previous = (0..=b.len()).collect::<Vec<_>>();
+	let mut current = vec![0; b.len() + 1];
+
+	for i in 1..=a.len() {
+		current[0] = i;
+
+		for j in 1..=b.len() {
+			let cost = usize::from(a[i - 1] != b[j - 1]);
+
+			current[j] = (previous[j] + 1)
+				.min(current[j - 1] + 1)
+				.min(previous[j - 1] + cost);
+
+			if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+				current[j] = current[j].min(before[j - 2] + 1);
+			}
+		}
+
+		core::mem::swap(&mut before, &mut previous);
+		core::mem::swap(&mut previous, &mut current);
+	}
+
+	previous[b.len()]
+}
+
+/// Returns the closest candidate, if it is close enough to be a likely typo.
+#[cfg(feature = "alloc")]
+fn suggest<'c>(item: &str, candidates: impl Iterator<Item = &'c str>) -> Option<&'c str> {
+	let threshold = (item.chars().count() / 3).max(1);
+
+	candidates
+		.map(|candidate| (distance(item, candidate), candidate))
+		.filter(|(distance, _)| *distance <= threshold)
+		.min_by_key(|(distance, _)| *distance)
+		.map(|(_, candidate)| candidate)
+}
+
+/// Rule for set membership validation.
+///
+/// Works with any type that can be compared to the allowed values with
+/// [`PartialEq`], such as strings and numbers. Strings can be compared without
+/// regard to case with `ignore_case`. If a string is not allowed, the error
+/// suggests the closest allowed value when there is one that looks like a
+/// typo.
+///
+/// With the derive macro, the allowed values are given as the first argument.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Post {
+///   #[validate(one_of(["draft", "published", "archived"]))]
+///   status: String,
+///   #[validate(one_of(["en", "fr"], ignore_case))]
+///   language: String,
+///   #[validate(one_of([1, 2, 4, 8]))]
+///   columns: u8,
+/// }
+///
+/// let post = Post {
+///   status: "draft".into(),
+///   language: "FR".into(),
+///   columns: 4,
+/// };
+///
+/// assert!(post.validate(&()).is_ok());
+///
+/// let post = Post {
+///   status: "drfat".into(),
+///   language: "de".into(),
+///   columns: 3,
+/// };
+///
+/// let report = post.validate(&()).unwrap_err();
+///
+/// assert_eq!(report.len(), 3);
+/// ```
+#[must_use]
+pub struct OneOfRule<V, Mode> {
+	values: V,
+	mode: PhantomData<Mode>,
+}
+
+impl OneOfRule<Unset, Unset> {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			values: Unset,
+			mode: PhantomData,
+		}
+	}
+}
+
+impl<M> OneOfRule<Unset, M> {
+	/// Set the allowed values.
+	#[inline]
+	pub fn value<V>(self, values: V) -> OneOfRule<V, M>
+	where
+		V: AsSlice,
+	{
+		OneOfRule {
+			values,
+			mode: PhantomData,
+		}
+	}
+}
+
+impl<V> OneOfRule<V, Unset> {
+	/// Compare strings without regard to case.
+	#[inline]
+	pub fn ignore_case(self) -> OneOfRule<V, IgnoreCase> {
+		OneOfRule {
+			values: self.values,
+			mode: PhantomData,
+		}
+	}
+}
+
+impl<I: ?Sized, V> crate::Rule<I> for OneOfRule<V, Unset>
+where
+	I: PartialEq<V::Item> + fmt::Debug,
+	V: AsSlice,
+	V::Item: fmt::Debug,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let values = self.values.as_slice();
+
+		if values.iter().any(|value| item == value) {
+			return Ok(());
+		}
+
+		#[cfg(feature = "alloc")]
+		let allowed = list(values);
+		#[cfg(feature = "alloc")]
+		let suggestion = {
+			let item = DebugDisplay(item).to_string();
+
+			// only strings are close to each other as text
+			if item.starts_with('"') {
+				suggest(&item, allowed.iter().map(String::as_str)).map(ToString::to_string)
+			} else {
+				None
+			}
+		};
+		#[cfg(not(feature = "alloc"))]
+		let (allowed, suggestion) = ((), None);
+
+		Err(
+			Error::NotOneOf {
+				allowed,
+				suggestion,
+			}
+			.into(),
+		)
+	}
+}
+
+impl<I: ?Sized, V> crate::Rule<I> for OneOfRule<V, IgnoreCase>
+where
+	I: AsRef<str>,
+	V: AsSlice,
+	V::Item: AsRef<str> + fmt::Debug,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let item = item.as_ref();
+		let values = self.values.as_slice();
+
+		if values
+			.iter()
+			.any(|value| eq_ignore_case(item, value.as_ref()))
+		{
+			return Ok(());
+		}
+
+		#[cfg(feature = "alloc")]
+		let suggestion = suggest(item, values.iter().map(AsRef::as_ref))
+			.map(|suggestion| DebugDisplay(suggestion).to_string());
+		#[cfg(not(feature = "alloc"))]
+		let suggestion = None;
+
+		Err(
+			Error::NotOneOf {
+				allowed: list(values),
+				suggestion,
+			}
+			.into(),
+		)
+	}
+}
+
+/// Compares two strings without regard to case.
+pub(crate) fn eq_ignore_case(a: &str, b: &str) -> bool {
+	a.chars()
+		.flat_map(char::to_lowercase)
+		.eq(b.chars().flat_map(char::to_lowercase))
+}
+
+#[cfg(test)]
+mod test {
+	use super::{distance, suggest, Error, OneOfRule};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_distance() {
+		assert_eq!(distance("draft", "draft"), 0);
+		assert_eq!(distance("drfat", "draft"), 1);
+		assert_eq!(distance("Draft", "draft"), 0);
+		assert_eq!(distance("publish", "published"), 2);
+		assert_eq!(distance("", "abc"), 3);
+
+		let candidates = ["draft", "published", "archived"];
+
+		assert_eq!(suggest("archive", candidates.into_iter()), Some("archived"));
+		assert_eq!(suggest("deleted", candidates.into_iter()), None);
+	}
+
+	#[test]
+	fn test_one_of_rule() {
+		let rule = OneOfRule::new().value(["draft", "published", "archived"]);
+
+		assert!(rule.validate(&(), &"draft".to_string()).is_ok());
+		assert_eq!(
+			rule.validate(&(), &"publihsed".to_string()),
+			Err(
+				Error::NotOneOf {
+					allowed: vec![
+						"\"draft\"".into(),
+						"\"published\"".into(),
+						"\"archived\"".into()
+					],
+					suggestion: Some("\"published\"".into()),
+				}
+				.into()
+			)
+		);
+		let error = rule.validate(&(), &"Draft".to_string()).unwrap_err();
+
+		assert_eq!(
+			error.message().as_deref(),
+			Some("expected one of \"draft\", \"published\", \"archived\"; did you mean \"draft\"?")
+		);
+
+		let rule = OneOfRule::new().value(["en", "fr"]).ignore_case();
+
+		assert!(rule.validate(&(), "EN").is_ok());
+		assert!(rule.validate(&(), "de").is_err());
+
+		let rule = OneOfRule::new().value(vec![0.5, 1.0]);
+
+		assert!(rule.validate(&(), &1.0).is_ok());
+		assert_eq!(
+			rule.validate(&(), &1.5),
+			Err(
+				Error::NotOneOf {
+					allowed: vec!["0.5".into(), "1.0".into()],
+					suggestion: None,
+				}
+				.into()
+			)
+		);
+	}
+}
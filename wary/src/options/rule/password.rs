@@ -0,0 +1,477 @@
+//! Rule for password strength validation.
+//!
+//! See [`PasswordRule`] for more information.
+
+use core::fmt;
+
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule<D> = PasswordRule<D>;
+
+/// A single requirement of a [`PasswordRule`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Requirement {
+	/// The password must have at least `min_length` characters.
+	MinLength,
+	/// The estimated entropy must be at least `min_entropy` bits.
+	MinEntropy,
+	/// The password must contain a lowercase letter.
+	Lowercase,
+	/// The password must contain an uppercase letter.
+	Uppercase,
+	/// The password must contain a digit.
+	Digit,
+	/// The password must contain a character that is not alphanumeric.
+	Special,
+	/// The password must not contain any of the denied values.
+	Deny,
+}
+
+impl Requirement {
+	const ALL: [Self; 7] = [
+		Self::MinLength,
+		Self::MinEntropy,
+		Self::Lowercase,
+		Self::Uppercase,
+		Self::Digit,
+		Self::Special,
+		Self::Deny,
+	];
+
+	#[must_use]
+	pub fn code(self) -> &'static str {
+		match self {
+			Self::MinLength => "min_length",
+			Self::MinEntropy => "min_entropy",
+			Self::Lowercase => "lowercase",
+			Self::Uppercase => "uppercase",
+			Self::Digit => "digit",
+			Self::Special => "special",
+			Self::Deny => "deny",
+		}
+	}
+
+	#[must_use]
+	pub fn message(self) -> &'static str {
+		match self {
+			Self::MinLength => "be longer",
+			Self::MinEntropy => "be less predictable",
+			Self::Lowercase => "contain a lowercase letter",
+			Self::Uppercase => "contain an uppercase letter",
+			Self::Digit => "contain a digit",
+			Self::Special => "contain a special character",
+			Self::Deny => "not contain personal information",
+		}
+	}
+
+	const fn bit(self) -> u8 {
+		1 << self as u8
+	}
+}
+
+/// A set of [`Requirement`]s, such as the ones that a password did not meet.
+///
+/// When serialized, this is a list of [`Requirement::code`]s.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct Requirements(u8);
+
+impl Requirements {
+	const fn with(self, requirement: Requirement) -> Self {
+		Self(self.0 | requirement.bit())
+	}
+
+	fn insert(&mut self, requirement: Requirement) {
+		*self = self.with(requirement);
+	}
+
+	#[must_use]
+	pub fn contains(&self, requirement: Requirement) -> bool {
+		self.0 & requirement.bit() != 0
+	}
+
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.0 == 0
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = Requirement> + '_ {
+		Requirement::ALL
+			.into_iter()
+			.filter(|requirement| self.contains(*requirement))
+	}
+}
+
+impl fmt::Debug for Requirements {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Requirements {
+	fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		serializer.collect_seq(self.iter().map(Requirement::code))
+	}
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Error {
+	#[error("password is too weak")]
+	TooWeak { unmet: Requirements },
+}
+
+impl Error {
+	#[must_use]
+	pub(crate) fn code(&self) -> &'static str {
+		match self {
+			Self::TooWeak { .. } => "password_too_weak",
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub(crate) fn message(&self) -> Cow<'static, str> {
+		match self {
+			Self::TooWeak { unmet } => {
+				let requirements = unmet
+					.iter()
+					.map(Requirement::message)
+					.collect::<Vec<_>>()
+					.join(", ");
+
+				format!("password must {requirements}").into()
+			}
+		}
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	pub(crate) fn message(&self) -> &'static str {
+		match self {
+			Self::TooWeak { .. } => "password is too weak",
+		}
+	}
+}
+
+/// Values that a password must not contain, such as the username or email of
+/// the same account.
+///
+/// This trait is implemented for every [`StrList`].
+pub trait Deny {
+	/// Returns `true` if the password contains any of the values, ignoring ASCII
+	/// case. Empty values are ignored.
+	fn is_contained_in(&self, password: &str) -> bool;
+}
+
+impl<T> Deny for T
+where
+	T: StrList + ?Sized,
+{
+	fn is_contained_in(&self, password: &str) -> bool {
+		self
+			.strs()
+			.is_some_and(|mut values| values.any(|value| contains_ignore_ascii_case(password, value)))
+	}
+}
+
+fn contains_ignore_ascii_case(haystack: &str, needle: &str) -> bool {
+	!needle.is_empty()
+		&& haystack
+			.as_bytes()
+			.windows(needle.len())
+			.any(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// Rule for password strength validation.
+///
+/// Every requirement is checked, and all of the unmet ones are reported in a
+/// single error (see [`Requirements`]) so that they can be shown as a
+/// checklist.
+///
+/// Entropy is estimated as `length * log2(pool)`, where the pool is the sum of
+/// the sizes of the character classes used: 26 for lowercase and uppercase
+/// ASCII letters, 10 for digits, 33 for ASCII punctuation and 100 for anything
+/// else.
+///
+/// Values that the password must not contain (like the username) are given
+/// with `deny`, which accepts other fields or values from the context.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Account {
+///   username: String,
+///   email: String,
+///   #[validate(password(min_length = 8, min_entropy = 50, digit, deny = [username, email]))]
+///   password: String,
+/// }
+///
+/// let account = Account {
+///   username: "gaben".into(),
+///   email: "gaben@valvesoftware.com".into(),
+///   password: "correct horse battery 9".into(),
+/// };
+///
+/// assert!(account.validate(&()).is_ok());
+///
+/// let account = Account {
+///   username: "gaben".into(),
+///   email: "gaben@valvesoftware.com".into(),
+///   password: "Gaben1234".into(),
+/// };
+///
+/// assert!(account.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct PasswordRule<D> {
+	min_length: usize,
+	min_entropy: u32,
+	classes: Requirements,
+	deny: D,
+}
+
+impl PasswordRule<Unset> {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			min_length: 0,
+			min_entropy: 0,
+			classes: Requirements(0),
+			deny: Unset,
+		}
+	}
+}
+
+impl<D> PasswordRule<D> {
+	/// Set the minimum number of characters (inclusive).
+	#[inline]
+	pub const fn min_length(mut self, min_length: usize) -> Self {
+		self.min_length = min_length;
+		self
+	}
+
+	/// Set the minimum estimated entropy, in bits (inclusive).
+	#[inline]
+	pub const fn min_entropy(mut self, min_entropy: u32) -> Self {
+		self.min_entropy = min_entropy;
+		self
+	}
+
+	/// Require a lowercase letter.
+	#[inline]
+	pub const fn lowercase(mut self) -> Self {
+		self.classes = self.classes.with(Requirement::Lowercase);
+		self
+	}
+
+	/// Require an uppercase letter.
+	#[inline]
+	pub const fn uppercase(mut self) -> Self {
+		self.classes = self.classes.with(Requirement::Uppercase);
+		self
+	}
+
+	/// Require a digit.
+	#[inline]
+	pub const fn digit(mut self) -> Self {
+		self.classes = self.classes.with(Requirement::Digit);
+		self
+	}
+
+	/// Require a character that is not alphanumeric.
+	#[inline]
+	pub const fn special(mut self) -> Self {
+		self.classes = self.classes.with(Requirement::Special);
+		self
+	}
+}
+
+impl PasswordRule<Unset> {
+	/// Set the values that the password must not contain.
+	#[inline]
+	pub fn deny<D>(self, deny: D) -> PasswordRule<D>
+	where
+		D: Deny,
+	{
+		PasswordRule {
+			min_length: self.min_length,
+			min_entropy: self.min_entropy,
+			classes: self.classes,
+			deny,
+		}
+	}
+}
+
+/// Computes `log2(x)` as a fixed-point number with 16 fractional bits, without
+/// relying on floating-point functions from `std`.
+fn log2_fixed(x: u32) -> u64 {
+	debug_assert!(x > 0);
+
+	let int = x.ilog2();
+	// mantissa in [1, 2) with 31 fractional bits
+	let mut y = u64::from(x) << (31 - int);
+	let mut frac = 0;
+
+	for bit in (0..16).rev() {
+		y = (y * y) >> 31;
+
+		if y >= 2 << 31 {
+			y >>= 1;
+			frac |= 1 << bit;
+		}
+	}
+
+	u64::from(int) << 16 | frac
+}
+
+impl<I: ?Sized, D> crate::Rule<I> for PasswordRule<D>
+where
+	I: AsRef<str>,
+	D: Deny,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let password = item.as_ref();
+
+		let (mut lowercase, mut uppercase, mut digit, mut special, mut other) =
+			(false, false, false, false, false);
+		let mut length = 0;
+
+		for ch in password.chars() {
+			length += 1;
+
+			match ch {
+				'a'..='z' => lowercase = true,
+				'A'..='Z' => uppercase = true,
+				'0'..='9' => digit = true,
+				_ if ch.is_ascii_punctuation() || ch == ' ' => special = true,
+				_ => other = true,
+			}
+		}
+
+		let mut unmet = Requirements::default();
+
+		if length < self.min_length {
+			unmet.insert(Requirement::MinLength);
+		}
+
+		if self.min_entropy > 0 {
+			let pool = u32::from(lowercase) * 26
+				+ u32::from(uppercase) * 26
+				+ u32::from(digit) * 10
+				+ u32::from(special) * 33
+				+ u32::from(other) * 100;
+
+			let entropy = if pool == 0 {
+				0
+			} else {
+				length as u64 * log2_fixed(pool)
+			};
+
+			if entropy < u64::from(self.min_entropy) << 16 {
+				unmet.insert(Requirement::MinEntropy);
+			}
+		}
+
+		let has_lowercase = lowercase || other && password.chars().any(char::is_lowercase);
+		let has_uppercase = uppercase || other && password.chars().any(char::is_uppercase);
+		let has_digit = digit || other && password.chars().any(char::is_numeric);
+		let has_special = special || other && password.chars().any(|ch| !ch.is_alphanumeric());
+
+		for (present, requirement) in [
+			(has_lowercase, Requirement::Lowercase),
+			(has_uppercase, Requirement::Uppercase),
+			(has_digit, Requirement::Digit),
+			(has_special, Requirement::Special),
+		] {
+			if self.classes.contains(requirement) && !present {
+				unmet.insert(requirement);
+			}
+		}
+
+		if self.deny.is_contained_in(password) {
+			unmet.insert(Requirement::Deny);
+		}
+
+		if unmet.is_empty() {
+			Ok(())
+		} else {
+			Err(Error::TooWeak { unmet }.into())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{log2_fixed, Error, PasswordRule, Requirement, Requirements};
+	use crate::toolbox::test::*;
+
+	fn unmet(requirements: &[Requirement]) -> crate::Error {
+		let mut unmet = Requirements::default();
+
+		for requirement in requirements {
+			unmet.insert(*requirement);
+		}
+
+		Error::TooWeak { unmet }.into()
+	}
+
+	#[test]
+	fn test_log2_fixed() {
+		assert_eq!(log2_fixed(1), 0);
+		assert_eq!(log2_fixed(64), 6 << 16);
+		// log2(10) = 3.3219...
+		assert_eq!(log2_fixed(10) >> 12, 53);
+	}
+
+	#[test]
+	fn test_password_rule() {
+		let rule = PasswordRule::new()
+			.min_length(8)
+			.lowercase()
+			.uppercase()
+			.digit()
+			.special();
+
+		assert!(rule.validate(&(), "Hunter2!").is_ok());
+		assert_eq!(
+			rule.validate(&(), "hunter"),
+			Err(unmet(&[
+				Requirement::MinLength,
+				Requirement::Uppercase,
+				Requirement::Digit,
+				Requirement::Special
+			]))
+		);
+
+		// 8 lowercase letters is 8 * log2(26) = 37.6 bits
+		let rule = PasswordRule::new().min_entropy(38);
+
+		assert!(rule.validate(&(), "abcdefghi").is_ok());
+		assert_eq!(
+			rule.validate(&(), "abcdefgh"),
+			Err(unmet(&[Requirement::MinEntropy]))
+		);
+		assert_eq!(
+			rule.validate(&(), ""),
+			Err(unmet(&[Requirement::MinEntropy]))
+		);
+
+		let (username, email) = (String::from("Gaben"), String::new());
+		let rule = PasswordRule::new().deny([&username, &email]);
+
+		assert!(rule.validate(&(), "hunter2").is_ok());
+		assert_eq!(
+			rule.validate(&(), "xGABENx"),
+			Err(unmet(&[Requirement::Deny]))
+		);
+	}
+}
@@ -218,6 +218,69 @@ impl Compare<&&str> for Cow<'_, str> {
 	}
 }
 
+/// Implements [`Compare`] for a decimal type against primitive numbers and
+/// strings, so that literals can be used as bounds. Numbers are converted
+/// through their shortest representation (so `0.1` is exactly `0.1`), and
+/// strings that cannot be parsed never compare.
+#[cfg(any(
+	feature = "rust_decimal",
+	feature = "bigdecimal",
+	feature = "num-bigint"
+))]
+macro_rules! impl_compare_decimal {
+	($ty:ty, [$($lit:ty),*]) => {
+		$(
+			impl Compare<$lit> for $ty {
+				#[inline]
+				fn compare(&self, other: &$lit) -> Option<Ordering> {
+					self.partial_cmp(&super::number::convert::<$ty>(other)?)
+				}
+			}
+
+			impl Compare<&$lit> for $ty {
+				#[inline]
+				fn compare(&self, other: &&$lit) -> Option<Ordering> {
+					self.compare(*other)
+				}
+			}
+		)*
+
+		impl Compare<&str> for $ty {
+			#[inline]
+			fn compare(&self, other: &&str) -> Option<Ordering> {
+				self.partial_cmp(&other.parse::<$ty>().ok()?)
+			}
+		}
+
+		impl Compare<&&str> for $ty {
+			#[inline]
+			fn compare(&self, other: &&&str) -> Option<Ordering> {
+				self.compare(*other)
+			}
+		}
+	};
+}
+
+#[cfg(feature = "rust_decimal")]
+impl_compare_decimal!(rust_decimal::Decimal, [
+	i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+]);
+
+#[cfg(feature = "bigdecimal")]
+impl_compare_decimal!(bigdecimal::BigDecimal, [
+	i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+]);
+
+#[cfg(feature = "num-bigint")]
+impl_compare_decimal!(num_bigint::BigInt, [
+	i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+]);
+
+#[cfg(feature = "num-bigint")]
+impl_compare_decimal!(num_bigint::BigUint, [
+	i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+]);
+
 #[cfg(test)]
 mod test {
 	use super::*;
@@ -263,6 +326,13 @@ mod test {
 
 		let rule = RangeRule::new().max(&10.0);
 		assert!(rule.validate(&(), &11.0).is_err());
+
+		// NaN cannot be compared, so it is never within a bound
+		let rule = RangeRule::new().min(&0.0);
+		assert!(rule.validate(&(), &f64::NAN).is_err());
+
+		let rule = RangeRule::new().max(&10.0);
+		assert!(rule.validate(&(), &f64::NAN).is_err());
 	}
 
 	#[test]
@@ -288,4 +358,68 @@ mod test {
 		let rule = RangeRule::new().max("worl");
 		assert!(rule.validate(&(), "world").is_err());
 	}
+
+	#[cfg(feature = "rust_decimal")]
+	#[test]
+	fn test_rust_decimal_range() {
+		use core::str::FromStr;
+
+		use rust_decimal::Decimal;
+
+		let rule = RangeRule::new().min(&0.01).max(&1000);
+		assert!(rule
+			.validate(&(), &Decimal::from_str("0.01").unwrap())
+			.is_ok());
+		assert!(rule.validate(&(), &Decimal::from(1000)).is_ok());
+		assert!(rule
+			.validate(&(), &Decimal::from_str("0.009").unwrap())
+			.is_err());
+		assert!(rule
+			.validate(&(), &Decimal::from_str("1000.01").unwrap())
+			.is_err());
+
+		let rule = RangeRule::new().exclusive_min(&"0.1");
+		assert!(rule
+			.validate(&(), &Decimal::from_str("0.1").unwrap())
+			.is_err());
+		assert!(rule
+			.validate(&(), &Decimal::from_str("0.11").unwrap())
+			.is_ok());
+	}
+
+	#[cfg(feature = "bigdecimal")]
+	#[test]
+	fn test_bigdecimal_range() {
+		use core::str::FromStr;
+
+		use bigdecimal::BigDecimal;
+
+		let rule = RangeRule::new().min(&0.1).max(&"1e30");
+		assert!(rule
+			.validate(&(), &BigDecimal::from_str("0.1").unwrap())
+			.is_ok());
+		assert!(rule
+			.validate(&(), &BigDecimal::from_str("1e31").unwrap())
+			.is_err());
+
+		// bounds whose display output is long still convert exactly
+		let rule = RangeRule::new().min(&-5e-324).max(&1e300);
+		assert!(rule
+			.validate(&(), &BigDecimal::from_str("1e300").unwrap())
+			.is_ok());
+		assert!(rule
+			.validate(&(), &BigDecimal::from_str("-1e-323").unwrap())
+			.is_err());
+	}
+
+	#[cfg(feature = "num-bigint")]
+	#[test]
+	fn test_bigint_range() {
+		use num_bigint::BigInt;
+
+		let rule = RangeRule::new().min(&-5).max(&"100000000000000000000000");
+		assert!(rule.validate(&(), &BigInt::from(-5)).is_ok());
+		assert!(rule.validate(&(), &BigInt::from(-6)).is_err());
+		assert!(rule.validate(&(), &BigInt::from(u128::MAX)).is_err());
+	}
 }
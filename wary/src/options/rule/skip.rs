@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod test {
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_skip_if() {
+		struct Context {
+			draft: bool,
+		}
+
+		#[derive(Wary)]
+		#[wary(crate = "crate", context = Context)]
+		struct Post {
+			#[validate(skip_if = |ctx, _| ctx.draft, length(min = 1))]
+			title: &'static str,
+			#[validate(skip_if_default, range(10..))]
+			views: u32,
+			#[validate(skip_if_default, length(max = 1))]
+			tags: Option<Vec<&'static str>>,
+			#[validate(skip_if = |_, body| body.starts_with('#'), ascii)]
+			body: &'static str,
+		}
+
+		let post = Post {
+			title: "",
+			views: 0,
+			tags: None,
+			body: "# 😃",
+		};
+
+		assert!(post.validate(&Context { draft: true }).is_ok());
+		assert_eq!(
+			post.validate(&Context { draft: false }).unwrap_err().len(),
+			1
+		);
+
+		let post = Post {
+			title: "hello",
+			views: 1,
+			tags: Some(vec!["a", "b"]),
+			body: "😃",
+		};
+
+		assert_eq!(
+			post.validate(&Context { draft: true }).unwrap_err().len(),
+			3
+		);
+	}
+
+	#[test]
+	fn test_skip_field() {
+		struct Opaque;
+
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		struct Account {
+			#[validate(length(min = 1))]
+			#[transform(lowercase)]
+			name: String,
+			#[wary(skip)]
+			#[validate(length(min = 1))]
+			#[transform(lowercase)]
+			password: String,
+			#[wary(skip)]
+			#[validate(dive)]
+			opaque: Opaque,
+		}
+
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		enum Login {
+			Password {
+				#[wary(skip)]
+				#[validate(length(min = 1))]
+				secret: &'static str,
+			},
+		}
+
+		let mut account = Account {
+			name: "Jane".into(),
+			password: "HUNTER2".into(),
+			opaque: Opaque,
+		};
+
+		account.transform(&());
+
+		assert_eq!(account.name, "jane");
+		assert_eq!(account.password, "HUNTER2");
+
+		account.password.clear();
+
+		assert!(account.validate(&()).is_ok());
+		assert!(Login::Password { secret: "" }.validate(&()).is_ok());
+	}
+}
@@ -0,0 +1,292 @@
+//! Rule for ordering validation.
+//!
+//! See [`SortedRule`] for more information.
+
+use core::cmp::Ordering;
+
+use crate::{error::Path, toolbox::rule::*};
+
+#[doc(hidden)]
+pub type Rule<By> = SortedRule<By>;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Error {
+	#[error("item at index {index} is out of order")]
+	NotSorted {
+		index: usize,
+		descending: bool,
+		strict: bool,
+	},
+}
+
+impl Error {
+	#[must_use]
+	pub(crate) fn code(&self) -> &'static str {
+		match self {
+			Self::NotSorted { .. } => "not_sorted",
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub(crate) fn message(&self) -> Cow<'static, str> {
+		match self {
+			Self::NotSorted {
+				index,
+				descending,
+				strict,
+			} => {
+				let strict = if *strict { "strictly " } else { "" };
+				let order = if *descending {
+					"descending"
+				} else {
+					"ascending"
+				};
+
+				format!(
+					"expected items in {strict}{order} order, but the item at index {index} is out of order"
+				)
+			}
+		}
+		.into()
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	pub(crate) fn message(&self) -> &'static str {
+		match self {
+			Self::NotSorted { .. } => "item is out of order",
+		}
+	}
+}
+
+/// Rule for ordering validation.
+///
+/// Every item of the collection must be in ascending order (or descending
+/// with `desc`). Equal neighbours are allowed unless `strict` is set. Items
+/// are compared with [`PartialOrd`], or by a key when `by` is set; items that
+/// cannot be compared (such as `NaN`) are out of order.
+///
+/// Only the first item that breaks the order is reported. When used with the
+/// derive macro, it is reported at its index (e.g. `points.3`).
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Tier {
+///   price: u32,
+/// }
+///
+/// #[derive(Wary)]
+/// struct Series {
+///   #[validate(sorted(strict))]
+///   timestamps: Vec<u64>,
+///   #[validate(sorted(desc))]
+///   scores: Vec<f32>,
+///   #[validate(sorted(by = |tier| tier.price))]
+///   tiers: Vec<Tier>,
+/// }
+///
+/// let series = Series {
+///   timestamps: vec![1, 2, 3],
+///   scores: vec![3.0, 2.5, 2.5],
+///   tiers: vec![Tier { price: 5 }, Tier { price: 10 }],
+/// };
+///
+/// assert!(series.validate(&()).is_ok());
+///
+/// let series = Series {
+///   timestamps: vec![1, 1, 3],
+///   scores: vec![3.0, 3.5],
+///   tiers: vec![Tier { price: 10 }, Tier { price: 5 }],
+/// };
+///
+/// let report = series.validate(&()).unwrap_err();
+///
+/// assert_eq!(report.len(), 3);
+/// ```
+#[must_use]
+pub struct SortedRule<By> {
+	by: By,
+	descending: bool,
+	strict: bool,
+}
+
+impl SortedRule<Unset> {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			by: Unset,
+			descending: false,
+			strict: false,
+		}
+	}
+
+	/// Set the key that items are compared by.
+	#[inline]
+	pub fn by<By>(self, by: By) -> SortedRule<By> {
+		SortedRule {
+			by,
+			descending: self.descending,
+			strict: self.strict,
+		}
+	}
+}
+
+impl<By> SortedRule<By> {
+	/// Expect the items in descending order.
+	#[inline]
+	pub const fn desc(mut self) -> Self {
+		self.descending = true;
+		self
+	}
+
+	/// Disallow equal neighbouring items.
+	#[inline]
+	pub const fn strict(mut self) -> Self {
+		self.strict = true;
+		self
+	}
+
+	/// Returns the index of the first item that is out of order.
+	fn find<T>(&self, items: &[T], cmp: impl Fn(&T, &T) -> Option<Ordering>) -> Option<usize> {
+		items
+			.windows(2)
+			.position(|pair| !match cmp(&pair[0], &pair[1]) {
+				Some(Ordering::Less) => !self.descending,
+				Some(Ordering::Greater) => self.descending,
+				Some(Ordering::Equal) => !self.strict,
+				None => false,
+			})
+			.map(|index| index + 1)
+	}
+
+	fn error(&self, index: usize) -> Error {
+		Error::NotSorted {
+			index,
+			descending: self.descending,
+			strict: self.strict,
+		}
+	}
+
+	fn check<T>(&self, items: &[T], cmp: impl Fn(&T, &T) -> Option<Ordering>) -> Result<()> {
+		match self.find(items, cmp) {
+			Some(index) => Err(self.error(index).into()),
+			None => Ok(()),
+		}
+	}
+
+	fn report<T>(
+		&self,
+		items: &[T],
+		cmp: impl Fn(&T, &T) -> Option<Ordering>,
+		parent: &Path,
+		report: &mut Report,
+	) {
+		if let Some(index) = self.find(items, cmp) {
+			report.push(parent.append(index), self.error(index).into());
+		}
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for SortedRule<Unset>
+where
+	I: AsSlice,
+	I::Item: PartialOrd,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		self.check(item.as_slice(), PartialOrd::partial_cmp)
+	}
+
+	fn validate_into(&self, _ctx: &Self::Context, item: &I, parent: &Path, report: &mut Report) {
+		self.report(item.as_slice(), PartialOrd::partial_cmp, parent, report);
+	}
+}
+
+impl<I: ?Sized, K, F> crate::Rule<I> for SortedRule<F>
+where
+	I: AsSlice,
+	K: PartialOrd,
+	F: Fn(&I::Item) -> K,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		self.check(item.as_slice(), |a, b| {
+			(self.by)(a).partial_cmp(&(self.by)(b))
+		})
+	}
+
+	fn validate_into(&self, _ctx: &Self::Context, item: &I, parent: &Path, report: &mut Report) {
+		self.report(
+			item.as_slice(),
+			|a, b| (self.by)(a).partial_cmp(&(self.by)(b)),
+			parent,
+			report,
+		);
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{Error, SortedRule};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_sorted_rule() {
+		let rule = SortedRule::new();
+
+		assert!(rule.validate(&(), &[1, 2, 2, 3]).is_ok());
+		assert!(rule.validate(&(), &Vec::<u8>::new()).is_ok());
+		assert_eq!(
+			rule.validate(&(), &[1, 3, 2, 0]),
+			Err(
+				Error::NotSorted {
+					index: 2,
+					descending: false,
+					strict: false,
+				}
+				.into()
+			)
+		);
+
+		let rule = SortedRule::new().desc().strict();
+
+		assert!(rule.validate(&(), &[3, 2, 1]).is_ok());
+		assert!(rule.validate(&(), &[3, 3, 1]).is_err());
+		assert!(rule.validate(&(), &[1.0, f32::NAN]).is_err());
+	}
+
+	#[test]
+	fn test_sorted_rule_by() {
+		let rule = SortedRule::new().by(|pair: &(u8, u8)| pair.1);
+
+		assert!(rule.validate(&(), &[(2, 1), (1, 2)]).is_ok());
+		assert!(rule.validate(&(), &[(1, 2), (2, 1)]).is_err());
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_sorted_path() {
+		#[derive(crate::Wary)]
+		#[wary(crate = "crate")]
+		struct Series {
+			#[validate(sorted(by = |pair| pair.0))]
+			points: Vec<(u8, u8)>,
+		}
+
+		let series = Series {
+			points: vec![(1, 0), (3, 0), (2, 0), (1, 0)],
+		};
+
+		let report = series.validate(&()).unwrap_err();
+
+		assert_eq!(report.len(), 1);
+		assert!(format!("{report:?}").contains("(points.2, Sorted(NotSorted { index: 2,"));
+	}
+}
@@ -1,9 +1,10 @@
-use core::cmp::Ordering;
+use core::{cmp::Ordering, time::Duration};
 
+use super::duration::Human;
 use crate::toolbox::rule::*;
 
 #[doc(hidden)]
-pub type Rule<Min, Max> = TimeRule<Min, Max>;
+pub type Rule<Min, Max, C> = TimeRule<Min, Max, C>;
 
 #[derive(Debug, thiserror::Error, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
@@ -12,6 +13,18 @@ pub enum Error {
 	TooOld,
 	#[error("time is too new")]
 	TooNew,
+	#[error("expected a time in the past")]
+	NotPast,
+	#[error("expected a time in the future")]
+	NotFuture,
+	#[error("expected a time within {} of now", Human(*window))]
+	OutsideWindow { window: Duration },
+	#[error("expected a time at most {} ago", Human(*max_age))]
+	Expired { max_age: Duration },
+	#[error("the current time is not available")]
+	NoClock,
+	#[error("time cannot be compared to the current time")]
+	Unmeasurable,
 }
 
 impl Error {
@@ -20,18 +33,202 @@ impl Error {
 		match self {
 			Self::TooOld => "too_old",
 			Self::TooNew => "too_new",
+			Self::NotPast => "not_past",
+			Self::NotFuture => "not_future",
+			Self::OutsideWindow { .. } => "outside_window",
+			Self::Expired { .. } => "expired",
+			Self::NoClock => "no_clock",
+			Self::Unmeasurable => "unmeasurable",
 		}
 	}
 
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub(crate) fn message(&self) -> Cow<'static, str> {
+		match self {
+			Self::OutsideWindow { window } => {
+				format!("expected a time within {} of now", Human(*window)).into()
+			}
+			Self::Expired { max_age } => {
+				format!("expected a time at most {} ago", Human(*max_age)).into()
+			}
+			_ => self.static_message().into(),
+		}
+	}
+
+	#[cfg(not(feature = "alloc"))]
 	pub(crate) fn message(&self) -> &'static str {
+		self.static_message()
+	}
+
+	fn static_message(&self) -> &'static str {
 		match self {
 			Self::TooOld => "time is too old",
 			Self::TooNew => "time is too new",
+			Self::NotPast => "expected a time in the past",
+			Self::NotFuture => "expected a time in the future",
+			Self::OutsideWindow { .. } => "time is outside of the allowed window",
+			Self::Expired { .. } => "time is too far in the past",
+			Self::NoClock => "the current time is not available",
+			Self::Unmeasurable => "time cannot be compared to the current time",
 		}
 	}
 }
 
-/// Rule for checking if a time is within a range.
+/// A source of the current time.
+///
+/// Implement this on a context (or a field of one) and pass it to a rule with
+/// `clock = ctx` to control "now", for example to freeze time in tests.
+pub trait Clock {
+	/// Returns the time elapsed since the Unix epoch.
+	fn now(&self) -> Duration;
+}
+
+impl<T: ?Sized> Clock for &T
+where
+	T: Clock,
+{
+	fn now(&self) -> Duration {
+		(**self).now()
+	}
+}
+
+/// A [`Clock`] reading the system time.
+#[cfg(feature = "std")]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+	fn now(&self) -> Duration {
+		std::time::SystemTime::now()
+			.duration_since(std::time::UNIX_EPOCH)
+			.unwrap_or_default()
+	}
+}
+
+/// A [`Clock`] that is stopped at a fixed time since the Unix epoch.
+pub struct FixedClock(pub Duration);
+
+impl Clock for FixedClock {
+	fn now(&self) -> Duration {
+		self.0
+	}
+}
+
+/// A clock that may not be set. Without one, the system time is used (which
+/// requires the `std` feature).
+pub trait MaybeClock {
+	fn try_now(&self) -> Option<Duration>;
+}
+
+impl MaybeClock for Unset {
+	#[cfg(feature = "std")]
+	fn try_now(&self) -> Option<Duration> {
+		Some(SystemClock.now())
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn try_now(&self) -> Option<Duration> {
+		None
+	}
+}
+
+impl<T> MaybeClock for T
+where
+	T: Clock,
+{
+	fn try_now(&self) -> Option<Duration> {
+		Some(self.now())
+	}
+}
+
+/// A point in time that can be compared to a [`Clock`].
+pub trait Instant {
+	/// Returns the nanoseconds since the Unix epoch, or `None` for values that
+	/// are not a point in time (like a time of day). Values without a time zone
+	/// are treated as UTC.
+	fn unix_nanos(&self) -> Option<i128>;
+}
+
+fn nanos(duration: Duration) -> i128 {
+	i128::try_from(duration.as_nanos()).unwrap_or(i128::MAX)
+}
+
+/// The unit of an integer Unix timestamp.
+#[derive(Clone, Copy)]
+enum Epoch {
+	Seconds,
+	Millis,
+}
+
+impl Epoch {
+	fn nanos(self, timestamp: i128) -> i128 {
+		match self {
+			Self::Seconds => timestamp * 1_000_000_000,
+			Self::Millis => timestamp * 1_000_000,
+		}
+	}
+}
+
+/// Checks against the current time.
+#[derive(Clone, Copy, Default)]
+struct Relative {
+	past: bool,
+	future: bool,
+	within: Option<Duration>,
+	not_older_than: Option<Duration>,
+}
+
+impl Relative {
+	fn is_set(&self) -> bool {
+		self.past || self.future || self.within.is_some() || self.not_older_than.is_some()
+	}
+
+	fn check<C>(&self, clock: &C, item: Option<i128>) -> Result<()>
+	where
+		C: MaybeClock,
+	{
+		if !self.is_set() {
+			return Ok(());
+		}
+
+		let now = nanos(clock.try_now().ok_or(Error::NoClock)?);
+		let item = item.ok_or(Error::Unmeasurable)?;
+
+		if self.past && item >= now {
+			return Err(Error::NotPast.into());
+		}
+
+		if self.future && item <= now {
+			return Err(Error::NotFuture.into());
+		}
+
+		if let Some(window) = self.within {
+			if (item - now).abs() > nanos(window) {
+				return Err(Error::OutsideWindow { window }.into());
+			}
+		}
+
+		if let Some(max_age) = self.not_older_than {
+			if now - item > nanos(max_age) {
+				return Err(Error::Expired { max_age }.into());
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// Rule for checking if a time is within a range, or relative to the current
+/// time.
+///
+/// Works with the date and time types of `jiff`, `chrono` and `time` (with the
+/// respective features), [`SystemTime`](std::time::SystemTime), and integer
+/// Unix timestamps in seconds (or milliseconds with `epoch_millis`).
+///
+/// The current time comes from the system clock, unless a [`Clock`] is given
+/// with `clock` (such as the context, with `clock = ctx`). Durations can be
+/// written as literals like `"30d"` (see [`duration`](super::duration)).
 ///
 /// # Example
 ///
@@ -49,15 +246,63 @@ impl Error {
 ///  time_jiff: jiff::civil::DateTime,
 /// }
 /// ```
+///
+/// Relative to the current time, with a frozen clock:
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use wary::{options::rule::time::Clock, Wary, Validate};
+///
+/// struct Context {
+///   now: Duration,
+/// }
+///
+/// impl Clock for Context {
+///   fn now(&self) -> Duration {
+///     self.now
+///   }
+/// }
+///
+/// #[derive(Wary)]
+/// #[wary(context = Context)]
+/// struct Token {
+///   #[validate(time(not_older_than = "1y", clock = ctx))]
+///   issued_at: jiff::Timestamp,
+///   #[validate(time(future, within = "30d", clock = ctx))]
+///   expires_at: jiff::Timestamp,
+/// }
+///
+/// let ctx = Context {
+///   now: Duration::from_secs(1_746_333_380),
+/// };
+///
+/// let token = Token {
+///   issued_at: jiff::Timestamp::from_second(1_746_000_000).unwrap(),
+///   expires_at: jiff::Timestamp::from_second(1_747_000_000).unwrap(),
+/// };
+///
+/// assert!(token.validate(&ctx).is_ok());
+///
+/// let token = Token {
+///   issued_at: jiff::Timestamp::from_second(1_600_000_000).unwrap(),
+///   expires_at: jiff::Timestamp::from_second(1_746_000_000).unwrap(),
+/// };
+///
+/// assert_eq!(token.validate(&ctx).unwrap_err().len(), 2);
+/// ```
 #[must_use]
-pub struct TimeRule<Min, Max> {
+pub struct TimeRule<Min, Max, C> {
 	min: Option<Min>,
 	max: Option<Max>,
 	exclusive_min: bool,
 	exclusive_max: bool,
+	relative: Relative,
+	epoch: Epoch,
+	clock: C,
 }
 
-impl TimeRule<Unset, Unset> {
+impl TimeRule<Unset, Unset, Unset> {
 	#[inline]
 	pub const fn new() -> Self {
 		TimeRule {
@@ -65,137 +310,292 @@ impl TimeRule<Unset, Unset> {
 			max: None,
 			exclusive_min: false,
 			exclusive_max: false,
+			relative: Relative {
+				past: false,
+				future: false,
+				within: None,
+				not_older_than: None,
+			},
+			epoch: Epoch::Seconds,
+			clock: Unset,
 		}
 	}
 }
 
-impl<Max> TimeRule<Unset, Max> {
+impl<Max, C> TimeRule<Unset, Max, C> {
 	/// After a specific time.
 	#[inline]
-	pub fn after<Min>(self, after: Min) -> TimeRule<Min, Max> {
+	pub fn after<Min>(self, after: Min) -> TimeRule<Min, Max, C> {
 		self.min(after)
 	}
 
 	/// Set the minimum value (inclusive).
 	#[inline]
-	pub fn min<Min>(self, min: Min) -> TimeRule<Min, Max> {
+	pub fn min<Min>(self, min: Min) -> TimeRule<Min, Max, C> {
 		TimeRule {
 			min: Some(min),
 			max: self.max,
 			exclusive_min: false,
 			exclusive_max: self.exclusive_max,
+			relative: self.relative,
+			epoch: self.epoch,
+			clock: self.clock,
 		}
 	}
 
 	/// Set the minimum value (exclusive).
 	#[inline]
-	pub fn exclusive_min<Min>(self, min: Min) -> TimeRule<Min, Max> {
+	pub fn exclusive_min<Min>(self, min: Min) -> TimeRule<Min, Max, C> {
 		TimeRule {
 			min: Some(min),
 			max: self.max,
 			exclusive_min: true,
 			exclusive_max: self.exclusive_max,
+			relative: self.relative,
+			epoch: self.epoch,
+			clock: self.clock,
 		}
 	}
 }
 
-impl<Min> TimeRule<Min, Unset> {
+impl<Min, C> TimeRule<Min, Unset, C> {
 	/// Before a specific time.
 	#[inline]
-	pub fn before<Max>(self, before: Max) -> TimeRule<Min, Max> {
+	pub fn before<Max>(self, before: Max) -> TimeRule<Min, Max, C> {
 		self.max(before)
 	}
 
 	/// Set the maximum value (inclusive).
 	#[inline]
-	pub fn max<Max>(self, max: Max) -> TimeRule<Min, Max> {
+	pub fn max<Max>(self, max: Max) -> TimeRule<Min, Max, C> {
 		TimeRule {
 			min: self.min,
 			max: Some(max),
 			exclusive_min: self.exclusive_min,
 			exclusive_max: false,
+			relative: self.relative,
+			epoch: self.epoch,
+			clock: self.clock,
 		}
 	}
 
 	/// Set the maximum value (exclusive).
 	#[inline]
-	pub fn exclusive_max<Max>(self, max: Max) -> TimeRule<Min, Max> {
+	pub fn exclusive_max<Max>(self, max: Max) -> TimeRule<Min, Max, C> {
 		TimeRule {
 			min: self.min,
 			max: Some(max),
 			exclusive_min: self.exclusive_min,
 			exclusive_max: true,
+			relative: self.relative,
+			epoch: self.epoch,
+			clock: self.clock,
+		}
+	}
+}
+
+impl<Min, Max> TimeRule<Min, Max, Unset> {
+	/// Set the clock used to get the current time.
+	#[inline]
+	pub fn clock<C>(self, clock: C) -> TimeRule<Min, Max, C>
+	where
+		C: Clock,
+	{
+		TimeRule {
+			min: self.min,
+			max: self.max,
+			exclusive_min: self.exclusive_min,
+			exclusive_max: self.exclusive_max,
+			relative: self.relative,
+			epoch: self.epoch,
+			clock,
+		}
+	}
+}
+
+impl<Min, Max, C> TimeRule<Min, Max, C> {
+	/// Before the current time.
+	#[inline]
+	pub fn past(mut self) -> Self {
+		self.relative.past = true;
+		self
+	}
+
+	/// After the current time.
+	#[inline]
+	pub fn future(mut self) -> Self {
+		self.relative.future = true;
+		self
+	}
+
+	/// At most this far from the current time, in either direction.
+	#[inline]
+	pub fn within(mut self, window: Duration) -> Self {
+		self.relative.within = Some(window);
+		self
+	}
+
+	/// At most this far before the current time.
+	#[inline]
+	pub fn not_older_than(mut self, max_age: Duration) -> Self {
+		self.relative.not_older_than = Some(max_age);
+		self
+	}
+
+	/// Treat integers as Unix timestamps in seconds (the default).
+	#[inline]
+	pub fn epoch_seconds(mut self) -> Self {
+		self.epoch = Epoch::Seconds;
+		self
+	}
+
+	/// Treat integers as Unix timestamps in milliseconds.
+	#[inline]
+	pub fn epoch_millis(mut self) -> Self {
+		self.epoch = Epoch::Millis;
+		self
+	}
+}
+
+impl<Min, Max, C> TimeRule<Min, Max, C>
+where
+	C: MaybeClock,
+{
+	fn check<T>(&self, item: &T, nanos: Option<i128>, min: Option<&T>, max: Option<&T>) -> Result<()>
+	where
+		T: Ord + ?Sized,
+	{
+		if let Some(min) = min {
+			match item.cmp(min) {
+				Ordering::Greater => {}
+				Ordering::Equal if !self.exclusive_min => {}
+				_ => return Err(Error::TooNew.into()),
+			}
+		}
+
+		if let Some(max) = max {
+			match item.cmp(max) {
+				Ordering::Less => {}
+				Ordering::Equal if !self.exclusive_max => {}
+				_ => return Err(Error::TooOld.into()),
+			}
 		}
+
+		self.relative.check(&self.clock, nanos)
 	}
 }
 
 macro_rules! impl_rule {
-	($type:ty) => {
-		impl crate::Rule<$type> for TimeRule<$type, $type> {
+	([$($generics:tt)*] $type:ty, |$this:ident, $item:ident| $nanos:expr) => {
+		impl<C: MaybeClock, $($generics)*> crate::Rule<$type> for TimeRule<$type, $type, C> {
 			type Context = ();
 
 			#[inline]
 			fn validate(&self, _ctx: &Self::Context, item: &$type) -> Result<()> {
-				if let Some(min) = &self.min {
-					match item.cmp(min) {
-						Ordering::Greater => {}
-						Ordering::Equal if !self.exclusive_min => {}
-						_ => return Err(Error::TooNew.into()),
-					}
-				}
+				let ($this, $item) = (self, item);
+				self.check(item, $nanos, self.min.as_ref(), self.max.as_ref())
+			}
+		}
 
-				if let Some(max) = &self.max {
-					match item.cmp(max) {
-						Ordering::Less => {}
-						Ordering::Equal if !self.exclusive_max => {}
-						_ => return Err(Error::TooOld.into()),
-					}
-				}
+		impl<C: MaybeClock, $($generics)*> crate::Rule<$type> for TimeRule<$type, Unset, C> {
+			type Context = ();
 
-				Ok(())
+			#[inline]
+			fn validate(&self, _ctx: &Self::Context, item: &$type) -> Result<()> {
+				let ($this, $item) = (self, item);
+				self.check(item, $nanos, self.min.as_ref(), None)
 			}
 		}
 
-		impl crate::Rule<$type> for TimeRule<$type, Unset> {
+		impl<C: MaybeClock, $($generics)*> crate::Rule<$type> for TimeRule<Unset, $type, C> {
 			type Context = ();
 
 			#[inline]
 			fn validate(&self, _ctx: &Self::Context, item: &$type) -> Result<()> {
-				if let Some(min) = &self.min {
-					match item.cmp(min) {
-						Ordering::Greater => {}
-						Ordering::Equal if !self.exclusive_min => {}
-						_ => return Err(Error::TooNew.into()),
-					}
-				}
-
-				Ok(())
+				let ($this, $item) = (self, item);
+				self.check(item, $nanos, None, self.max.as_ref())
 			}
 		}
 
-		impl crate::Rule<$type> for TimeRule<Unset, $type> {
+		impl<C: MaybeClock, $($generics)*> crate::Rule<$type> for TimeRule<Unset, Unset, C> {
 			type Context = ();
 
 			#[inline]
 			fn validate(&self, _ctx: &Self::Context, item: &$type) -> Result<()> {
-				if let Some(max) = &self.max {
-					match item.cmp(max) {
-						Ordering::Less => {}
-						Ordering::Equal if !self.exclusive_max => {}
-						_ => return Err(Error::TooOld.into()),
-					}
-				}
-
-				Ok(())
+				let ($this, $item) = (self, item);
+				self.check(item, $nanos, None, None)
 			}
 		}
 	};
+	([$($generics:tt)*] $type:ty) => {
+		impl_rule!([$($generics)*] $type, |_rule, item| Instant::unix_nanos(item));
+	};
+	($type:ty) => {
+		impl_rule!([] $type);
+	};
+}
+
+macro_rules! impl_epoch_rule {
+	($($type:ty),*) => {
+		$(
+			impl_rule!([] $type, |rule, item| Some(rule.epoch.nanos(i128::from(*item))));
+		)*
+	};
+}
+
+impl_epoch_rule!(i32, i64, u32, u64);
+
+#[cfg(feature = "std")]
+impl Instant for std::time::SystemTime {
+	fn unix_nanos(&self) -> Option<i128> {
+		Some(match self.duration_since(std::time::UNIX_EPOCH) {
+			Ok(duration) => nanos(duration),
+			Err(error) => -nanos(error.duration()),
+		})
+	}
 }
 
+#[cfg(feature = "std")]
+impl_rule!(std::time::SystemTime);
+
 #[cfg(feature = "jiff")]
 mod jiff_ {
 	use super::*;
 
+	impl Instant for jiff::Timestamp {
+		fn unix_nanos(&self) -> Option<i128> {
+			Some(self.as_nanosecond())
+		}
+	}
+
+	impl Instant for jiff::Zoned {
+		fn unix_nanos(&self) -> Option<i128> {
+			self.timestamp().unix_nanos()
+		}
+	}
+
+	impl Instant for jiff::civil::DateTime {
+		fn unix_nanos(&self) -> Option<i128> {
+			self
+				.to_zoned(jiff::tz::TimeZone::UTC)
+				.ok()
+				.and_then(|zoned| zoned.unix_nanos())
+		}
+	}
+
+	impl Instant for jiff::civil::Date {
+		fn unix_nanos(&self) -> Option<i128> {
+			self.to_datetime(jiff::civil::Time::midnight()).unix_nanos()
+		}
+	}
+
+	impl Instant for jiff::civil::Time {
+		fn unix_nanos(&self) -> Option<i128> {
+			None
+		}
+	}
+
+	impl_rule!(jiff::Timestamp);
 	impl_rule!(jiff::Zoned);
 	impl_rule!(jiff::civil::DateTime);
 	impl_rule!(jiff::civil::Date);
@@ -208,66 +608,197 @@ mod chrono_ {
 
 	use super::*;
 
-	impl<Tz: chrono::TimeZone> crate::Rule<DateTime<Tz>> for TimeRule<DateTime<Tz>, DateTime<Tz>> {
-		type Context = ();
+	impl<Tz: chrono::TimeZone> Instant for DateTime<Tz> {
+		fn unix_nanos(&self) -> Option<i128> {
+			Some(i128::from(self.timestamp()) * 1_000_000_000 + i128::from(self.timestamp_subsec_nanos()))
+		}
+	}
 
-		#[inline]
-		fn validate(&self, _ctx: &Self::Context, item: &DateTime<Tz>) -> Result<()> {
-			if let Some(min) = &self.min {
-				match item.cmp(min) {
-					Ordering::Greater => {}
-					Ordering::Equal if !self.exclusive_min => {}
-					_ => return Err(Error::TooNew.into()),
-				}
-			}
+	impl Instant for chrono::NaiveDateTime {
+		fn unix_nanos(&self) -> Option<i128> {
+			self.and_utc().unix_nanos()
+		}
+	}
 
-			if let Some(max) = &self.max {
-				match item.cmp(max) {
-					Ordering::Less => {}
-					Ordering::Equal if !self.exclusive_max => {}
-					_ => return Err(Error::TooOld.into()),
-				}
-			}
+	impl Instant for chrono::NaiveDate {
+		fn unix_nanos(&self) -> Option<i128> {
+			self.and_time(chrono::NaiveTime::MIN).unix_nanos()
+		}
+	}
 
-			Ok(())
+	impl Instant for chrono::NaiveTime {
+		fn unix_nanos(&self) -> Option<i128> {
+			None
 		}
 	}
 
-	impl<Tz: chrono::TimeZone> crate::Rule<DateTime<Tz>> for TimeRule<DateTime<Tz>, Unset> {
-		type Context = ();
+	impl_rule!([Tz: chrono::TimeZone] DateTime<Tz>);
+	impl_rule!(chrono::NaiveDateTime);
+	impl_rule!(chrono::NaiveDate);
+	impl_rule!(chrono::NaiveTime);
+}
 
-		#[inline]
-		fn validate(&self, _ctx: &Self::Context, item: &DateTime<Tz>) -> Result<()> {
-			if let Some(min) = &self.min {
-				match item.cmp(min) {
-					Ordering::Greater => {}
-					Ordering::Equal if !self.exclusive_min => {}
-					_ => return Err(Error::TooNew.into()),
-				}
-			}
+#[cfg(feature = "time")]
+mod time_ {
+	use super::*;
 
-			Ok(())
+	impl Instant for ::time::OffsetDateTime {
+		fn unix_nanos(&self) -> Option<i128> {
+			Some(self.unix_timestamp_nanos())
 		}
 	}
 
-	impl<Tz: chrono::TimeZone> crate::Rule<DateTime<Tz>> for TimeRule<Unset, DateTime<Tz>> {
-		type Context = ();
+	impl Instant for ::time::PrimitiveDateTime {
+		fn unix_nanos(&self) -> Option<i128> {
+			self.assume_utc().unix_nanos()
+		}
+	}
 
-		#[inline]
-		fn validate(&self, _ctx: &Self::Context, item: &DateTime<Tz>) -> Result<()> {
-			if let Some(max) = &self.max {
-				match item.cmp(max) {
-					Ordering::Less => {}
-					Ordering::Equal if !self.exclusive_max => {}
-					_ => return Err(Error::TooOld.into()),
-				}
-			}
+	impl Instant for ::time::Date {
+		fn unix_nanos(&self) -> Option<i128> {
+			self.midnight().unix_nanos()
+		}
+	}
 
-			Ok(())
+	impl Instant for ::time::Time {
+		fn unix_nanos(&self) -> Option<i128> {
+			None
 		}
 	}
 
-	impl_rule!(chrono::NaiveDateTime);
-	impl_rule!(chrono::NaiveDate);
-	impl_rule!(chrono::NaiveTime);
+	impl_rule!(::time::OffsetDateTime);
+	impl_rule!(::time::PrimitiveDateTime);
+	impl_rule!(::time::Date);
+	impl_rule!(::time::Time);
+}
+
+#[cfg(test)]
+mod test {
+	use core::time::Duration;
+
+	use super::{Error, FixedClock, TimeRule};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_epoch_time_rule() {
+		let clock = || FixedClock(Duration::from_secs(1_000_000));
+
+		let rule = TimeRule::new().min(999_000_u64).past().clock(clock());
+
+		assert!(rule.validate(&(), &999_999_u64).is_ok());
+		assert_eq!(
+			rule.validate(&(), &1_000_001_u64),
+			Err(Error::NotPast.into())
+		);
+		assert_eq!(rule.validate(&(), &998_999_u64), Err(Error::TooNew.into()));
+
+		let rule = TimeRule::new()
+			.epoch_millis()
+			.not_older_than(Duration::from_secs(1))
+			.clock(clock());
+
+		assert!(rule.validate(&(), &999_999_000_i64).is_ok());
+		assert!(rule.validate(&(), &999_998_999_i64).is_err());
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_system_time_rule() {
+		let now = std::time::SystemTime::now();
+		let hour = Duration::from_secs(3600);
+
+		let rule = TimeRule::new().future().within(hour * 2);
+
+		assert!(rule.validate(&(), &(now + hour)).is_ok());
+		assert!(rule.validate(&(), &(now - hour)).is_err());
+		assert!(rule.validate(&(), &(now + hour * 3)).is_err());
+	}
+
+	#[cfg(feature = "time")]
+	#[test]
+	fn test_time_crate_rule() {
+		let clock = || FixedClock(Duration::from_secs(1_746_333_380));
+
+		let rule = TimeRule::new()
+			.min(time::macros::date!(2025 - 01 - 01))
+			.past()
+			.clock(clock());
+
+		assert!(rule
+			.validate(&(), &time::macros::date!(2025 - 05 - 03))
+			.is_ok());
+		assert_eq!(
+			rule.validate(&(), &time::macros::date!(2025 - 05 - 05)),
+			Err(Error::NotPast.into())
+		);
+		assert_eq!(
+			rule.validate(&(), &time::macros::date!(2024 - 12 - 31)),
+			Err(Error::TooNew.into())
+		);
+
+		let rule = TimeRule::new().future().clock(clock());
+
+		assert!(rule
+			.validate(&(), &time::macros::datetime!(2025-05-04 04:36:21 UTC))
+			.is_ok());
+		assert!(rule
+			.validate(&(), &time::macros::datetime!(2025-05-04 06:36:19 +02:00))
+			.is_err());
+	}
+
+	#[cfg(feature = "jiff")]
+	#[test]
+	fn test_relative_time_rule() {
+		let now = jiff::Timestamp::from_second(1_000_000).unwrap();
+		let clock = || FixedClock(Duration::from_secs(1_000_000));
+		let hour = jiff::SignedDuration::from_hours(1);
+
+		let rule = TimeRule::new().past().clock(clock());
+
+		assert!(rule.validate(&(), &(now - hour)).is_ok());
+		assert_eq!(rule.validate(&(), &now), Err(Error::NotPast.into()));
+
+		let rule = TimeRule::new()
+			.future()
+			.within(Duration::from_secs(7200))
+			.clock(clock());
+
+		assert!(rule.validate(&(), &(now + hour)).is_ok());
+		assert_eq!(
+			rule.validate(&(), &(now - hour)),
+			Err(Error::NotFuture.into())
+		);
+		assert_eq!(
+			rule.validate(&(), &(now + hour * 3)),
+			Err(
+				Error::OutsideWindow {
+					window: Duration::from_secs(7200)
+				}
+				.into()
+			)
+		);
+
+		let rule = TimeRule::new()
+			.not_older_than(Duration::from_secs(3600))
+			.clock(clock());
+
+		assert!(rule.validate(&(), &(now - hour)).is_ok());
+		assert!(rule.validate(&(), &(now + hour * 100)).is_ok());
+		assert_eq!(
+			rule.validate(&(), &(now - hour * 2)),
+			Err(
+				Error::Expired {
+					max_age: Duration::from_secs(3600)
+				}
+				.into()
+			)
+		);
+
+		let rule = TimeRule::new().future().clock(clock());
+
+		assert_eq!(
+			rule.validate(&(), &jiff::civil::time(12, 0, 0, 0)),
+			Err(Error::Unmeasurable.into())
+		);
+	}
 }
@@ -0,0 +1,107 @@
+//! Rule for IANA time zone validation.
+//!
+//! See [`TimeZoneRule`] for more information.
+
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = TimeZoneRule;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Error {
+	#[error("expected a valid time zone")]
+	InvalidTimeZone,
+}
+
+impl Error {
+	#[must_use]
+	pub(crate) fn code(&self) -> &'static str {
+		match self {
+			Self::InvalidTimeZone => "invalid_time_zone",
+		}
+	}
+
+	pub(crate) fn message(&self) -> &'static str {
+		match self {
+			Self::InvalidTimeZone => "expected a valid time zone",
+		}
+	}
+}
+
+/// Rule for IANA time zone name validation, such as `Europe/Berlin`.
+///
+/// Names are looked up in the time zone database bundled with `jiff`, and
+/// must match the name in the database exactly, including case.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Schedule {
+///   #[validate(timezone)]
+///   time_zone: String,
+/// }
+///
+/// let schedule = Schedule {
+///   time_zone: "America/New_York".into(),
+/// };
+///
+/// assert!(schedule.validate(&()).is_ok());
+///
+/// let schedule = Schedule {
+///   time_zone: "Mars/Olympus_Mons".into(),
+/// };
+///
+/// assert!(schedule.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct TimeZoneRule;
+
+impl TimeZoneRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for TimeZoneRule
+where
+	I: AsRef<str>,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let name = item.as_ref();
+
+		// the lookup ignores case, depending on the database
+		match jiff::tz::db().get(name) {
+			Ok(tz) if tz.iana_name() == Some(name) => Ok(()),
+			_ => Err(Error::InvalidTimeZone.into()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::TimeZoneRule;
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_timezone_rule() {
+		let rule = TimeZoneRule::new();
+
+		assert!(rule.validate(&(), "Europe/Berlin").is_ok());
+		assert!(rule.validate(&(), "UTC").is_ok());
+		assert!(rule.validate(&(), "America/Argentina/Buenos_Aires").is_ok());
+		assert!(rule.validate(&(), "US/Eastern").is_ok());
+
+		assert!(rule.validate(&(), "").is_err());
+		assert!(rule.validate(&(), "Europe/Atlantis").is_err());
+		assert!(rule.validate(&(), "europe/berlin").is_err());
+		assert!(rule.validate(&(), "+05:00").is_err());
+		assert!(rule.validate(&(), "../../etc/passwd").is_err());
+	}
+}
@@ -0,0 +1,242 @@
+//! Rule for uniqueness validation.
+//!
+//! See [`UniqueRule`] for more information.
+
+use crate::{error::Path, toolbox::rule::*};
+
+#[doc(hidden)]
+pub type Rule<By> = UniqueRule<By>;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Error {
+	#[error("duplicate of the item at index {first}")]
+	Duplicate { first: usize },
+}
+
+impl Error {
+	#[must_use]
+	pub(crate) fn code(&self) -> &'static str {
+		match self {
+			Self::Duplicate { .. } => "duplicate",
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub(crate) fn message(&self) -> Cow<'static, str> {
+		match self {
+			Self::Duplicate { first } => format!("duplicate of the item at index {first}"),
+		}
+		.into()
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	pub(crate) fn message(&self) -> &'static str {
+		match self {
+			Self::Duplicate { .. } => "item is a duplicate",
+		}
+	}
+}
+
+/// Rule for uniqueness validation.
+///
+/// Every item of the collection must be different from the items before it.
+/// Items are compared with [`PartialEq`], or by a key when `by` is set. When
+/// used with the derive macro, each duplicate is reported at the index of its
+/// later occurrence (e.g. `tags.3`).
+///
+/// Since items only need to implement [`PartialEq`], each item is compared
+/// with every item before it, so validation takes quadratic time in the length
+/// of the collection.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Item {
+///   id: u32,
+///   name: String,
+/// }
+///
+/// #[derive(Wary)]
+/// struct Cart {
+///   #[validate(unique)]
+///   tags: Vec<String>,
+///   #[validate(unique(by = |item| &item.id))]
+///   items: Vec<Item>,
+/// }
+///
+/// let cart = Cart {
+///   tags: vec!["gift".into(), "sale".into()],
+///   items: vec![
+///     Item { id: 1, name: "apple".into() },
+///     Item { id: 2, name: "apple".into() },
+///   ],
+/// };
+///
+/// assert!(cart.validate(&()).is_ok());
+///
+/// let cart = Cart {
+///   tags: vec!["gift".into(), "sale".into(), "gift".into()],
+///   items: vec![
+///     Item { id: 1, name: "apple".into() },
+///     Item { id: 1, name: "pear".into() },
+///   ],
+/// };
+///
+/// let report = cart.validate(&()).unwrap_err();
+///
+/// assert_eq!(report.len(), 2);
+/// ```
+#[must_use]
+pub struct UniqueRule<By> {
+	by: By,
+}
+
+impl UniqueRule<Unset> {
+	#[inline]
+	pub const fn new() -> Self {
+		Self { by: Unset }
+	}
+
+	/// Set the key that items are compared by.
+	#[inline]
+	pub fn by<By>(self, by: By) -> UniqueRule<By> {
+		UniqueRule { by }
+	}
+}
+
+/// Calls `f` with the index of each duplicate and the index of the item it
+/// duplicates, stopping early if `f` returns `false`.
+fn duplicates<T>(
+	items: &[T],
+	eq: impl Fn(&T, &T) -> bool,
+	mut f: impl FnMut(usize, usize) -> bool,
+) {
+	for (index, item) in items.iter().enumerate().skip(1) {
+		if let Some(first) = items[..index].iter().position(|other| eq(other, item)) {
+			if !f(index, first) {
+				return;
+			}
+		}
+	}
+}
+
+fn first_duplicate<T>(items: &[T], eq: impl Fn(&T, &T) -> bool) -> Result<()> {
+	let mut result = Ok(());
+
+	duplicates(items, eq, |_, first| {
+		result = Err(Error::Duplicate { first }.into());
+		false
+	});
+
+	result
+}
+
+fn report_duplicates<T>(
+	items: &[T],
+	eq: impl Fn(&T, &T) -> bool,
+	parent: &Path,
+	report: &mut Report,
+) {
+	duplicates(items, eq, |index, first| {
+		report.push(parent.append(index), Error::Duplicate { first }.into());
+		true
+	});
+}
+
+impl<I: ?Sized> crate::Rule<I> for UniqueRule<Unset>
+where
+	I: AsSlice,
+	I::Item: PartialEq,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		first_duplicate(item.as_slice(), PartialEq::eq)
+	}
+
+	fn validate_into(&self, _ctx: &Self::Context, item: &I, parent: &Path, report: &mut Report) {
+		report_duplicates(item.as_slice(), PartialEq::eq, parent, report);
+	}
+}
+
+impl<I: ?Sized, K: ?Sized, F> crate::Rule<I> for UniqueRule<F>
+where
+	I: AsSlice,
+	K: PartialEq,
+	F: Fn(&I::Item) -> &K,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		first_duplicate(item.as_slice(), |a, b| (self.by)(a) == (self.by)(b))
+	}
+
+	fn validate_into(&self, _ctx: &Self::Context, item: &I, parent: &Path, report: &mut Report) {
+		report_duplicates(
+			item.as_slice(),
+			|a, b| (self.by)(a) == (self.by)(b),
+			parent,
+			report,
+		);
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{Error, UniqueRule};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_unique_rule() {
+		let rule = UniqueRule::new();
+
+		assert!(rule.validate(&(), &[1, 2, 3]).is_ok());
+		assert!(rule.validate(&(), &Vec::<u8>::new()).is_ok());
+		assert!(matches!(
+			rule.validate(&(), &[1, 2, 1]),
+			Err(crate::Error::Unique(Error::Duplicate { first: 0 }))
+		));
+	}
+
+	#[test]
+	fn test_unique_rule_by() {
+		fn first(pair: &(u8, u8)) -> &u8 {
+			&pair.0
+		}
+
+		let rule = UniqueRule::new().by(first);
+
+		assert!(rule.validate(&(), &[(1, 1), (2, 1)]).is_ok());
+		assert!(rule.validate(&(), &[(1, 1), (1, 2)]).is_err());
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_unique_paths() {
+		#[derive(crate::Wary)]
+		#[wary(crate = "crate")]
+		struct Tags {
+			#[validate(unique)]
+			tags: Vec<&'static str>,
+			#[validate(unique(by = |pair| &pair.0))]
+			pairs: Vec<(u8, u8)>,
+		}
+
+		let tags = Tags {
+			tags: vec!["a", "b", "a", "c", "b"],
+			pairs: vec![(1, 1), (2, 2)],
+		};
+
+		let report = tags.validate(&()).unwrap_err();
+		let debug = format!("{report:?}");
+
+		assert_eq!(report.len(), 2);
+		assert!(debug.contains("(tags.2, Unique(Duplicate { first: 0 }))"));
+		assert!(debug.contains("(tags.4, Unique(Duplicate { first: 1 }))"));
+	}
+}
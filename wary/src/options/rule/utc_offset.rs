@@ -0,0 +1,331 @@
+//! Rule for UTC offset validation.
+//!
+//! See [`UtcOffsetRule`] for more information.
+
+use core::fmt;
+
+use crate::toolbox::rule::*;
+
+#[doc(hidden)]
+pub type Rule = UtcOffsetRule;
+
+const HOUR: i32 = 3600;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Error {
+	#[error("expected a valid UTC offset")]
+	Invalid,
+	#[error("expected a UTC offset between {} and {}", Hms(*min), Hms(*max))]
+	OutOfRange { min: i32, max: i32 },
+}
+
+impl Error {
+	#[must_use]
+	pub(crate) fn code(&self) -> &'static str {
+		match self {
+			Self::Invalid => "invalid_utc_offset",
+			Self::OutOfRange { .. } => "utc_offset_out_of_range",
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub(crate) fn message(&self) -> Cow<'static, str> {
+		match self {
+			Self::Invalid => "expected a valid UTC offset".into(),
+			Self::OutOfRange { min, max } => format!(
+				"expected a UTC offset between {} and {}",
+				Hms(*min),
+				Hms(*max)
+			)
+			.into(),
+		}
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	pub(crate) fn message(&self) -> &'static str {
+		match self {
+			Self::Invalid => "expected a valid UTC offset",
+			Self::OutOfRange { .. } => "UTC offset is out of range",
+		}
+	}
+}
+
+/// Formats an offset in seconds as `±HH:MM` (or `±HH:MM:SS`).
+struct Hms(i32);
+
+impl fmt::Display for Hms {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let sign = if self.0 < 0 { '-' } else { '+' };
+		let seconds = self.0.unsigned_abs();
+
+		write!(f, "{sign}{:02}:{:02}", seconds / 3600, seconds / 60 % 60)?;
+
+		if seconds % 60 != 0 {
+			write!(f, ":{:02}", seconds % 60)?;
+		}
+
+		Ok(())
+	}
+}
+
+/// A value holding an offset from UTC.
+pub trait UtcOffset {
+	/// Returns the offset in seconds, or `None` if the value is not a valid
+	/// offset.
+	fn utc_offset(&self) -> Option<i32>;
+}
+
+/// Parses `Z`, `±HH`, `±HHMM`, `±HH:MM` or `±HH:MM:SS`.
+impl UtcOffset for str {
+	fn utc_offset(&self) -> Option<i32> {
+		if self == "Z" {
+			return Some(0);
+		}
+
+		let (sign, rest) = match self.as_bytes().first()? {
+			b'+' => (1, &self[1..]),
+			b'-' => (-1, &self[1..]),
+			_ => return None,
+		};
+
+		// the checks below index by byte, which is only valid for ASCII
+		if !rest.is_ascii() {
+			return None;
+		}
+
+		let two_digits = |s: &str| -> Option<i32> {
+			let bytes = s.as_bytes();
+
+			(bytes.len() == 2 && bytes.iter().all(u8::is_ascii_digit))
+				.then(|| i32::from(bytes[0] - b'0') * 10 + i32::from(bytes[1] - b'0'))
+		};
+
+		let (hours, minutes, seconds) = match rest.len() {
+			2 => (two_digits(rest)?, 0, 0),
+			4 => (two_digits(&rest[..2])?, two_digits(&rest[2..])?, 0),
+			5 if rest.as_bytes()[2] == b':' => (two_digits(&rest[..2])?, two_digits(&rest[3..])?, 0),
+			8 if rest.as_bytes()[2] == b':' && rest.as_bytes()[5] == b':' => (
+				two_digits(&rest[..2])?,
+				two_digits(&rest[3..5])?,
+				two_digits(&rest[6..])?,
+			),
+			_ => return None,
+		};
+
+		if minutes >= 60 || seconds >= 60 {
+			return None;
+		}
+
+		Some(sign * (hours * HOUR + minutes * 60 + seconds))
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl UtcOffset for String {
+	fn utc_offset(&self) -> Option<i32> {
+		self.as_str().utc_offset()
+	}
+}
+
+impl<T: ?Sized> UtcOffset for &T
+where
+	T: UtcOffset,
+{
+	fn utc_offset(&self) -> Option<i32> {
+		(**self).utc_offset()
+	}
+}
+
+#[cfg(feature = "jiff")]
+impl UtcOffset for jiff::tz::Offset {
+	fn utc_offset(&self) -> Option<i32> {
+		Some(self.seconds())
+	}
+}
+
+#[cfg(feature = "chrono")]
+impl UtcOffset for chrono::FixedOffset {
+	fn utc_offset(&self) -> Option<i32> {
+		Some(self.local_minus_utc())
+	}
+}
+
+/// A bound of a [`UtcOffsetRule`]. Integers are whole hours.
+pub trait Bound {
+	fn seconds(self) -> i32;
+}
+
+/// Hours beyond what fits in seconds saturate, since no offset can reach them.
+impl Bound for i32 {
+	fn seconds(self) -> i32 {
+		self
+			.checked_mul(HOUR)
+			.unwrap_or(if self < 0 { i32::MIN } else { i32::MAX })
+	}
+}
+
+#[cfg(feature = "jiff")]
+impl Bound for jiff::tz::Offset {
+	fn seconds(self) -> i32 {
+		jiff::tz::Offset::seconds(self)
+	}
+}
+
+#[cfg(feature = "chrono")]
+impl Bound for chrono::FixedOffset {
+	fn seconds(self) -> i32 {
+		self.local_minus_utc()
+	}
+}
+
+/// Rule for UTC offset validation.
+///
+/// Accepts strings (`Z`, `+05:30`, `-0800`, `+09`), and `jiff::tz::Offset` or
+/// `chrono::FixedOffset` values with the respective features. By default,
+/// offsets must be between `-12:00` and `+14:00` (the offsets in use around the
+/// world), which can be changed with `min` and `max` (or range syntax) in whole
+/// hours or as offset values.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// struct Schedule {
+///   #[validate(utc_offset)]
+///   offset: String,
+///   #[validate(utc_offset(-5..=5))]
+///   nearby_offset: String,
+/// }
+///
+/// let schedule = Schedule {
+///   offset: "+05:45".into(),
+///   nearby_offset: "-04:00".into(),
+/// };
+///
+/// assert!(schedule.validate(&()).is_ok());
+///
+/// let schedule = Schedule {
+///   offset: "+15:00".into(),
+///   nearby_offset: "+09:00".into(),
+/// };
+///
+/// assert!(schedule.validate(&()).is_err());
+/// ```
+#[must_use]
+pub struct UtcOffsetRule {
+	min: i32,
+	max: i32,
+}
+
+impl UtcOffsetRule {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			min: -12 * HOUR,
+			max: 14 * HOUR,
+		}
+	}
+
+	/// Set the minimum offset (inclusive).
+	#[inline]
+	pub fn min(mut self, min: impl Bound) -> Self {
+		self.min = min.seconds();
+		self
+	}
+
+	/// Set the maximum offset (inclusive).
+	#[inline]
+	pub fn max(mut self, max: impl Bound) -> Self {
+		self.max = max.seconds();
+		self
+	}
+
+	/// Set the maximum offset (exclusive).
+	#[inline]
+	pub fn exclusive_max(mut self, max: impl Bound) -> Self {
+		self.max = max.seconds().saturating_sub(1);
+		self
+	}
+}
+
+impl<I: ?Sized> crate::Rule<I> for UtcOffsetRule
+where
+	I: UtcOffset,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let offset = item.utc_offset().ok_or(Error::Invalid)?;
+
+		if (self.min..=self.max).contains(&offset) {
+			Ok(())
+		} else {
+			Err(
+				Error::OutOfRange {
+					min: self.min,
+					max: self.max,
+				}
+				.into(),
+			)
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{Error, UtcOffset, UtcOffsetRule};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_parse_utc_offset() {
+		assert_eq!("Z".utc_offset(), Some(0));
+		assert_eq!("+09".utc_offset(), Some(9 * 3600));
+		assert_eq!("-0830".utc_offset(), Some(-(8 * 3600 + 30 * 60)));
+		assert_eq!("+05:45".utc_offset(), Some(5 * 3600 + 45 * 60));
+		assert_eq!("-00:00:30".utc_offset(), Some(-30));
+
+		for input in ["", "z", "05:00", "+5", "+05:60", "+05-00", "+0500:00"] {
+			assert_eq!(input.utc_offset(), None, "{input}");
+		}
+
+		// multibyte characters must not split the byte-indexed slices
+		for input in ["+1é1", "-0é", "+05:4é"] {
+			assert_eq!(input.utc_offset(), None, "{input}");
+		}
+	}
+
+	#[test]
+	fn test_utc_offset_rule() {
+		let rule = UtcOffsetRule::new();
+
+		assert!(rule.validate(&(), "-12:00").is_ok());
+		assert!(rule.validate(&(), "+14:00").is_ok());
+		assert_eq!(
+			rule.validate(&(), "+14:30"),
+			Err(
+				Error::OutOfRange {
+					min: -12 * 3600,
+					max: 14 * 3600
+				}
+				.into()
+			)
+		);
+		assert_eq!(rule.validate(&(), "EST"), Err(Error::Invalid.into()));
+
+		let rule = UtcOffsetRule::new().min(0).exclusive_max(2);
+
+		assert!(rule.validate(&(), "+01:59:59").is_ok());
+		assert!(rule.validate(&(), "+02:00").is_err());
+		assert!(rule.validate(&(), "-00:30").is_err());
+
+		let rule = UtcOffsetRule::new().min(i32::MIN).max(i32::MAX);
+
+		assert!(rule.validate(&(), "-99:59").is_ok());
+		assert!(rule.validate(&(), "+99:59").is_ok());
+		assert_eq!(rule.validate(&(), "+1é1"), Err(Error::Invalid.into()));
+	}
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod test {
+	use std::collections::{BTreeMap, HashMap};
+
+	use crate::toolbox::test::*;
+
+	#[derive(Wary)]
+	#[wary(crate = "crate")]
+	struct Header {
+		#[validate(ascii)]
+		value: &'static str,
+	}
+
+	#[test]
+	fn test_values_rule() {
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		struct Request {
+			#[validate(keys(ascii), values(dive))]
+			headers: BTreeMap<&'static str, Header>,
+			#[validate(values(inner(range(1..))))]
+			counts: HashMap<&'static str, Vec<u32>>,
+		}
+
+		let request = Request {
+			headers: BTreeMap::from([("accept", Header { value: "*/*" })]),
+			counts: HashMap::from([("a", vec![1, 2])]),
+		};
+
+		assert!(request.validate(&()).is_ok());
+
+		let request = Request {
+			headers: BTreeMap::from([
+				("accept", Header { value: "😃" }),
+				("😃", Header { value: "*/*" }),
+			]),
+			counts: HashMap::from([("a", vec![1, 0])]),
+		};
+
+		let report = request.validate(&()).unwrap_err();
+		let debug = format!("{report:?}");
+
+		assert_eq!(report.len(), 3);
+		assert!(debug.contains("(headers.accept.value, Ascii("));
+		assert!(debug.contains("(headers.😃.$key, Ascii("));
+		assert!(debug.contains("(counts.a.1, Range("));
+	}
+
+	#[cfg(feature = "indexmap")]
+	#[test]
+	fn test_values_rule_indexmap() {
+		use indexmap::IndexMap;
+
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		struct Metadata {
+			#[validate(values(length(max = 4)))]
+			entries: IndexMap<String, String>,
+		}
+
+		let mut entries = IndexMap::new();
+		entries.insert("a".to_string(), "short".to_string());
+
+		let report = Metadata { entries }.validate(&()).unwrap_err();
+
+		assert!(format!("{report:?}").contains("(entries.a, Length("));
+	}
+}
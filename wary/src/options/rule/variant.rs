@@ -0,0 +1,204 @@
+//! Rule for enum variant validation.
+//!
+//! See [`VariantRule`] for more information.
+
+use crate::{options::ItemSlice, toolbox::rule::*};
+
+#[doc(hidden)]
+pub type Rule<V> = VariantRule<V>;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Error {
+	#[error("variant is not allowed")]
+	NotAllowed { variant: ItemSlice },
+}
+
+impl Error {
+	#[must_use]
+	pub(crate) fn code(&self) -> &'static str {
+		match self {
+			Self::NotAllowed { .. } => "variant_not_allowed",
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub(crate) fn message(&self) -> Cow<'static, str> {
+		match self {
+			Self::NotAllowed { variant } => format!("variant {variant} is not allowed"),
+		}
+		.into()
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	pub(crate) fn message(&self) -> &'static str {
+		match self {
+			Self::NotAllowed { .. } => "variant is not allowed",
+		}
+	}
+}
+
+/// Rule for enum variant validation.
+///
+/// Checks the name of an enum variant against a list of allowed names. This
+/// is used by the derive macro for enums, and is not usually needed directly.
+///
+/// - `#[validate(variant(one_of = [Admin, Member]))]` on the enum allows only
+///   the listed variants (which must exist).
+/// - `#[validate(variant(one_of = ctx.roles))]` on the enum takes the names of
+///   the allowed variants from the context, as any slice of strings.
+/// - `#[validate(deny)]` on a variant always rejects it.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Validate};
+///
+/// #[derive(Wary)]
+/// #[validate(variant(one_of = [Admin, Member]))]
+/// enum Role {
+///   Admin,
+///   Member { team: u32 },
+///   Guest,
+///   #[validate(deny)]
+///   Owner,
+/// }
+///
+/// assert!(Role::Admin.validate(&()).is_ok());
+/// assert!(Role::Guest.validate(&()).is_err());
+///
+/// struct Context {
+///   roles: Vec<String>,
+/// }
+///
+/// #[derive(Wary)]
+/// #[wary(context = Context)]
+/// #[validate(variant(one_of = ctx.roles))]
+/// enum Plan {
+///   Free,
+///   Pro,
+/// }
+///
+/// let ctx = Context {
+///   roles: vec!["Pro".into()],
+/// };
+///
+/// assert!(Plan::Pro.validate(&ctx).is_ok());
+/// assert!(Plan::Free.validate(&ctx).is_err());
+/// ```
+#[must_use]
+pub struct VariantRule<V> {
+	allowed: V,
+}
+
+impl VariantRule<Unset> {
+	#[inline]
+	pub const fn new() -> Self {
+		Self { allowed: Unset }
+	}
+
+	/// Set the names of the allowed variants.
+	#[inline]
+	pub fn one_of<V>(self, allowed: V) -> VariantRule<V>
+	where
+		V: AsSlice,
+	{
+		VariantRule { allowed }
+	}
+
+	/// Reject every variant.
+	#[inline]
+	pub fn deny(self) -> VariantRule<[&'static str; 0]> {
+		VariantRule { allowed: [] }
+	}
+}
+
+impl<I: ?Sized, V> crate::Rule<I> for VariantRule<V>
+where
+	I: AsRef<str>,
+	V: AsSlice,
+	V::Item: AsRef<str>,
+{
+	type Context = ();
+
+	fn validate(&self, _ctx: &Self::Context, item: &I) -> Result<()> {
+		let item = item.as_ref();
+
+		if self
+			.allowed
+			.as_slice()
+			.iter()
+			.any(|allowed| allowed.as_ref() == item)
+		{
+			return Ok(());
+		}
+
+		#[cfg(feature = "alloc")]
+		let variant = item.to_string();
+		#[cfg(not(feature = "alloc"))]
+		let variant = ();
+
+		Err(Error::NotAllowed { variant }.into())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{Error, VariantRule};
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_variant_rule() {
+		let rule = VariantRule::new().one_of(["Admin", "Member"]);
+
+		assert!(rule.validate(&(), "Admin").is_ok());
+		assert_eq!(
+			rule.validate(&(), "Guest"),
+			Err(
+				Error::NotAllowed {
+					variant: "Guest".into()
+				}
+				.into()
+			)
+		);
+
+		let rule = VariantRule::new().deny();
+
+		assert!(rule.validate(&(), "Admin").is_err());
+	}
+
+	#[test]
+	fn test_variant_derive() {
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		#[validate(variant(one_of = [Admin, Member]))]
+		enum Role {
+			Admin,
+			Member(#[validate(range(1..))] u32),
+			Guest { name: &'static str },
+		}
+
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		enum Legacy {
+			Current,
+			#[validate(deny)]
+			Deprecated,
+		}
+
+		assert!(Role::Admin.validate(&()).is_ok());
+		assert!(Role::Member(1).validate(&()).is_ok());
+		assert_eq!(Role::Member(0).validate(&()).unwrap_err().len(), 1);
+		assert_eq!(
+			Role::Guest { name: "jane" }
+				.validate(&())
+				.unwrap_err()
+				.len(),
+			1
+		);
+
+		assert!(Legacy::Current.validate(&()).is_ok());
+		assert!(Legacy::Deprecated.validate(&()).is_err());
+	}
+}
@@ -28,4 +28,31 @@ mod test {
 
 		assert_eq!(name.item.name, "hello");
 	}
+
+	#[test]
+	fn test_dive_transformer_generic() {
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		struct Item {
+			#[transform(lowercase)]
+			name: String,
+		}
+
+		#[derive(Wary)]
+		#[wary(crate = "crate")]
+		struct Page<T> {
+			#[transform(inner(dive))]
+			items: Vec<T>,
+		}
+
+		let mut page = Page {
+			items: vec![Item {
+				name: "Hello".into(),
+			}],
+		};
+
+		page.transform(&());
+
+		assert_eq!(page.items[0].name, "hello");
+	}
 }
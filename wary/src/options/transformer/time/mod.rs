@@ -0,0 +1,149 @@
+//! Transformers for normalizing dates and times.
+//!
+//! Each transformer lives in its own module (re-exported at the top level of
+//! [`transformer`](crate::options::transformer)).
+//!
+//! - [`round`] (round to the nearest multiple of a unit)
+//! - [`to_utc`] (convert to UTC)
+//! - [`truncate`] (drop precision below a unit)
+
+pub mod round;
+pub mod to_utc;
+pub mod truncate;
+
+pub use crate::options::rule::calendar::TimeOfDay;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+const NANOS_PER_MINUTE: u64 = 60 * NANOS_PER_SECOND;
+const NANOS_PER_HOUR: u64 = 60 * NANOS_PER_MINUTE;
+const NANOS_PER_DAY: u64 = 24 * NANOS_PER_HOUR;
+
+/// A time value that can be moved forwards or backwards.
+///
+/// Values move by local (wall-clock) time, so that moving to midnight lands on
+/// midnight even across a daylight saving time transition. Values with a time
+/// zone are then resolved in it again, keeping their offset if it is still
+/// valid. Times of day wrap around midnight.
+pub trait Shift: TimeOfDay + Sized {
+	/// Returns the value moved by a number of nanoseconds of local time, or
+	/// `None` if it would be out of range.
+	fn shift(&self, nanos: i64) -> Option<Self>;
+}
+
+/// Returns the (possibly negative) nanoseconds between `time` and `target`.
+fn delta(time: u64, target: u64) -> i64 {
+	i64::try_from(target).unwrap_or(i64::MAX) - i64::try_from(time).unwrap_or(i64::MAX)
+}
+
+#[cfg(feature = "jiff")]
+mod jiff_ {
+	use jiff::SignedDuration;
+
+	use super::*;
+
+	impl Shift for jiff::Timestamp {
+		fn shift(&self, nanos: i64) -> Option<Self> {
+			self.checked_add(SignedDuration::from_nanos(nanos)).ok()
+		}
+	}
+
+	impl Shift for jiff::Zoned {
+		fn shift(&self, nanos: i64) -> Option<Self> {
+			let datetime = self
+				.datetime()
+				.checked_add(SignedDuration::from_nanos(nanos))
+				.ok()?;
+
+			// the builder prefers the current offset, and otherwise resolves
+			// gaps and folds like `DateTime::to_zoned`
+			self
+				.with()
+				.date(datetime.date())
+				.time(datetime.time())
+				.build()
+				.ok()
+		}
+	}
+
+	impl Shift for jiff::civil::DateTime {
+		fn shift(&self, nanos: i64) -> Option<Self> {
+			self.checked_add(SignedDuration::from_nanos(nanos)).ok()
+		}
+	}
+
+	impl Shift for jiff::civil::Time {
+		fn shift(&self, nanos: i64) -> Option<Self> {
+			Some(self.wrapping_add(SignedDuration::from_nanos(nanos)))
+		}
+	}
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_ {
+	use chrono::{LocalResult, Offset, TimeDelta};
+
+	use super::*;
+
+	impl<Tz: chrono::TimeZone> Shift for chrono::DateTime<Tz> {
+		fn shift(&self, nanos: i64) -> Option<Self> {
+			let local = self
+				.naive_local()
+				.checked_add_signed(TimeDelta::nanoseconds(nanos))?;
+			let offset = self.offset().fix();
+
+			match self.timezone().from_local_datetime(&local) {
+				LocalResult::Single(datetime) => Some(datetime),
+				LocalResult::Ambiguous(earliest, latest) => {
+					if latest.offset().fix() == offset {
+						Some(latest)
+					} else {
+						Some(earliest)
+					}
+				}
+				// skipped by a transition, so keep the current offset
+				LocalResult::None => {
+					let utc = local.checked_sub_offset(offset)?;
+
+					Some(self.timezone().from_utc_datetime(&utc))
+				}
+			}
+		}
+	}
+
+	impl Shift for chrono::NaiveDateTime {
+		fn shift(&self, nanos: i64) -> Option<Self> {
+			self.checked_add_signed(TimeDelta::nanoseconds(nanos))
+		}
+	}
+
+	impl Shift for chrono::NaiveTime {
+		fn shift(&self, nanos: i64) -> Option<Self> {
+			Some(self.overflowing_add_signed(TimeDelta::nanoseconds(nanos)).0)
+		}
+	}
+}
+
+#[cfg(feature = "time")]
+mod time_ {
+	use ::time::Duration;
+
+	use super::*;
+
+	impl Shift for ::time::OffsetDateTime {
+		fn shift(&self, nanos: i64) -> Option<Self> {
+			self.checked_add(Duration::nanoseconds(nanos))
+		}
+	}
+
+	impl Shift for ::time::PrimitiveDateTime {
+		fn shift(&self, nanos: i64) -> Option<Self> {
+			self.checked_add(Duration::nanoseconds(nanos))
+		}
+	}
+
+	impl Shift for ::time::Time {
+		fn shift(&self, nanos: i64) -> Option<Self> {
+			Some(*self + Duration::nanoseconds(nanos))
+		}
+	}
+}
@@ -0,0 +1,156 @@
+//! Transformer for rounding a time.
+//!
+//! See [`RoundTransformer`] for more information.
+
+use super::{delta, Shift, NANOS_PER_HOUR, NANOS_PER_MINUTE, NANOS_PER_SECOND};
+
+#[doc(hidden)]
+pub type Transformer = RoundTransformer;
+
+/// Transformer for rounding a time to the nearest multiple of a unit of local
+/// time, such as a quarter of an hour. Halfway values are rounded up.
+///
+/// Works with any [`Shift`], such as the date and time types of `jiff`,
+/// `chrono` and `time`. Multiples are counted from midnight, so units should
+/// divide a day evenly.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Transform};
+///
+/// #[derive(Wary)]
+/// struct Appointment {
+///   #[transform(round(minutes = 15))]
+///   starts_at: jiff::civil::DateTime,
+/// }
+///
+/// let mut appointment = Appointment {
+///   starts_at: jiff::civil::datetime(2025, 5, 4, 23, 53, 0, 0),
+/// };
+///
+/// appointment.transform(&());
+/// assert_eq!(
+///   appointment.starts_at,
+///   jiff::civil::datetime(2025, 5, 5, 0, 0, 0, 0)
+/// );
+/// ```
+#[must_use]
+pub struct RoundTransformer {
+	unit: u64,
+}
+
+/// Units too long to count in nanoseconds saturate, which rounds down to
+/// midnight like any unit longer than a day.
+const fn saturating_nanos(count: u64, unit: u64) -> u64 {
+	match count.checked_mul(unit) {
+		Some(nanos) => nanos,
+		None => u64::MAX,
+	}
+}
+
+impl RoundTransformer {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			unit: NANOS_PER_SECOND,
+		}
+	}
+
+	/// Round to a multiple of seconds.
+	#[inline]
+	pub const fn seconds(mut self, seconds: u64) -> Self {
+		self.unit = saturating_nanos(seconds, NANOS_PER_SECOND);
+		self
+	}
+
+	/// Round to a multiple of minutes.
+	#[inline]
+	pub const fn minutes(mut self, minutes: u64) -> Self {
+		self.unit = saturating_nanos(minutes, NANOS_PER_MINUTE);
+		self
+	}
+
+	/// Round to a multiple of hours.
+	#[inline]
+	pub const fn hours(mut self, hours: u64) -> Self {
+		self.unit = saturating_nanos(hours, NANOS_PER_HOUR);
+		self
+	}
+}
+
+impl<I> crate::Transformer<I> for RoundTransformer
+where
+	I: Shift,
+{
+	type Context = ();
+
+	#[inline]
+	fn transform(&self, _ctx: &Self::Context, item: &mut I) {
+		if self.unit == 0 {
+			return;
+		}
+
+		let time = item.nanos_since_midnight();
+		let remainder = time % self.unit;
+		let rounded = if remainder * 2 >= self.unit {
+			time - remainder + self.unit
+		} else {
+			time - remainder
+		};
+
+		if let Some(shifted) = item.shift(delta(time, rounded)) {
+			*item = shifted;
+		}
+	}
+}
+
+#[cfg(all(test, feature = "jiff"))]
+mod test {
+	use super::RoundTransformer;
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_round_transformer() {
+		let rule = RoundTransformer::new().minutes(15);
+		let mut input = jiff::civil::time(9, 7, 29, 0);
+
+		rule.transform(&(), &mut input);
+		assert_eq!(input, jiff::civil::time(9, 0, 0, 0));
+
+		let mut input = jiff::civil::time(9, 7, 30, 0);
+
+		rule.transform(&(), &mut input);
+		assert_eq!(input, jiff::civil::time(9, 15, 0, 0));
+
+		let mut input = jiff::civil::time(23, 55, 0, 0);
+
+		rule.transform(&(), &mut input);
+		assert_eq!(input, jiff::civil::time(0, 0, 0, 0));
+
+		let mut input = jiff::civil::time(9, 7, 30, 0);
+
+		RoundTransformer::new()
+			.minutes(u64::MAX)
+			.transform(&(), &mut input);
+		assert_eq!(input, jiff::civil::time(0, 0, 0, 0));
+	}
+
+	#[test]
+	fn test_round_transformer_dst() {
+		let zoned = |s: &str| s.parse::<jiff::Zoned>().unwrap();
+		let rule = RoundTransformer::new().hours(1);
+
+		// 01:45 EST rounds to 02:00, which is skipped and becomes 03:00 EDT
+		let mut input = zoned("2025-03-09T01:45[America/New_York]");
+
+		rule.transform(&(), &mut input);
+		assert_eq!(input, zoned("2025-03-09T03:00-04:00[America/New_York]"));
+
+		// the second 01:20 (EST) stays in EST
+		let mut input = zoned("2025-11-02T01:20-05:00[America/New_York]");
+
+		rule.transform(&(), &mut input);
+		assert_eq!(input, zoned("2025-11-02T01:00-05:00[America/New_York]"));
+	}
+}
@@ -0,0 +1,111 @@
+//! Transformer for converting a time to UTC.
+//!
+//! See [`ToUtcTransformer`] for more information.
+
+#[doc(hidden)]
+pub type Transformer = ToUtcTransformer;
+
+/// A time value with a time zone or offset that can be converted to UTC.
+pub trait ToUtc {
+	/// Converts the value to the same instant in UTC.
+	fn to_utc(&mut self);
+}
+
+#[cfg(feature = "jiff")]
+impl ToUtc for jiff::Zoned {
+	fn to_utc(&mut self) {
+		*self = self.with_time_zone(jiff::tz::TimeZone::UTC);
+	}
+}
+
+/// Already in UTC, so this does nothing. It lets fields use the same
+/// transformer whether they hold an offset or not.
+#[cfg(feature = "chrono")]
+impl ToUtc for chrono::DateTime<chrono::Utc> {
+	fn to_utc(&mut self) {}
+}
+
+#[cfg(feature = "chrono")]
+impl ToUtc for chrono::DateTime<chrono::FixedOffset> {
+	fn to_utc(&mut self) {
+		*self = self.with_timezone(&chrono::Offset::fix(&chrono::Utc));
+	}
+}
+
+#[cfg(feature = "time")]
+impl ToUtc for ::time::OffsetDateTime {
+	fn to_utc(&mut self) {
+		*self = self.to_offset(::time::UtcOffset::UTC);
+	}
+}
+
+/// Transformer for converting a time to UTC, keeping the same instant.
+///
+/// Works with any [`ToUtc`]: `jiff::Zoned`, `chrono::DateTime<FixedOffset>`,
+/// `chrono::DateTime<Utc>` and `time::OffsetDateTime` with the respective
+/// features.
+///
+/// The value is converted in place, so its type must be able to hold UTC.
+/// Other `chrono` time zones, such as `chrono::DateTime<Local>`, are not
+/// supported since their offset is set by the zone; convert those to
+/// `DateTime<Utc>` with [`with_timezone`](chrono::DateTime::with_timezone)
+/// instead.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Transform};
+///
+/// #[derive(Wary)]
+/// struct Event {
+///   #[transform(to_utc)]
+///   starts_at: jiff::Zoned,
+/// }
+///
+/// let mut event = Event {
+///   starts_at: "2025-05-04T06:36:20+02:00[Europe/Berlin]".parse().unwrap(),
+/// };
+///
+/// event.transform(&());
+/// assert_eq!(event.starts_at.to_string(), "2025-05-04T04:36:20+00:00[UTC]");
+/// ```
+#[must_use]
+pub struct ToUtcTransformer;
+
+impl ToUtcTransformer {
+	#[inline]
+	pub const fn new() -> Self {
+		Self
+	}
+}
+
+impl<I: ?Sized> crate::Transformer<I> for ToUtcTransformer
+where
+	I: ToUtc,
+{
+	type Context = ();
+
+	#[inline]
+	fn transform(&self, _ctx: &Self::Context, item: &mut I) {
+		item.to_utc();
+	}
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+	use super::ToUtcTransformer;
+	use crate::toolbox::test::*;
+
+	#[test]
+	fn test_to_utc_transformer() {
+		let mut input = chrono::DateTime::parse_from_rfc3339("2025-05-04T06:36:20+02:00").unwrap();
+
+		ToUtcTransformer::new().transform(&(), &mut input);
+		assert_eq!(input.to_rfc3339(), "2025-05-04T04:36:20+00:00");
+
+		let mut input = input.to_utc();
+
+		ToUtcTransformer::new().transform(&(), &mut input);
+		assert_eq!(input.to_rfc3339(), "2025-05-04T04:36:20+00:00");
+	}
+}
@@ -0,0 +1,139 @@
+//! Transformer for truncating the precision of a time.
+//!
+//! See [`TruncateTransformer`] for more information.
+
+use super::{delta, Shift, NANOS_PER_DAY, NANOS_PER_HOUR, NANOS_PER_MINUTE, NANOS_PER_SECOND};
+
+#[doc(hidden)]
+pub type Transformer = TruncateTransformer;
+
+/// Transformer for truncating the precision of a time, such as dropping the
+/// fraction of a second.
+///
+/// Works with any [`Shift`], such as the date and time types of `jiff`,
+/// `chrono` and `time`. Truncates to whole `seconds` (the default),
+/// `minutes`, `hours` or `days` of local time.
+///
+/// # Example
+///
+/// ```
+/// use wary::{Wary, Transform};
+///
+/// #[derive(Wary)]
+/// struct Event {
+///   #[transform(truncate(minutes))]
+///   starts_at: jiff::civil::DateTime,
+/// }
+///
+/// let mut event = Event {
+///   starts_at: jiff::civil::datetime(2025, 5, 4, 4, 36, 20, 500),
+/// };
+///
+/// event.transform(&());
+/// assert_eq!(event.starts_at, jiff::civil::datetime(2025, 5, 4, 4, 36, 0, 0));
+/// ```
+#[must_use]
+pub struct TruncateTransformer {
+	unit: u64,
+}
+
+impl TruncateTransformer {
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			unit: NANOS_PER_SECOND,
+		}
+	}
+
+	/// Truncate to whole seconds.
+	#[inline]
+	pub const fn seconds(mut self) -> Self {
+		self.unit = NANOS_PER_SECOND;
+		self
+	}
+
+	/// Truncate to whole minutes.
+	#[inline]
+	pub const fn minutes(mut self) -> Self {
+		self.unit = NANOS_PER_MINUTE;
+		self
+	}
+
+	/// Truncate to whole hours.
+	#[inline]
+	pub const fn hours(mut self) -> Self {
+		self.unit = NANOS_PER_HOUR;
+		self
+	}
+
+	/// Truncate to midnight.
+	#[inline]
+	pub const fn days(mut self) -> Self {
+		self.unit = NANOS_PER_DAY;
+		self
+	}
+}
+
+impl<I> crate::Transformer<I> for TruncateTransformer
+where
+	I: Shift,
+{
+	type Context = ();
+
+	#[inline]
+	fn transform(&self, _ctx: &Self::Context, item: &mut I) {
+		let time = item.nanos_since_midnight();
+
+		if let Some(truncated) = item.shift(delta(time, time - time % self.unit)) {
+			*item = truncated;
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::TruncateTransformer;
+	use crate::toolbox::test::*;
+
+	#[cfg(feature = "chrono")]
+	#[test]
+	fn test_truncate_transformer() {
+		let datetime = |hour, minute, second, nano| {
+			chrono::NaiveDate::from_ymd_opt(2025, 5, 4)
+				.unwrap()
+				.and_hms_nano_opt(hour, minute, second, nano)
+				.unwrap()
+		};
+
+		let mut input = datetime(4, 36, 20, 999_999_999);
+
+		TruncateTransformer::new().transform(&(), &mut input);
+		assert_eq!(input, datetime(4, 36, 20, 0));
+
+		TruncateTransformer::new()
+			.hours()
+			.transform(&(), &mut input);
+		assert_eq!(input, datetime(4, 0, 0, 0));
+
+		TruncateTransformer::new().days().transform(&(), &mut input);
+		assert_eq!(input, datetime(0, 0, 0, 0));
+	}
+
+	#[cfg(feature = "jiff")]
+	#[test]
+	fn test_truncate_transformer_dst() {
+		let zoned = |s: &str| s.parse::<jiff::Zoned>().unwrap();
+
+		// 05:30 EDT is 5.5 hours of wall-clock time, but only 4.5 elapsed hours,
+		// after midnight
+		let mut input = zoned("2025-03-09T05:30[America/New_York]");
+
+		TruncateTransformer::new().days().transform(&(), &mut input);
+		assert_eq!(input, zoned("2025-03-09T00:00-05:00[America/New_York]"));
+
+		let mut input = zoned("2025-11-02T23:10[America/New_York]");
+
+		TruncateTransformer::new().days().transform(&(), &mut input);
+		assert_eq!(input, zoned("2025-11-02T00:00-04:00[America/New_York]"));
+	}
+}
@@ -1,3 +1,6 @@
+// the darling derives below expand to a `continue` that newer clippy flags
+#![allow(clippy::needless_continue)]
+
 use darling::{ast, FromDeriveInput, FromField};
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -1,3 +1,5 @@
+use crate::util::Args;
+
 fn ungroup(mut ty: &syn::Type) -> &syn::Type {
 	while let syn::Type::Group(group) = ty {
 		ty = &group.elem;
@@ -37,3 +39,264 @@ pub fn extract_option_path(ty: &syn::Type) -> Option<syn::Path> {
 		None
 	}
 }
+
+/// Parses a duration literal such as `5m` or `1h30m` into seconds and
+/// nanoseconds.
+pub fn parse_duration(literal: &str) -> Result<(u64, u32), String> {
+	const UNITS: [(&str, u128); 10] = [
+		("ns", 1),
+		("us", 1_000),
+		("µs", 1_000),
+		("ms", 1_000_000),
+		("s", 1_000_000_000),
+		("m", 60 * 1_000_000_000),
+		("h", 3600 * 1_000_000_000),
+		("d", 86_400 * 1_000_000_000),
+		("w", 7 * 86_400 * 1_000_000_000),
+		("y", 365 * 86_400 * 1_000_000_000),
+	];
+
+	let mut rest = literal.trim();
+	let mut total = 0u128;
+
+	if rest.is_empty() {
+		return Err(format!(
+			"expected a duration such as \"5m\", found {literal:?}"
+		));
+	}
+
+	while !rest.is_empty() {
+		let digits = rest
+			.find(|c: char| !c.is_ascii_digit())
+			.unwrap_or(rest.len());
+		let unit_len = rest[digits..]
+			.find(|c: char| c.is_ascii_digit())
+			.unwrap_or(rest.len() - digits);
+
+		let (number, unit) = (&rest[..digits], rest[digits..digits + unit_len].trim());
+
+		let Some((_, size)) = UNITS.iter().find(|(name, _)| *name == unit) else {
+			return Err(format!(
+				"unknown duration unit {unit:?} in {literal:?}, expected one of ns, us, ms, s, m, h, d, w \
+				 or y"
+			));
+		};
+
+		let number = number
+			.parse::<u128>()
+			.map_err(|_| format!("expected a number before {unit:?} in {literal:?}"))?;
+
+		total = number
+			.checked_mul(*size)
+			.and_then(|nanos| total.checked_add(nanos))
+			.ok_or_else(|| format!("duration {literal:?} is too long"))?;
+
+		rest = rest[digits + unit_len..].trim_start();
+	}
+
+	let secs = u64::try_from(total / 1_000_000_000)
+		.map_err(|_| format!("duration {literal:?} is too long"))?;
+
+	#[allow(clippy::cast_possible_truncation)]
+	Ok((secs, (total % 1_000_000_000) as u32))
+}
+
+/// Prefixes bare weekday identifiers (alone or in an array) with `prefix`, so
+/// that `not = [Sat, Sunday]` becomes `not = [prefix::Sat, prefix::Sun]`.
+/// Other identifiers, such as constants, are left as they are.
+pub fn qualify_idents(args: &mut Args, keys: &[&str], prefix: &syn::Path) {
+	const WEEKDAYS: [(&str, &str); 7] = [
+		("Mon", "Monday"),
+		("Tue", "Tuesday"),
+		("Wed", "Wednesday"),
+		("Thu", "Thursday"),
+		("Fri", "Friday"),
+		("Sat", "Saturday"),
+		("Sun", "Sunday"),
+	];
+
+	let qualify = |expr: &mut syn::Expr| {
+		if let syn::Expr::Path(path) = expr {
+			if let Some(ident) = path.path.get_ident() {
+				if let Some((short, _)) = WEEKDAYS
+					.iter()
+					.find(|(short, long)| ident == short || ident == long)
+				{
+					let variant = syn::Ident::new(short, ident.span());
+
+					*expr = syn::parse_quote! { #prefix::#variant };
+				}
+			}
+		}
+	};
+
+	for key in keys {
+		let key = syn::Ident::new(key, proc_macro2::Span::call_site()).into();
+
+		if let Some(Some(expr)) = args.get(&key) {
+			let mut expr = expr.clone();
+
+			if let syn::Expr::Array(array) = &mut expr {
+				array.elems.iter_mut().for_each(qualify);
+			} else {
+				qualify(&mut expr);
+			}
+
+			args.insert(key, Some(expr));
+		}
+	}
+}
+
+/// Replaces the string literal values of the given keys with
+/// [`core::time::Duration`] expressions.
+pub fn expand_durations(args: &mut Args, keys: &[&str]) -> syn::Result<()> {
+	for key in keys {
+		let key = syn::Ident::new(key, proc_macro2::Span::call_site()).into();
+
+		if let Some(Some(expr)) = args.get(&key) {
+			if let Some(literal) = extract_str(expr) {
+				let (secs, nanos) =
+					parse_duration(&literal).map_err(|e| syn::Error::new_spanned(expr, e))?;
+
+				args.insert(
+					key,
+					Some(syn::parse_quote! { ::core::time::Duration::new(#secs, #nanos) }),
+				);
+			}
+		}
+	}
+
+	Ok(())
+}
+
+/// Parses the leading digits of `bytes` at `range`.
+fn digits(bytes: &[u8], range: core::ops::Range<usize>) -> Option<u32> {
+	bytes.get(range)?.iter().try_fold(0, |acc, digit| {
+		digit
+			.is_ascii_digit()
+			.then(|| acc * 10 + u32::from(digit - b'0'))
+	})
+}
+
+/// Checks that a `datetime` bound literal is an RFC 3339 date and time, or an
+/// ISO 8601 date.
+pub fn parse_datetime(literal: &str) -> Result<(), String> {
+	let error = || {
+		format!(
+			"expected an RFC 3339 date and time or an ISO 8601 date such as \"2025-05-04\", found \
+			 {literal:?}"
+		)
+	};
+	let bytes = literal.as_bytes();
+	let at = |index: usize, allowed: &[u8]| bytes.get(index).is_some_and(|b| allowed.contains(b));
+
+	let (Some(year), Some(month), Some(day)) = (
+		digits(bytes, 0..4),
+		digits(bytes, 5..7),
+		digits(bytes, 8..10),
+	) else {
+		return Err(error());
+	};
+
+	let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+	let days = match month {
+		2 if leap => 29,
+		2 => 28,
+		4 | 6 | 9 | 11 => 30,
+		_ => 31,
+	};
+
+	if !at(4, b"-") || !at(7, b"-") || !(1..=12).contains(&month) || !(1..=days).contains(&day) {
+		return Err(error());
+	}
+
+	if bytes.len() == 10 {
+		return Ok(());
+	}
+
+	let (Some(hour), Some(minute), Some(second)) = (
+		digits(bytes, 11..13),
+		digits(bytes, 14..16),
+		digits(bytes, 17..19),
+	) else {
+		return Err(error());
+	};
+
+	if !at(10, b"Tt ") || !at(13, b":") || !at(16, b":") || hour > 23 || minute > 59 || second > 60 {
+		return Err(error());
+	}
+
+	let mut rest = &bytes[19..];
+
+	if let Some(fraction) = rest.strip_prefix(b".") {
+		let len = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+
+		if !(1..=9).contains(&len) {
+			return Err(error());
+		}
+
+		rest = &fraction[len..];
+	}
+
+	let valid = match rest {
+		[b'Z' | b'z'] => true,
+		[b'+' | b'-', _, _, b':', _, _] => {
+			digits(rest, 1..3).is_some_and(|hours| hours <= 23)
+				&& digits(rest, 4..6).is_some_and(|minutes| minutes <= 59)
+		}
+		_ => false,
+	};
+
+	if valid {
+		Ok(())
+	} else {
+		Err(error())
+	}
+}
+
+/// Checks that a `time_of_day` bound literal is `HH:MM` or `HH:MM:SS`.
+pub fn parse_time_of_day(literal: &str) -> Result<(), String> {
+	let bytes = literal.as_bytes();
+	let hour = digits(bytes, 0..2).filter(|hour| *hour <= 23);
+	let minute = digits(bytes, 3..5).filter(|minute| *minute <= 59);
+	let second = match bytes.len() {
+		5 => Some(0),
+		8 if bytes[5] == b':' => digits(bytes, 6..8).filter(|second| *second <= 59),
+		_ => None,
+	};
+
+	if bytes.get(2) == Some(&b':') && hour.is_some() && minute.is_some() && second.is_some() {
+		Ok(())
+	} else {
+		Err(format!(
+			"expected a time of day such as \"09:00\" or \"09:00:30\", found {literal:?}"
+		))
+	}
+}
+
+/// Checks the string literal values of the given keys (alone or in a tuple)
+/// with `parse`, so that invalid bounds fail at compile time.
+pub fn check_literals(
+	args: &Args,
+	keys: &[&str],
+	parse: fn(&str) -> Result<(), String>,
+) -> syn::Result<()> {
+	let check = |expr: &syn::Expr| match extract_str(expr) {
+		Some(literal) => parse(&literal).map_err(|e| syn::Error::new_spanned(expr, e)),
+		None => Ok(()),
+	};
+
+	for key in keys {
+		let key = syn::Ident::new(key, proc_macro2::Span::call_site()).into();
+
+		if let Some(Some(expr)) = args.get(&key) {
+			if let syn::Expr::Tuple(tuple) = expr {
+				tuple.elems.iter().try_for_each(check)?;
+			} else {
+				check(expr)?;
+			}
+		}
+	}
+
+	Ok(())
+}
@@ -3,7 +3,7 @@ use std::{collections::HashSet, ops};
 use darling::{ast, FromMeta};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
-use syn::punctuated::Punctuated;
+use syn::{ext::IdentExt, punctuated::Punctuated};
 
 use crate::wary::{emit::serde, transform::TransformFieldWrapper, validate::ValidateFieldWrapper};
 
@@ -77,6 +77,15 @@ impl<K, V> VecMap<K, V> {
 			.iter_mut()
 			.find_map(|(k, v)| if k == key { Some(v) } else { None })
 	}
+
+	pub fn remove(&mut self, key: &K) -> Option<V>
+	where
+		K: PartialEq,
+	{
+		let index = self.inner.iter().position(|(k, _)| k == key)?;
+
+		Some(self.inner.remove(index).1)
+	}
 }
 
 impl<V: FromMeta> FromMeta for VecMap<syn::Path, V> {
@@ -140,6 +149,46 @@ impl Args {
 	pub fn get(&self, key: &syn::Path) -> Option<&Option<syn::Expr>> {
 		self.0.get(key)
 	}
+
+	/// Removes the `code` and `message` arguments, which apply to the errors
+	/// of the rule rather than the rule itself.
+	pub fn take_overrides(&mut self) -> Overrides {
+		Overrides {
+			code: self.0.remove(&syn::parse_quote! { code }).flatten(),
+			message: self.0.remove(&syn::parse_quote! { message }).flatten(),
+		}
+	}
+}
+
+/// The `code` and `message` arguments of a rule.
+///
+/// Emits the calls that replace the code and message of an error.
+#[derive(Debug, Default)]
+pub struct Overrides {
+	code: Option<syn::Expr>,
+	message: Option<syn::Expr>,
+}
+
+impl Overrides {
+	pub fn is_empty(&self) -> bool {
+		self.code.is_none() && self.message.is_none()
+	}
+}
+
+impl ToTokens for Overrides {
+	fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+		if let Some(code) = &self.code {
+			tokens.extend(quote! {
+				.override_code(#code)
+			});
+		}
+
+		if let Some(message) = &self.message {
+			tokens.extend(quote! {
+				.override_message(#message)
+			});
+		}
+	}
 }
 
 impl<'d> IntoIterator for &'d Args {
@@ -192,6 +241,7 @@ enum Arg {
 	Name(syn::Path),
 	NameValue(syn::MetaNameValue),
 	Range(syn::ExprRange),
+	Value(syn::Expr),
 }
 
 impl syn::parse::Parse for Arg {
@@ -222,20 +272,21 @@ impl syn::parse::Parse for Arg {
 			});
 		}
 
-		if let Ok(range) = input.parse::<syn::ExprRange>() {
-			// otherwise, check if it's range syntax and expand it into a few different
-			// arguments l..h -> min = l, exclusive_max = h
-			// l..=h -> min = l, max = h
-			// ..h -> exclusive_max = h
-			// ..=h -> max = h
-			//
-			// unfortuntely cannot support exclusive min with this syntax, but it could be
-			// provided manually with a simple ..=h and exclusive_min=...
-
-			return Ok(Self::Range(range));
+		// otherwise, check if it's range syntax and expand it into a few different
+		// arguments l..h -> min = l, exclusive_max = h
+		// l..=h -> min = l, max = h
+		// ..h -> exclusive_max = h
+		// ..=h -> max = h
+		//
+		// unfortuntely cannot support exclusive min with this syntax, but it could be
+		// provided manually with a simple ..=h and exclusive_min=...
+		//
+		// any other expression is a positional value, passed to `.value(...)`
+		match input.parse::<syn::Expr>() {
+			Ok(syn::Expr::Range(range)) => Ok(Self::Range(range)),
+			Ok(expr) => Ok(Self::Value(expr)),
+			Err(_) => Err(input.error("expected `=`, range syntax or a value")),
 		}
-
-		Err(input.error("expected `=` or range syntax"))
 	}
 }
 
@@ -259,6 +310,9 @@ impl FromMeta for Args {
 				Arg::Name(path) => {
 					map.insert(path, None);
 				}
+				Arg::Value(value) => {
+					map.insert(syn::parse_quote! { value }, Some(value));
+				}
 				Arg::Range(range) => {
 					if let Some(start) = range.start {
 						map.insert(syn::parse_quote! { min }, Some(*start));
@@ -298,16 +352,89 @@ impl ToTokens for ArgsRef<'_> {
 	}
 }
 
+/// A case convention for `#[wary(rename_all = "...")]`, matching the
+/// conventions supported by serde.
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+	Lower,
+	Upper,
+	Pascal,
+	Camel,
+	Snake,
+	ScreamingSnake,
+	Kebab,
+	ScreamingKebab,
+}
+
+impl RenameRule {
+	const ALL: &'static [(&'static str, Self)] = &[
+		("lowercase", Self::Lower),
+		("UPPERCASE", Self::Upper),
+		("PascalCase", Self::Pascal),
+		("camelCase", Self::Camel),
+		("snake_case", Self::Snake),
+		("SCREAMING_SNAKE_CASE", Self::ScreamingSnake),
+		("kebab-case", Self::Kebab),
+		("SCREAMING-KEBAB-CASE", Self::ScreamingKebab),
+	];
+
+	/// Applies the convention to a field name written in `snake_case`.
+	pub fn apply(self, field: &str) -> String {
+		match self {
+			Self::Lower | Self::Snake => field.to_string(),
+			Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+			Self::Pascal | Self::Camel => {
+				let mut pascal = String::with_capacity(field.len());
+				let mut capitalize = matches!(self, Self::Pascal);
+
+				for c in field.chars() {
+					if c == '_' {
+						capitalize = true;
+					} else if capitalize {
+						pascal.push(c.to_ascii_uppercase());
+						capitalize = false;
+					} else {
+						pascal.push(c);
+					}
+				}
+
+				pascal
+			}
+			Self::Kebab => field.replace('_', "-"),
+			Self::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+		}
+	}
+}
+
+impl FromMeta for RenameRule {
+	fn from_string(value: &str) -> darling::Result<Self> {
+		Self::ALL
+			.iter()
+			.find(|(name, _)| *name == value)
+			.map(|(_, rule)| *rule)
+			.ok_or_else(|| darling::Error::unknown_value(value))
+	}
+}
+
 pub struct Fields<'f, F>(pub &'f ast::Fields<F>);
 
 pub trait Identify {
 	fn ident(&self) -> Option<&syn::Ident>;
+
+	/// The name of the field in error paths, if set with `rename`.
+	fn rename(&self) -> Option<&str> {
+		None
+	}
 }
 
 impl Identify for ValidateFieldWrapper {
 	fn ident(&self) -> Option<&syn::Ident> {
 		self.ident.as_ref()
 	}
+
+	fn rename(&self) -> Option<&str> {
+		self.rename.as_deref()
+	}
 }
 
 impl Identify for TransformFieldWrapper {
@@ -323,7 +450,7 @@ where
 	/// Emits the destructuring of the variant to be used in a match arm within
 	/// e.g. `Self::Variant { #here }`.
 	pub fn destruct(&self) -> TokenStream {
-		let fields = self.idents(None, false);
+		let fields = self.idents(None, None, false);
 
 		let destruct = fields.into_iter().map(|field| {
 			let ident = field.ident();
@@ -336,7 +463,17 @@ where
 		quote!(#(#destruct),*)
 	}
 
-	pub fn idents(&self, fields: Option<Vec<serde::Field<'_>>>, transparent: bool) -> Vec<Field> {
+	/// Returns the fields along with their names in error paths.
+	///
+	/// A field named with `#[validate(rename = "...")]` takes precedence over
+	/// `#[wary(rename_all = "...")]`, which takes precedence over the name
+	/// given by serde attributes.
+	pub fn idents(
+		&self,
+		fields: Option<Vec<serde::Field<'_>>>,
+		rename_all: Option<RenameRule>,
+		transparent: bool,
+	) -> Vec<Field> {
 		let mut idents = self
 			.0
 			.iter()
@@ -348,12 +485,23 @@ where
 				)
 			})
 			.collect::<Vec<_>>();
+		// fields with an explicit `#[serde(rename)]`, which `rename_all` skips
+		let mut renamed = vec![false; idents.len()];
 
 		#[cfg(feature = "serde")]
 		if let Some(fields) = fields {
-			for (ident, field) in idents.iter_mut().zip(fields.iter()) {
+			for ((ident, renamed), field) in idents.iter_mut().zip(&mut renamed).zip(fields.iter()) {
 				let field_path = field.attrs.name().deserialize_name();
 				ident.set_path(field_path.to_string());
+				*renamed = has_serde_rename(field.original);
+			}
+		}
+
+		for ((ident, renamed), field) in idents.iter_mut().zip(renamed).zip(self.0.iter()) {
+			if let Some(rename) = field.rename() {
+				ident.set_path(rename.to_string());
+			} else if let (Some(rule), Some(name), false) = (rename_all, field.ident(), renamed) {
+				ident.set_path(rule.apply(&name.unraw().to_string()));
 			}
 		}
 
@@ -361,12 +509,56 @@ where
 	}
 }
 
+/// Whether the field sets its deserialized name with `#[serde(rename = "...")]`
+/// or `#[serde(rename(deserialize = "..."))]`.
+#[cfg(feature = "serde")]
+fn has_serde_rename(field: &syn::Field) -> bool {
+	/// Skips the value of a meta item, such as `= "..."` or `(...)`.
+	fn skip(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+		if meta.input.peek(syn::Token![=]) {
+			meta.value()?.parse::<syn::Expr>()?;
+		} else if meta.input.peek(syn::token::Paren) {
+			meta.parse_nested_meta(|meta| skip(&meta))?;
+		}
+
+		Ok(())
+	}
+
+	let mut renamed = false;
+
+	for attr in field
+		.attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("serde"))
+	{
+		// serde reports malformed attributes itself
+		let _ = attr.parse_nested_meta(|meta| {
+			if !meta.path.is_ident("rename") {
+				return skip(&meta);
+			}
+
+			if meta.input.peek(syn::Token![=]) {
+				renamed = true;
+				return skip(&meta);
+			}
+
+			meta.parse_nested_meta(|meta| {
+				renamed |= meta.path.is_ident("deserialize");
+				skip(&meta)
+			})
+		});
+	}
+
+	renamed
+}
+
 pub struct Field {
 	path: Option<String>,
 	has_path: bool,
 	pub kind: FieldKind,
 }
 
+#[derive(Clone)]
 pub enum FieldKind {
 	Ident(syn::Ident),
 	Index(usize),
@@ -392,6 +584,16 @@ impl Field {
 	pub fn set_path(&mut self, path: String) {
 		self.path = Some(path);
 	}
+
+	/// Returns the same binding for the elements of the field, whose paths are
+	/// `__wary_field` (such as an index) when `has_path` is set.
+	pub fn elements(&self, has_path: bool) -> Self {
+		Self {
+			path: None,
+			has_path,
+			kind: self.kind.clone(),
+		}
+	}
 }
 
 impl Field {
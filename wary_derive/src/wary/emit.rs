@@ -1,12 +1,15 @@
-use darling::{ast, FromDeriveInput};
+// the darling derives below expand to a `continue` that newer clippy flags
+#![allow(clippy::needless_continue)]
+
+use darling::{ast, FromDeriveInput, FromField, FromMeta, FromVariant};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, ToTokens};
 
 use super::{
 	transform::{Transform, TransformFieldWrapper, TransformOptions, TransformVariant},
-	validate::{Validate, ValidateFieldWrapper, ValidateOptions, ValidateVariant},
+	validate::{Validate, ValidateFieldWrapper, ValidateOptions, ValidateVariant, VariantOptions},
 };
-use crate::util::Fields;
+use crate::util::{Fields, RenameRule};
 
 fn default_context() -> Type {
 	Type(syn::parse_quote! { () })
@@ -49,11 +52,78 @@ impl darling::ToTokens for Type {
 	}
 }
 
+/// Where clause predicates that replace the inferred bounds.
+#[derive(Clone)]
+struct Bound(syn::punctuated::Punctuated<syn::WherePredicate, syn::Token![,]>);
+
+impl FromMeta for Bound {
+	fn from_string(value: &str) -> darling::Result<Self> {
+		syn::parse::Parser::parse_str(syn::punctuated::Punctuated::parse_terminated, value)
+			.map(Self)
+			.map_err(|e| darling::Error::custom(e.to_string()))
+	}
+}
+
+/// The bounds of `#[wary(bound = "...")]`, shared by the `Validate` and
+/// `Transform` impls, or of `#[wary(bound(validate = "...", transform =
+/// "..."))]`, where each impl not listed keeps its inferred bounds.
+#[derive(Default, FromMeta)]
+struct Bounds {
+	validate: Option<Bound>,
+	transform: Option<Bound>,
+}
+
+impl Bounds {
+	fn parse(item: &syn::Meta) -> darling::Result<Self> {
+		if let syn::Meta::NameValue(..) = item {
+			let bound = Bound::from_meta(item)?;
+
+			return Ok(Self {
+				validate: Some(bound.clone()),
+				transform: Some(bound),
+			});
+		}
+
+		Self::from_meta(item)
+	}
+}
+
+/// A field with `#[wary(...)]` attributes.
+#[derive(FromField)]
+#[darling(attributes(wary))]
+struct WaryField {
+	/// Excludes the field from validation and transformation.
+	skip: darling::util::Flag,
+}
+
+#[derive(FromVariant)]
+#[darling(attributes(wary))]
+struct WaryVariant {
+	fields: ast::Fields<WaryField>,
+}
+
+/// Marks the fields with `#[wary(skip)]` as skipped.
+fn skip_fields(
+	options: &ast::Fields<WaryField>,
+	validate: &mut ast::Fields<ValidateFieldWrapper>,
+	transform: &mut ast::Fields<TransformFieldWrapper>,
+) {
+	for ((options, validate), transform) in options
+		.iter()
+		.zip(validate.fields.iter_mut())
+		.zip(transform.fields.iter_mut())
+	{
+		validate.skip = options.skip.is_present();
+		transform.skip = options.skip.is_present();
+	}
+}
+
 #[derive(FromDeriveInput)]
 #[darling(attributes(wary))]
 pub struct Options {
 	ident: syn::Ident,
 	generics: syn::Generics,
+	data: ast::Data<WaryVariant, WaryField>,
 
 	/// The context type to use when validating.
 	#[darling(default = "default_context")]
@@ -61,6 +131,72 @@ pub struct Options {
 	#[darling(default = "crate::default_crate_name", rename = "crate")]
 	crate_name: syn::Path,
 	transparent: darling::util::Flag,
+	/// Replaces the inferred bounds on type parameters.
+	#[darling(default, with = Bounds::parse)]
+	bound: Bounds,
+	/// Renames every field in error paths.
+	rename_all: Option<RenameRule>,
+}
+
+impl Options {
+	/// Returns the generics for an impl of `bound` (such as `Validate`).
+	///
+	/// Unless `explicit` bounds are set with `#[wary(bound = "...")]`, every
+	/// type parameter used in one of `tys` (the types of the fields that dive)
+	/// is bounded by `T: #bound<Context = C>`.
+	fn generics<'t>(
+		&self,
+		bound: &TokenStream,
+		explicit: Option<&Bound>,
+		tys: impl IntoIterator<Item = &'t syn::Type>,
+	) -> syn::Generics {
+		let mut generics = self.generics.clone();
+
+		if let Some(Bound(predicates)) = explicit {
+			generics
+				.make_where_clause()
+				.predicates
+				.extend(predicates.iter().cloned());
+
+			return generics;
+		}
+
+		let mut used = Vec::new();
+
+		for ty in tys {
+			idents(ty.to_token_stream(), &mut used);
+		}
+
+		let crate_name = &self.crate_name;
+		let context = &self.context;
+		let predicates = self
+			.generics
+			.type_params()
+			.filter(|param| used.contains(&param.ident))
+			.map(|param| -> syn::WherePredicate {
+				let ident = &param.ident;
+
+				syn::parse_quote! { #ident: #crate_name::#bound<Context = #context> }
+			})
+			.collect::<Vec<_>>();
+
+		if !predicates.is_empty() {
+			generics.make_where_clause().predicates.extend(predicates);
+		}
+
+		generics
+	}
+}
+
+/// Collects every identifier in `tokens`.
+fn idents(tokens: TokenStream, idents: &mut Vec<syn::Ident>) {
+	for token in tokens {
+		match token {
+			proc_macro2::TokenTree::Ident(ident) => idents.push(ident),
+			proc_macro2::TokenTree::Group(group) => self::idents(group.stream(), idents),
+			_ => {}
+		}
+	}
 }
 
 #[cfg(feature = "serde")]
@@ -104,6 +240,7 @@ impl<'a> Emit<'a> {
 					serde,
 					validate,
 					transform,
+					variant: self.validate.variant,
 					validate_top: ValidateOptions {
 						func: self.validate.func,
 						or: self.validate.or,
@@ -119,6 +256,9 @@ impl<'a> Emit<'a> {
 				}
 				.into_token_stream()
 			}
+			(ast::Data::Struct(..), ..) if self.validate.variant.is_some() => {
+				darling::Error::custom("`variant` is only supported on enums").write_errors()
+			}
 			(
 				ast::Data::Struct(validate),
 				ast::Data::Struct(transform),
@@ -148,8 +288,20 @@ impl<'a> Emit<'a> {
 
 	pub fn from_derive_input(input: &'a mut syn::DeriveInput) -> darling::Result<Self> {
 		let options = Options::from_derive_input(input)?;
-		let validate = Validate::from_derive_input(input)?;
-		let transform = Transform::from_derive_input(input)?;
+		let mut validate = Validate::from_derive_input(input)?;
+		let mut transform = Transform::from_derive_input(input)?;
+
+		match (&options.data, &mut validate.data, &mut transform.data) {
+			(ast::Data::Struct(o), ast::Data::Struct(v), ast::Data::Struct(t)) => {
+				skip_fields(o, v, t);
+			}
+			(ast::Data::Enum(o), ast::Data::Enum(v), ast::Data::Enum(t)) => {
+				for ((o, v), t) in o.iter().zip(v).zip(t) {
+					skip_fields(&o.fields, &mut v.fields, &mut t.fields);
+				}
+			}
+			_ => {}
+		}
 
 		#[cfg(feature = "serde")]
 		let cont = {
@@ -167,8 +319,8 @@ impl<'a> Emit<'a> {
 		};
 		#[cfg(not(feature = "serde"))]
 		let cont = serde::Container {
-			data: if validate.data.is_enum() {
-				serde::Data::Enum(Vec::new())
+			data: if let ast::Data::Enum(variants) = &validate.data {
+				serde::Data::Enum(vec![core::marker::PhantomData; variants.len()])
 			} else {
 				serde::Data::Struct((), Vec::new())
 			},
@@ -188,6 +340,7 @@ struct EmitEnum<'o> {
 	serde: Vec<serde::Variant<'o>>,
 	validate: Vec<ValidateVariant>,
 	transform: Vec<TransformVariant>,
+	variant: Option<VariantOptions>,
 	validate_top: ValidateOptions,
 	transform_top: TransformOptions,
 }
@@ -203,15 +356,63 @@ impl EmitEnum<'_> {
 		let is_validate_async = self
 			.validate
 			.iter()
-			.any(|v| !v.fields.iter().any(|f| f.custom_async.is_empty()))
+			.any(|v| v.fields.iter().any(|f| !f.custom_async.is_empty()))
 			|| !self.validate_top.custom_async.is_empty();
 
 		let is_transform_async = self
 			.transform
 			.iter()
-			.any(|m| !m.fields.iter().any(|f| f.custom_async.is_empty()))
+			.any(|m| m.fields.iter().any(|f| !f.custom_async.is_empty()))
 			|| !self.transform_top.custom_async.is_empty();
 
+		let variants = self
+			.validate
+			.iter()
+			.map(|v| v.ident.clone())
+			.collect::<Vec<_>>();
+
+		let validate_generics = self.options.generics(
+			&quote!(Validate),
+			self.options.bound.validate.as_ref(),
+			self
+				.validate
+				.iter()
+				.flat_map(|v| v.fields.iter())
+				.filter(|f| f.dives())
+				.map(|f| &f.ty),
+		);
+		let transform_generics = self.options.generics(
+			&quote!(Transform),
+			self.options.bound.transform.as_ref(),
+			self
+				.transform
+				.iter()
+				.flat_map(|v| v.fields.iter())
+				.filter(|f| f.dives())
+				.map(|f| &f.ty),
+		);
+		let (v_imp, v_ty, v_wher) = validate_generics.split_for_impl();
+		let (t_imp, t_ty, t_wher) = transform_generics.split_for_impl();
+
+		let validate_variant = self.variant.map(|variant| {
+			let names = variants.iter().map(ToString::to_string);
+			let check = variant.into_token_stream(&self.options.crate_name, &variants);
+
+			quote! {
+				{
+					let __wary_variant: &'static str = match self {
+						#(
+							Self::#variants { .. } => #names,
+						)*
+					};
+
+					#check
+				}
+			}
+		});
+
+		let crate_name = &self.options.crate_name;
+
 		#[allow(unused)]
 		let validate = self.validate.into_iter().zip(self.serde).map(|(v, serde)| {
 			let destruct = Fields(&v.fields).destruct();
@@ -222,14 +423,30 @@ impl EmitEnum<'_> {
 			#[cfg(not(feature = "serde"))]
 			let serde_fields = None;
 
+			let deny = v.deny.is_present().then(|| {
+				let name = ident.to_string();
+
+				quote! {
+					#crate_name::Rule::validate_into(
+						&#crate_name::options::rule::variant::Rule::new().deny(),
+						&(),
+						#name,
+						__wary_parent,
+						__wary_report,
+					);
+				}
+			});
+
 			let fields = Fields(&v.fields)
-				.idents(serde_fields, false)
+				.idents(serde_fields, self.options.rename_all, false)
 				.into_iter()
 				.zip(v.fields)
 				.map(|(field, f)| f.into_token_stream(&self.options.crate_name, &field));
 
 			quote! {
 				Self::#ident { #destruct } => {
+					#deny
+
 					#(
 						#fields
 					)*
@@ -246,7 +463,7 @@ impl EmitEnum<'_> {
 			let ident = m.ident.clone();
 
 			let fields = Fields(&m.fields)
-				.idents(None, false)
+				.idents(None, None, false)
 				.into_iter()
 				.zip(m.fields)
 				.map(|(field, m)| m.into_token_stream(&self.options.crate_name, &field));
@@ -264,7 +481,6 @@ impl EmitEnum<'_> {
 			.transform_top
 			.into_token_stream(&self.options.crate_name, &syn::parse_quote!(#ident));
 
-		let (imp, ty, wher) = self.options.generics.split_for_impl();
 		let crate_name = &self.options.crate_name;
 		let context = &self.options.context;
 		let ident = &self.options.ident;
@@ -273,10 +489,12 @@ impl EmitEnum<'_> {
 			quote! {
 				#[allow(warnings)]
 				#[automatically_derived]
-				impl #imp #crate_name::AsyncValidate for #ident #ty #wher {
+				impl #v_imp #crate_name::AsyncValidate for #ident #v_ty #v_wher {
 					type Context = #context;
 
 					async fn validate_into_async(&self, ctx: &Self::Context, __wary_parent: &#crate_name::error::Path, __wary_report: &mut #crate_name::error::Report) {
+						#validate_variant
+
 						match self {
 							#(
 								#validate
@@ -289,7 +507,7 @@ impl EmitEnum<'_> {
 
 				#[allow(warnings)]
 				#[automatically_derived]
-				impl #imp #crate_name::AsyncTransform for #ident #ty #wher {
+				impl #t_imp #crate_name::AsyncTransform for #ident #t_ty #t_wher {
 					type Context = #context;
 
 					async fn transform_async(&mut self, ctx: &Self::Context) {
@@ -307,10 +525,12 @@ impl EmitEnum<'_> {
 			quote! {
 				#[allow(warnings)]
 				#[automatically_derived]
-				impl #imp #crate_name::Validate for #ident #ty #wher {
+				impl #v_imp #crate_name::Validate for #ident #v_ty #v_wher {
 					type Context = #context;
 
 					fn validate_into(&self, ctx: &Self::Context, __wary_parent: &#crate_name::error::Path, __wary_report: &mut #crate_name::error::Report) {
+						#validate_variant
+
 						match self {
 							#(
 								#validate
@@ -323,7 +543,7 @@ impl EmitEnum<'_> {
 
 				#[allow(warnings)]
 				#[automatically_derived]
-				impl #imp #crate_name::Transform for #ident #ty #wher {
+				impl #t_imp #crate_name::Transform for #ident #t_ty #t_wher {
 					type Context = #context;
 
 					fn transform(&mut self, ctx: &Self::Context) {
@@ -358,8 +578,11 @@ impl EmitStruct<'_> {
 		}
 
 		let destruct = Fields(&self.validate).destruct();
-		let idents =
-			Fields(&self.validate).idents(Some(self.serde), self.options.transparent.is_present());
+		let idents = Fields(&self.validate).idents(
+			Some(self.serde),
+			self.options.rename_all,
+			self.options.transparent.is_present(),
+		);
 		let ident = &self.options.ident;
 
 		let is_validate_async = self.validate.iter().any(|v| !v.custom_async.is_empty())
@@ -368,6 +591,19 @@ impl EmitStruct<'_> {
 		let is_transform_async = self.transform.iter().any(|m| !m.custom_async.is_empty())
 			|| !self.transform_top.custom_async.is_empty();
 
+		let validate_generics = self.options.generics(
+			&quote!(Validate),
+			self.options.bound.validate.as_ref(),
+			self.validate.iter().filter(|f| f.dives()).map(|f| &f.ty),
+		);
+		let transform_generics = self.options.generics(
+			&quote!(Transform),
+			self.options.bound.transform.as_ref(),
+			self.transform.iter().filter(|f| f.dives()).map(|f| &f.ty),
+		);
+		let (v_imp, v_ty, v_wher) = validate_generics.split_for_impl();
+		let (t_imp, t_ty, t_wher) = transform_generics.split_for_impl();
+
 		let validate = self
 			.validate
 			.into_iter()
@@ -388,7 +624,6 @@ impl EmitStruct<'_> {
 			.transform_top
 			.into_token_stream(&self.options.crate_name, &syn::parse_quote!(#ident));
 
-		let (imp, ty, wher) = self.options.generics.split_for_impl();
 		let crate_name = &self.options.crate_name;
 		let context = &self.options.context;
 		let ident = &self.options.ident;
@@ -397,7 +632,7 @@ impl EmitStruct<'_> {
 			quote! {
 				#[allow(warnings)]
 				#[automatically_derived]
-				impl #imp #crate_name::AsyncValidate for #ident #ty #wher {
+				impl #v_imp #crate_name::AsyncValidate for #ident #v_ty #v_wher {
 					type Context = #context;
 
 					async fn validate_into_async(&self, ctx: &Self::Context, __wary_parent: &#crate_name::error::Path, __wary_report: &mut #crate_name::error::Report) {
@@ -413,7 +648,7 @@ impl EmitStruct<'_> {
 
 				#[allow(warnings)]
 				#[automatically_derived]
-				impl #imp #crate_name::AsyncTransform for #ident #ty #wher {
+				impl #t_imp #crate_name::AsyncTransform for #ident #t_ty #t_wher {
 					type Context = #context;
 
 					async fn transform_async(&mut self, ctx: &Self::Context) {
@@ -431,7 +666,7 @@ impl EmitStruct<'_> {
 			quote! {
 				#[allow(warnings)]
 				#[automatically_derived]
-				impl #imp #crate_name::Validate for #ident #ty #wher {
+				impl #v_imp #crate_name::Validate for #ident #v_ty #v_wher {
 					type Context = #context;
 
 					fn validate_into(&self, ctx: &Self::Context, __wary_parent: &#crate_name::error::Path, __wary_report: &mut #crate_name::error::Report) {
@@ -447,7 +682,7 @@ impl EmitStruct<'_> {
 
 				#[allow(warnings)]
 				#[automatically_derived]
-				impl #imp #crate_name::Transform for #ident #ty #wher {
+				impl #t_imp #crate_name::Transform for #ident #t_ty #t_wher {
 					type Context = #context;
 
 					fn transform(&mut self, ctx: &Self::Context) {
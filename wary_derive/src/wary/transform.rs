@@ -1,3 +1,6 @@
+// the darling derives below expand to a `continue` that newer clippy flags
+#![allow(clippy::needless_continue)]
+
 use darling::{ast, FromDeriveInput, FromField, FromMeta, FromVariant};
 use quote::{format_ident, quote};
 
@@ -36,6 +39,10 @@ pub struct TransformFieldWrapper {
 	pub ident: Option<syn::Ident>,
 	pub ty: syn::Type,
 
+	/// Set by `#[wary(skip)]` on the field.
+	#[darling(skip)]
+	pub skip: bool,
+
 	#[darling(multiple)]
 	func: Vec<syn::Expr>,
 
@@ -74,7 +81,21 @@ struct TransformField {
 	builtin: Map<syn::Path, Option<Args>>,
 }
 
+impl TransformField {
+	/// Returns `true` if the field dives into its value, including through
+	/// `inner`.
+	fn dives(&self) -> bool {
+		self.dive.is_present() || self.inner.as_ref().is_some_and(|inner| inner.dives())
+	}
+}
+
 impl TransformFieldWrapper {
+	/// Returns `true` if the field dives into its value, including through
+	/// `inner`.
+	pub fn dives(&self) -> bool {
+		!self.skip && (self.dive.is_present() || self.inner.as_ref().is_some_and(|inner| inner.dives()))
+	}
+
 	fn into_inner(self) -> TransformField {
 		TransformField {
 			func: self.func,
@@ -93,6 +114,10 @@ impl TransformFieldWrapper {
 		crate_name: &syn::Path,
 		field: &Field,
 	) -> proc_macro2::TokenStream {
+		if self.skip {
+			return proc_macro2::TokenStream::new();
+		}
+
 		let ty = self.ty.clone();
 		self
 			.into_inner()
@@ -1,3 +1,6 @@
+// the darling derives below expand to a `continue` that newer clippy flags
+#![allow(clippy::needless_continue)]
+
 use darling::{ast, FromDeriveInput, FromField, FromMeta, FromVariant};
 use quote::{format_ident, quote, ToTokens};
 
@@ -25,6 +28,15 @@ pub struct Validate {
 
 	#[darling(default)]
 	pub custom_async: Map<syn::Path, Option<Args>>,
+
+	#[darling(default)]
+	pub variant: Option<VariantOptions>,
+}
+
+/// Restricts which variants of an enum are allowed.
+#[derive(Debug, FromMeta)]
+pub struct VariantOptions {
+	one_of: syn::Expr,
 }
 
 pub struct ValidateOptions {
@@ -36,9 +48,12 @@ pub struct ValidateOptions {
 }
 
 #[derive(Debug, FromVariant)]
+#[darling(attributes(validate))]
 pub struct ValidateVariant {
 	pub ident: syn::Ident,
 	pub fields: ast::Fields<ValidateFieldWrapper>,
+
+	pub deny: darling::util::Flag,
 }
 
 #[derive(Debug, FromMeta)]
@@ -61,6 +76,12 @@ pub struct ValidateField {
 	#[darling(default)]
 	inner: Option<Box<ValidateField>>,
 
+	#[darling(default)]
+	keys: Option<Box<ValidateField>>,
+
+	#[darling(default)]
+	values: Option<Box<ValidateField>>,
+
 	dive: darling::util::Flag,
 
 	#[darling(default)]
@@ -76,6 +97,21 @@ pub struct ValidateFieldWrapper {
 	pub ident: Option<syn::Ident>,
 	pub ty: syn::Type,
 
+	/// The name of the field in error paths.
+	#[darling(default)]
+	pub rename: Option<String>,
+
+	/// Set by `#[wary(skip)]` on the field.
+	#[darling(skip)]
+	pub skip: bool,
+
+	/// Skips validation when the function returns `true`.
+	#[darling(default)]
+	skip_if: Option<syn::Expr>,
+
+	/// Skips validation when the field equals its `Default`.
+	skip_if_default: darling::util::Flag,
+
 	#[darling(multiple)]
 	func: Vec<syn::Expr>,
 
@@ -94,6 +130,12 @@ pub struct ValidateFieldWrapper {
 	#[darling(default)]
 	inner: Option<Box<ValidateField>>,
 
+	#[darling(default)]
+	keys: Option<Box<ValidateField>>,
+
+	#[darling(default)]
+	values: Option<Box<ValidateField>>,
+
 	dive: darling::util::Flag,
 
 	#[darling(default)]
@@ -129,6 +171,12 @@ impl ValidateField {
 		};
 
 		let option_path = crate::attr::extract_option_path(ty);
+		let required_overrides = self
+			.required
+			.as_mut()
+			.and_then(Option::as_mut)
+			.map(Args::take_overrides)
+			.unwrap_or_default();
 
 		if option_path.is_none() {
 			if let Some(args) = &self.required {
@@ -138,13 +186,14 @@ impl ValidateField {
 						&(),
 						#field
 					) {
-						__wary_report.push(#error_path, e);
+						__wary_report.push(#error_path, e #required_overrides);
 					};
 				});
 			}
 		}
 
 		for (path, args) in self.builtin.iter_mut() {
+			let overrides = args.as_mut().map(Args::take_overrides).unwrap_or_default();
 			let args_ref = args.as_ref().map(ArgsRef);
 			let args: &dyn ToTokens = if path.is_ident("range") {
 				&args_ref
@@ -167,35 +216,161 @@ impl ValidateField {
 					}
 				}
 
+				args
+			} else if path.is_ident("datetime") {
+				if let Some(args) = &args {
+					if let Err(e) = attr::check_literals(
+						args,
+						&[
+							"min",
+							"max",
+							"exclusive_min",
+							"exclusive_max",
+							"after",
+							"before",
+						],
+						attr::parse_datetime,
+					) {
+						tokens.extend(e.to_compile_error());
+						continue;
+					}
+				}
+
+				args
+			} else if path.is_ident("time_of_day") {
+				if let Some(args) = &args {
+					if let Err(e) = attr::check_literals(args, &["between"], attr::parse_time_of_day) {
+						tokens.extend(e.to_compile_error());
+						continue;
+					}
+				}
+
+				args
+			} else if path.is_ident("duration") || path.is_ident("time") {
+				let keys: &[&str] = if path.is_ident("duration") {
+					&["min", "max", "exclusive_min", "exclusive_max"]
+				} else {
+					&["within", "not_older_than"]
+				};
+
+				if let Some(args) = args {
+					if let Err(e) = attr::expand_durations(args, keys) {
+						tokens.extend(e.to_compile_error());
+						continue;
+					}
+				}
+
+				args
+			} else if path.is_ident("unique") || path.is_ident("sorted") {
+				let key = syn::parse_quote! { by };
+				let helper = if path.is_ident("unique") {
+					format_ident!("borrowed_key")
+				} else {
+					format_ident!("owned_key")
+				};
+
+				if let Some(args) = args {
+					if let Some(Some(expr)) = args.get(&key) {
+						let expr = expr.clone();
+
+						args.insert(
+							key,
+							Some(syn::parse_quote! {
+								#crate_name::internal::#helper(#field, #expr)
+							}),
+						);
+					}
+				}
+
+				args
+			} else if path.is_ident("weekday") {
+				if let Some(args) = args {
+					attr::qualify_idents(
+						args,
+						&["not", "only"],
+						&syn::parse_quote! { #crate_name::options::rule::weekday::Weekday },
+					);
+				}
+
 				args
 			} else {
 				args
 			};
 
-			tokens.extend(quote! {
-				if let Err(e) = #crate_name::Rule::validate(
+			let validate = quote! {
+				#crate_name::Rule::validate_into(
 					&#crate_name::options::rule::#path::Rule::new() #args,
 					&(),
-					#field
-				) {
-					__wary_report.push(#error_path, e);
-				};
-			});
+					#field,
+					&#error_path,
+					__wary_report,
+				);
+			};
+
+			if overrides.is_empty() {
+				tokens.extend(validate);
+			} else {
+				tokens.extend(quote! {
+					{
+						let __wary_start = __wary_report.len();
+						#validate
+						__wary_report.map_from(__wary_start, |e| e #overrides);
+					}
+				});
+			}
 		}
 
 		if let Some(inner) = &mut self.inner {
-			let inner = inner.to_token_stream(crate_name, field, ty, false);
+			let inner = inner.to_token_stream(crate_name, &field.elements(true), ty, false);
 
 			tokens.extend(quote! {
 				{
 					let __wary_parent = #error_path;
-					for (__wary_field, #field) in #crate_name::AsSlice::as_slice(#field).iter().enumerate() {
+					for (__wary_field, #field) in #crate_name::AsIter::items(#field).enumerate() {
 						#inner
 					}
 				}
 			});
 		}
 
+		if self.keys.is_some() || self.values.is_some() {
+			// the key is already part of `__wary_parent` within the loop
+			let entry = field.elements(false);
+			let any = syn::parse_quote! { _ };
+			let keys = self.keys.as_mut().map(|keys| {
+				let keys = keys.to_token_stream(crate_name, &entry, &any, false);
+
+				quote! {
+					let __wary_parent = __wary_parent.append(#crate_name::error::Elem::MapKey);
+					let #field = __wary_key;
+					#keys
+				}
+			});
+			let values = self.values.as_mut().map(|values| {
+				let values = values.to_token_stream(crate_name, &entry, &any, false);
+
+				quote! {
+					let #field = __wary_value;
+					#values
+				}
+			});
+
+			tokens.extend(quote! {
+				{
+					let __wary_parent = #error_path;
+					for (__wary_key, __wary_value) in #crate_name::AsMap::entries(#field) {
+						let __wary_parent = __wary_parent.append_key(__wary_key);
+						{
+							#keys
+						}
+						{
+							#values
+						}
+					}
+				}
+			});
+		}
+
 		for func in &self.func {
 			tokens.extend(quote! {
 				{
@@ -207,26 +382,30 @@ impl ValidateField {
 			});
 		}
 
-		for (path, args) in self.custom.iter() {
+		for (path, args) in self.custom.iter_mut() {
+			let overrides = args.as_mut().map(Args::take_overrides).unwrap_or_default();
+
 			tokens.extend(quote! {
 				if let Err(e) = #crate_name::Rule::validate(
 					&rule::#path::new() #args,
 					ctx,
 					#field
 				) {
-					__wary_report.push(#error_path, e);
+					__wary_report.push(#error_path, e #overrides);
 				};
 			});
 		}
 
-		for (path, args) in self.custom_async.iter() {
+		for (path, args) in self.custom_async.iter_mut() {
+			let overrides = args.as_mut().map(Args::take_overrides).unwrap_or_default();
+
 			tokens.extend(quote! {
 				if let Err(e) = #crate_name::AsyncRule::validate_async(
 					&rule::#path::new() #args,
 					ctx,
 					#field
 				).await {
-					__wary_report.push(#error_path, e);
+					__wary_report.push(#error_path, e #overrides);
 				};
 			});
 		}
@@ -307,7 +486,7 @@ impl ValidateField {
 							&(),
 							#field
 						) {
-							__wary_report.push(#error_path, e);
+							__wary_report.push(#error_path, e #required_overrides);
 						};
 					}
 				},
@@ -330,6 +509,50 @@ impl ValidateField {
 	}
 }
 
+impl VariantOptions {
+	/// Emits the check of the variant name in `__wary_variant` against the
+	/// allowed variants. A list of bare idents is checked against `variants`
+	/// and turned into their names; anything else is passed through as is, so
+	/// it can come from the context.
+	pub fn into_token_stream(
+		self,
+		crate_name: &syn::Path,
+		variants: &[syn::Ident],
+	) -> proc_macro2::TokenStream {
+		let mut one_of = self.one_of;
+
+		if let syn::Expr::Array(array) = &mut one_of {
+			for elem in &mut array.elems {
+				let syn::Expr::Path(path) = elem else {
+					continue;
+				};
+
+				let Some(ident) = path.path.get_ident() else {
+					continue;
+				};
+
+				if !variants.contains(ident) {
+					return syn::Error::new_spanned(ident, format!("unknown variant `{ident}`"))
+						.to_compile_error();
+				}
+
+				let name = ident.to_string();
+				*elem = syn::parse_quote! { #name };
+			}
+		}
+
+		quote! {
+			#crate_name::Rule::validate_into(
+				&#crate_name::options::rule::variant::Rule::new().one_of(&#one_of),
+				&(),
+				__wary_variant,
+				__wary_parent,
+				__wary_report,
+			);
+		}
+	}
+}
+
 impl ValidateOptions {
 	pub fn into_token_stream(
 		self,
@@ -344,6 +567,8 @@ impl ValidateOptions {
 			and: self.and,
 			dive: darling::util::Flag::default(),
 			inner: None,
+			keys: None,
+			values: None,
 			required: None,
 			builtin: Map::default(),
 		}
@@ -356,7 +581,37 @@ impl ValidateOptions {
 	}
 }
 
+impl ValidateField {
+	/// Returns `true` if the field dives into its value, including through
+	/// `inner`, `keys`, `values`, `and` or `or`.
+	fn dives(&self) -> bool {
+		self.dive.is_present()
+			|| [&self.inner, &self.keys, &self.values]
+				.into_iter()
+				.flatten()
+				.any(|inner| inner.dives())
+			|| self.and.0.iter().chain(&self.or.0).any(Self::dives)
+	}
+}
+
 impl ValidateFieldWrapper {
+	/// Returns `true` if the field dives into its value, including through
+	/// `inner`, `keys`, `values`, `and` or `or`.
+	pub fn dives(&self) -> bool {
+		!self.skip
+			&& (self.dive.is_present()
+				|| [&self.inner, &self.keys, &self.values]
+					.into_iter()
+					.flatten()
+					.any(|inner| inner.dives())
+				|| self
+					.and
+					.0
+					.iter()
+					.chain(&self.or.0)
+					.any(ValidateField::dives))
+	}
+
 	fn into_inner(self) -> ValidateField {
 		ValidateField {
 			func: self.func,
@@ -365,6 +620,8 @@ impl ValidateFieldWrapper {
 			custom: self.custom,
 			custom_async: self.custom_async,
 			inner: self.inner,
+			keys: self.keys,
+			values: self.values,
 			builtin: self.builtin,
 			required: self.required,
 			dive: self.dive,
@@ -378,9 +635,37 @@ impl ValidateFieldWrapper {
 		crate_name: &syn::Path,
 		field: &Field,
 	) -> proc_macro2::TokenStream {
+		if self.skip {
+			return proc_macro2::TokenStream::new();
+		}
+
 		let ty = self.ty.clone();
-		self
+		let mut skip = Vec::new();
+
+		if let Some(skip_if) = &self.skip_if {
+			skip.push(quote! {
+				#crate_name::internal::skip_if(ctx, #field, #skip_if)
+			});
+		}
+
+		if self.skip_if_default.is_present() {
+			skip.push(quote! {
+				*#field == <#ty as ::core::default::Default>::default()
+			});
+		}
+
+		let tokens = self
 			.into_inner()
-			.to_token_stream(crate_name, field, &ty, true)
+			.to_token_stream(crate_name, field, &ty, true);
+
+		if skip.is_empty() {
+			tokens
+		} else {
+			quote! {
+				if !(#(#skip)||*) {
+					#tokens
+				}
+			}
+		}
 	}
 }